@@ -1,8 +1,14 @@
 use bevy::{asset::RenderAssetUsages, prelude::*, render::render_resource::PrimitiveTopology};
 use bevy_mesh::*;
 
+/// 原点を中心に、各軸方向に`half`ずつ広がる立方体のメッシュを作る
+///
+/// フィールドのブロックに使う場合は[`BLOCK_HALF_SIZE`]を渡し、
+/// ブロックの大きさの定数を変えるだけでメッシュも合わせて変わるようにする。
+///
+/// [`BLOCK_HALF_SIZE`]: crate::constants::BLOCK_HALF_SIZE
 #[rustfmt::skip]
-pub fn create_cube_mesh() -> Mesh {
+pub fn create_cube_mesh(half: f32) -> Mesh {
     // Keep the mesh data accessible in future frames to be able to mutate it in toggle_texture.
     Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD)
     .with_inserted_attribute(
@@ -13,35 +19,35 @@ pub fn create_cube_mesh() -> Mesh {
         // By centering our mesh around the origin, rotating the mesh preserves its center of mass.
         vec![
             // top (facing towards +y)
-            [-8.0, 8.0, -8.0], // vertex with index 0
-            [8.0, 8.0, -8.0], // vertex with index 1
-            [8.0, 8.0, 8.0], // etc. until 23
-            [-8.0, 8.0, 8.0],
+            [-half, half, -half], // vertex with index 0
+            [half, half, -half], // vertex with index 1
+            [half, half, half], // etc. until 23
+            [-half, half, half],
             // bottom   (-y)
-            [-8.0, -8.0, -8.0],
-            [8.0, -8.0, -8.0],
-            [8.0, -8.0, 8.0],
-            [-8.0, -8.0, 8.0],
+            [-half, -half, -half],
+            [half, -half, -half],
+            [half, -half, half],
+            [-half, -half, half],
             // right    (+x)
-            [8.0, -8.0, -8.0],
-            [8.0, -8.0, 8.0],
-            [8.0, 8.0, 8.0], // This vertex is at the same position as vertex with index 2, but they'll have different UV and normal
-            [8.0, 8.0, -8.0],
+            [half, -half, -half],
+            [half, -half, half],
+            [half, half, half], // This vertex is at the same position as vertex with index 2, but they'll have different UV and normal
+            [half, half, -half],
             // left     (-x)
-            [-8.0, -8.0, -8.0],
-            [-8.0, -8.0, 8.0],
-            [-8.0, 8.0, 8.0],
-            [-8.0, 8.0, -8.0],
+            [-half, -half, -half],
+            [-half, -half, half],
+            [-half, half, half],
+            [-half, half, -half],
             // back     (+z)
-            [-8.0, -8.0, 8.0],
-            [-8.0, 8.0, 8.0],
-            [8.0, 8.0, 8.0],
-            [8.0, -8.0, 8.0],
+            [-half, -half, half],
+            [-half, half, half],
+            [half, half, half],
+            [half, -half, half],
             // forward  (-z)
-            [-8.0, -8.0, -8.0],
-            [-8.0, 8.0, -8.0],
-            [8.0, 8.0, -8.0],
-            [8.0, -8.0, -8.0],
+            [-half, -half, -half],
+            [-half, half, -half],
+            [half, half, -half],
+            [half, -half, -half],
         ],
     )
     // Set-up UV coordinates to point to the upper (V < 0.5), "dirt+grass" part of the texture.
@@ -148,3 +154,41 @@ pub fn create_cube_mesh() -> Mesh {
         20,21,23 , 21,22,23, // forward (-z)
     ]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cube_vertices_lie_on_the_half_extent() {
+        let half = 8.0;
+        let mesh = create_cube_mesh(half);
+        let positions = mesh
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|attribute| attribute.as_float3())
+            .unwrap();
+
+        // 6面 × 4頂点
+        assert_eq!(positions.len(), 24);
+        for position in positions {
+            assert!(position.iter().all(|&axis| axis.abs() == half));
+        }
+        assert_eq!(mesh.indices().map(|indices| indices.len()), Some(36));
+    }
+
+    #[test]
+    fn cube_size_is_twice_the_half_extent() {
+        let mesh = create_cube_mesh(crate::constants::BLOCK_HALF_SIZE);
+        let positions = mesh
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|attribute| attribute.as_float3())
+            .unwrap();
+        let (min, max) = positions.iter().map(|&position| Vec3::from(position)).fold(
+            (Vec3::INFINITY, Vec3::NEG_INFINITY),
+            |(min, max), position| (min.min(position), max.max(position)),
+        );
+
+        assert_eq!((min + max) / 2.0, Vec3::ZERO);
+        assert_eq!(max - min, Vec3::splat(crate::constants::BLOCK_SIZE));
+    }
+}
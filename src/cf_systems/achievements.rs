@@ -0,0 +1,68 @@
+//! 実績
+//!
+//! ゲームプレイの各システムが送る[`RockMined`]・[`FoxPossessed`]・[`ObjectBuilt`]を
+//! 購読し、条件を満たした実績を一度だけ解除してフィードバックテキストで知らせる。
+//! 解除した実績はセーブファイルに保存する。
+
+use bevy::prelude::*;
+
+use crate::components::ClickFeedbackText;
+use crate::constants::*;
+use crate::events::{FoxPossessed, ObjectBuilt, RockMined};
+use crate::locale::{Locale, TextKey};
+use crate::resources::{Achievement, Achievements};
+use crate::traits::JsonSave;
+
+/// ゲームプレイのメッセージから実績の解除を判定するシステム
+pub fn unlock_achievements(
+    mut rock_mined: MessageReader<RockMined>,
+    mut fox_possessed: MessageReader<FoxPossessed>,
+    mut object_built: MessageReader<ObjectBuilt>,
+    mut achievements: ResMut<Achievements>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    let mut unlocked = Vec::new();
+
+    if rock_mined.read().count() > 0 && achievements.unlock(Achievement::FirstOreMined) {
+        unlocked.push(Achievement::FirstOreMined);
+    }
+
+    if fox_possessed.read().count() > 0 && achievements.unlock(Achievement::FirstPossession) {
+        unlocked.push(Achievement::FirstPossession);
+    }
+
+    let built = object_built.read().count() as u32;
+    if built > 0 {
+        achievements.objects_built += built;
+        if achievements.objects_built >= ACHIEVEMENT_BUILDER_COUNT
+            && achievements.unlock(Achievement::Builder)
+        {
+            unlocked.push(Achievement::Builder);
+        }
+    }
+
+    if (built > 0 || !unlocked.is_empty())
+        && let Err(e) = achievements.save_to_file()
+    {
+        eprintln!("Failed to save achievements: {}", e);
+    }
+
+    if let Some(achievement) = unlocked.last()
+        && let Ok(mut feedback_text) = feedback_text_query.single_mut()
+    {
+        feedback_text.0 = locale.tf(
+            TextKey::AchievementUnlocked,
+            &[&locale.t(achievement_name(*achievement))],
+        );
+    }
+}
+
+/// 実績の表示名
+fn achievement_name(achievement: Achievement) -> TextKey {
+    match achievement {
+        Achievement::FirstOreMined => TextKey::AchievementFirstOreMined,
+        Achievement::FirstPossession => TextKey::AchievementFirstPossession,
+        Achievement::Builder => TextKey::AchievementBuilder,
+    }
+}
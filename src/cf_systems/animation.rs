@@ -1,141 +1,239 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use rand::seq::IndexedRandom;
+
+use crate::components::{Fox, FoxPathTarget, Spooked};
+use crate::constants::*;
+use crate::resources::{GameRng, PossessionMode};
 
-use crate::components::Fox;
-use crate::resources::PossessionMode;
+/// 待機・歩き・走りのアニメーションの数（これより後ろは別の待機アニメーション）
+const FOX_BASE_ANIMATION_COUNT: usize = 3;
 
 /// キツネのアニメーションクリップを事前にロードするためのリソース
+///
+/// 待機・歩き・走りの順に並べ、その後ろに[`FOX_IDLE_VARIANT_ANIMATIONS`]の待機アニメーションを並べる。
 #[derive(Resource)]
 pub struct FoxAnimationClips {
-    pub clips: [Handle<AnimationClip>; 3],
+    pub clips: Vec<Handle<AnimationClip>>,
 }
 
 impl FromWorld for FoxAnimationClips {
     fn from_world(world: &mut World) -> Self {
         let asset_server = world.resource::<AssetServer>();
         Self {
-            clips: [
-                asset_server.load(GltfAssetLabel::Animation(0).from_asset("animated/Fox.glb")),
-                asset_server.load(GltfAssetLabel::Animation(1).from_asset("animated/Fox.glb")),
-                asset_server.load(GltfAssetLabel::Animation(2).from_asset("animated/Fox.glb")),
-            ],
+            clips: (0..FOX_BASE_ANIMATION_COUNT)
+                .chain(FOX_IDLE_VARIANT_ANIMATIONS.iter().copied())
+                .map(|index| {
+                    asset_server
+                        .load(GltfAssetLabel::Animation(index).from_asset("animated/Fox.glb"))
+                })
+                .collect(),
         }
     }
 }
 
 /// キツネの現在の移動状態を追跡するコンポーネント
-#[derive(Component)]
+#[derive(Component, Default)]
 pub struct FoxAnimationState {
     pub is_moving: bool,
-    pub current_animation: usize,
-    pub animation_graphs: [Option<(Handle<AnimationGraph>, AnimationNodeIndex)>; 3],
-}
-
-impl Default for FoxAnimationState {
-    fn default() -> Self {
-        Self {
-            is_moving: false,
-            current_animation: 0,
-            animation_graphs: [None, None, None],
-        }
-    }
+    /// 現在再生中のアニメーション番号（未再生の場合は`None`）
+    pub current_animation: Option<usize>,
+    /// 直前に再生していたアニメーションノード（クロスフェード元）
+    pub previous_node: Option<AnimationNodeIndex>,
+    /// 全クリップを登録したアニメーショングラフと各クリップのノード
+    pub animation_graph: Option<(Handle<AnimationGraph>, Vec<AnimationNodeIndex>)>,
+    /// 子孫から見つけたAnimationPlayerを持つエンティティ（毎フレームの探索を避けるためのキャッシュ）
+    pub player: Option<Entity>,
+    /// 通常の待機アニメーションを続けて再生している時間（秒）
+    pub idle_secs: f32,
 }
 
 /// Foxのアニメーションを再生するシステム
+///
+/// 待機が[`FOX_IDLE_VARIANT_DELAY_SECS`]続くと、別の待機アニメーションを[`GameRng`]で選んで1回だけ再生し、
+/// 終わったら通常の待機に戻す。移動やダッシュのアニメーションは常にこれより優先する。
+#[allow(clippy::too_many_arguments)]
 pub fn play_fox_animation(
     mut commands: Commands,
     mut graphs: ResMut<Assets<AnimationGraph>>,
-    fox_query: Query<(Entity, &Transform), With<Fox>>,
+    fox_query: Query<(Entity, Has<FoxPathTarget>, Has<Spooked>), With<Fox>>,
     children_query: Query<&Children>,
-    mut player_query: Query<(Entity, &mut AnimationPlayer, Option<&AnimationGraphHandle>)>,
+    mut player_query: Query<(
+        Entity,
+        &mut AnimationPlayer,
+        Option<&mut AnimationTransitions>,
+    )>,
     animation_clips: Res<Assets<AnimationClip>>,
     fox_animation_clips: Res<FoxAnimationClips>,
     possession_mode: Res<PossessionMode>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut animation_state_query: Query<&mut FoxAnimationState>,
     dash_state: Res<crate::resources::DashInputState>,
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
 ) {
-    for (fox_entity, _fox_transform) in fox_query.iter() {
-        // Foxエンティティの子孫からAnimationPlayerを持つエンティティを探す
-        if let Some(player_entity) =
-            find_animation_player(fox_entity, &children_query, &player_query)
-            && let Ok((entity, mut player, graph_handle)) = player_query.get_mut(player_entity)
-        {
-            // アニメーション状態を取得または作成
-            let mut anim_state = if let Ok(state) = animation_state_query.get_mut(fox_entity) {
-                state
-            } else {
-                commands
-                    .entity(fox_entity)
-                    .insert(FoxAnimationState::default());
+    for (fox_entity, is_walking_to_target, spooked) in fox_query.iter() {
+        // アニメーション状態を取得または作成
+        let Ok(mut anim_state) = animation_state_query.get_mut(fox_entity) else {
+            commands
+                .entity(fox_entity)
+                .insert(FoxAnimationState::default());
+            continue;
+        };
+
+        // キャッシュしたAnimationPlayerが消えていれば、Foxエンティティの子孫から探し直す
+        let cached_player = anim_state
+            .player
+            .filter(|player| player_query.contains(*player));
+        let Some(player_entity) = cached_player
+            .or_else(|| find_animation_player(fox_entity, &children_query, &player_query))
+        else {
+            continue;
+        };
+        if cached_player.is_none() {
+            // 新しく見つけたプレイヤーにはグラフが付いていないため、再生状態を作り直す
+            *anim_state = FoxAnimationState {
+                player: Some(player_entity),
+                ..default()
+            };
+        }
+
+        if let Ok((entity, mut player, transitions)) = player_query.get_mut(player_entity) {
+            // 全クリップの読み込み完了後、クロスフェード用に1つのグラフへまとめる
+            if anim_state.animation_graph.is_none() {
+                let all_loaded = fox_animation_clips
+                    .clips
+                    .iter()
+                    .all(|clip| animation_clips.get(clip).is_some());
+                if !all_loaded {
+                    continue;
+                }
+
+                let (graph, nodes) =
+                    AnimationGraph::from_clips(fox_animation_clips.clips.iter().cloned());
+                let graph_handle = graphs.add(graph);
+
+                // AnimationGraphHandleとAnimationTransitionsをプレイヤーに追加
+                commands.entity(entity).insert((
+                    AnimationGraphHandle(graph_handle.clone()),
+                    AnimationTransitions::new(),
+                ));
+
+                anim_state.animation_graph = Some((graph_handle, nodes));
+                continue;
+            }
+
+            // AnimationTransitionsの挿入が反映されるまで待つ
+            let Some(mut transitions) = transitions else {
                 continue;
             };
 
-            // Possessionモードで移動中かどうかを判定
-            let is_moving = possession_mode.is_active
-                && (keyboard_input.pressed(KeyCode::KeyW)
-                    || keyboard_input.pressed(KeyCode::KeyS)
-                    || keyboard_input.pressed(KeyCode::KeyA)
-                    || keyboard_input.pressed(KeyCode::KeyD));
+            // Possessionモードで移動中、または移動先に向かって歩いている最中かどうかを判定
+            let is_moving = is_walking_to_target
+                || possession_mode.is_active
+                    && (keyboard_input.pressed(KeyCode::KeyW)
+                        || keyboard_input.pressed(KeyCode::KeyS)
+                        || keyboard_input.pressed(KeyCode::KeyA)
+                        || keyboard_input.pressed(KeyCode::KeyD));
+
+            // 通常の待機が続いた時間を数える（移動したり別の待機に切り替えたりしたら数え直す）
+            if !is_moving && anim_state.current_animation == Some(0) {
+                anim_state.idle_secs += time.delta_secs();
+            } else {
+                anim_state.idle_secs = 0.0;
+            }
+
+            // 再生中の別の待機アニメーションがまだ終わっていないか
+            let playing_idle_variant = anim_state
+                .current_animation
+                .filter(|&animation| animation >= FOX_BASE_ANIMATION_COUNT)
+                .zip(anim_state.animation_graph.as_ref())
+                .and_then(|(animation, (_, nodes))| player.animation(nodes[animation]))
+                .is_some_and(|active_animation| !active_animation.is_finished());
+
+            // 嵐におびえて走っているか
+            let is_fleeing = spooked && is_walking_to_target;
 
             // 使用するアニメーションを決定
-            // ダッシュ中: Animation2, 移動中: Animation1, 待機中: Animation0
-            let target_animation = if is_moving && dash_state.is_dashing {
+            // ダッシュ中・おびえて走っている時: Animation2, 移動中: Animation1,
+            // 待機中: Animation0（おびえていなければ時々別の待機アニメーション）
+            let target_animation = if is_moving && (dash_state.is_dashing || is_fleeing) {
                 2
             } else if is_moving {
                 1
+            } else if spooked {
+                0
+            } else if playing_idle_variant {
+                anim_state.current_animation.unwrap_or(0)
+            } else if anim_state.idle_secs >= FOX_IDLE_VARIANT_DELAY_SECS {
+                let variants: Vec<usize> =
+                    (FOX_BASE_ANIMATION_COUNT..fox_animation_clips.clips.len()).collect();
+                variants.choose(&mut rng.0).copied().unwrap_or(0)
             } else {
                 0
             };
 
-            // 必要なアニメーショングラフを取得または作成
-            if anim_state.animation_graphs[target_animation].is_none() {
-                // 事前にロードされたアニメーションクリップを取得
-                let animation_clip = fox_animation_clips.clips[target_animation].clone();
-
-                // アニメーションクリップが読み込まれているかチェック
-                if animation_clips.get(&animation_clip).is_some() {
-                    // 新しいアニメーショングラフを作成
-                    let mut graph = AnimationGraph::new();
-
-                    // グラフにアニメーションを追加
-                    let animation_index = graph.add_clip(animation_clip, 1.0, graph.root);
-
-                    // グラフをアセットに追加
-                    let graph_handle = graphs.add(graph);
-
-                    // キャッシュに保存
-                    anim_state.animation_graphs[target_animation] =
-                        Some((graph_handle, animation_index));
+            // アニメーションが変わった場合のみ更新
+            if anim_state.current_animation != Some(target_animation)
+                && let Some((_, nodes)) = &anim_state.animation_graph
+            {
+                let target_node = nodes[target_animation];
+
+                // 初回再生時はフェードせずに即座に切り替える
+                let transition_duration = if anim_state.current_animation.is_some() {
+                    Duration::from_secs_f32(FOX_ANIMATION_TRANSITION_SECS)
+                } else {
+                    Duration::ZERO
+                };
+
+                // 直前のノードからクロスフェードして再生（別の待機アニメーションは1回だけ）
+                anim_state.previous_node = transitions.get_main_animation();
+                let active_animation =
+                    transitions.play(&mut player, target_node, transition_duration);
+                if target_animation < FOX_BASE_ANIMATION_COUNT {
+                    active_animation.repeat();
                 }
+
+                // 状態を更新
+                anim_state.is_moving = is_moving;
+                anim_state.current_animation = Some(target_animation);
             }
 
-            // アニメーションが変わった場合のみ更新
-            if anim_state.current_animation != target_animation {
-                if let Some((graph_handle, animation_index)) =
-                    &anim_state.animation_graphs[target_animation]
-                {
-                    // AnimationGraphHandleをエンティティに追加または更新
-                    commands
-                        .entity(entity)
-                        .insert(AnimationGraphHandle(graph_handle.clone()));
-
-                    // アニメーションを再生
-                    player.play(*animation_index).repeat();
-
-                    // 状態を更新
-                    anim_state.is_moving = is_moving;
-                    anim_state.current_animation = target_animation;
-                }
+            // 移動速度に応じて再生速度を調整（ダッシュ中は脚の動きを速くする）
+            let animation_speed = if is_moving {
+                let movement_speed = if dash_state.is_dashing {
+                    FOX_DASH_SPEED
+                } else if is_fleeing {
+                    FOX_WALK_SPEED * FOX_SPOOKED_SPEED_FACTOR
+                } else {
+                    FOX_WALK_SPEED
+                };
+                1.0 + (movement_speed / FOX_WALK_SPEED - 1.0) * FOX_ANIMATION_SPEED_FACTOR
+            } else {
+                1.0
+            };
+
+            if let Some(main_node) = transitions.get_main_animation()
+                && let Some(active_animation) = player.animation_mut(main_node)
+            {
+                active_animation.set_speed(animation_speed);
             }
         }
     }
 }
 
 /// 子エンティティを再帰的に探索してAnimationPlayerを持つエンティティを見つける
+///
+/// 見つけたエンティティは[`FoxAnimationState::player`]にキャッシュし、消えるまで探し直さない。
 fn find_animation_player(
     entity: Entity,
     children_query: &Query<&Children>,
-    player_query: &Query<(Entity, &mut AnimationPlayer, Option<&AnimationGraphHandle>)>,
+    player_query: &Query<(
+        Entity,
+        &mut AnimationPlayer,
+        Option<&mut AnimationTransitions>,
+    )>,
 ) -> Option<Entity> {
     // 現在のエンティティがAnimationPlayerを持っているかチェック
     if player_query.contains(entity) {
@@ -0,0 +1,71 @@
+//! 効果音
+//!
+//! 音声ファイルを同梱していないため、効果音は[`Pitch`]で合成した短い音を使う。
+//! 音は起動時に一度だけアセットとして登録し、鳴らす時はハンドルを使い回す。
+
+use std::time::Duration;
+
+use bevy::audio::{Pitch, Volume};
+use bevy::prelude::*;
+
+use crate::constants::*;
+
+/// 効果音のアセット
+#[derive(Resource)]
+pub struct SoundEffects {
+    /// キツネの鳴き声
+    pub fox_bark: Handle<Pitch>,
+    /// 操作できなかった時のエラー音
+    pub error: Handle<Pitch>,
+    /// アイテムを拾った時の音
+    pub pickup: Handle<Pitch>,
+    /// 稲光の後の雷鳴
+    pub thunder: Handle<Pitch>,
+}
+
+impl FromWorld for SoundEffects {
+    fn from_world(world: &mut World) -> Self {
+        let mut pitches = world.resource_mut::<Assets<Pitch>>();
+        let mut tone = |frequency: f32, secs: f32| {
+            pitches.add(Pitch::new(frequency, Duration::from_secs_f32(secs)))
+        };
+        let fox_bark = tone(FOX_BARK_FREQUENCY, FOX_BARK_DURATION_SECS);
+        let error = tone(ERROR_SOUND_FREQUENCY, ERROR_SOUND_DURATION_SECS);
+        let pickup = tone(PICKUP_SOUND_FREQUENCY, PICKUP_SOUND_DURATION_SECS);
+        let thunder = tone(THUNDER_SOUND_FREQUENCY, THUNDER_SOUND_DURATION_SECS);
+        Self {
+            fox_bark,
+            error,
+            pickup,
+            thunder,
+        }
+    }
+}
+
+/// 効果音を1回鳴らす（鳴り終わったら自動で削除される）
+///
+/// `volume`には[`GlobalVolume`]が掛かるため、全体の音量設定に従う。
+pub fn play_sound(commands: &mut Commands, sound: &Handle<Pitch>, volume: f32) {
+    commands.spawn((
+        AudioPlayer(sound.clone()),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume)),
+    ));
+}
+
+/// キツネの鳴き声を1回鳴らす
+///
+/// 前回鳴らしてから[`FOX_BARK_COOLDOWN_SECS`]秒経っていない場合は鳴らさない。
+/// `last_played`には最後に鳴らした時刻（起動からの経過秒）を記録する。
+pub fn play_fox_bark(
+    commands: &mut Commands,
+    sounds: &SoundEffects,
+    now: f32,
+    last_played: &mut Option<f32>,
+) {
+    if last_played.is_some_and(|last| now - last < FOX_BARK_COOLDOWN_SECS) {
+        return;
+    }
+    *last_played = Some(now);
+
+    play_sound(commands, &sounds.fox_bark, FOX_BARK_VOLUME);
+}
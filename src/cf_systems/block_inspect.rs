@@ -0,0 +1,86 @@
+//! ブロックの情報パネル
+//!
+//! Altを押しながらブロックにカーソルを乗せると、そのブロックのマス目の座標・種類・
+//! 選択可能かどうか・タイマーの値を画面左のパネルに表示する。
+//! ブロックのアクションメニューで「調べる」を押した場合も、メニューを閉じるまで同じパネルを表示する。
+//! レベルデザインやデバッグの時に、各[`Block`]が持っているデータを確かめるのに使う。
+
+use bevy::prelude::*;
+
+use crate::cf_tool;
+use crate::components::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+
+/// Altを押しながらホバーしているブロックの情報をパネルに表示するシステム
+///
+/// ホバー中のブロックはピッキングのオブザーバーが記録した[`HoveredBlock`]を使う。
+/// Altを離すかブロックから外れたらパネルを隠す。
+/// Altを押していない間は、アクションメニューで「調べる」を押したブロックを表示する。
+#[allow(clippy::too_many_arguments)]
+pub fn inspect_block(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    hovered_block: Res<HoveredBlock>,
+    block_menu_query: Query<&BlockActionMenu>,
+    block_query: Query<(
+        &Transform,
+        &BlockType,
+        Option<&Mineable>,
+        Option<&cf_tool::timer::Timer>,
+    )>,
+    selectable_query: Query<(), With<Selectable>>,
+    mut panel_query: Query<(&mut Text, &mut Node), With<BlockInspectPanel>>,
+    field_config: Res<FieldConfig>,
+    locale: Res<Locale>,
+) {
+    let Ok((mut text, mut node)) = panel_query.single_mut() else {
+        return;
+    };
+
+    let inspecting = keyboard_input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    let target = if inspecting {
+        hovered_block.entity
+    } else {
+        block_menu_query
+            .iter()
+            .find(|menu| menu.inspecting)
+            .map(|menu| menu.block)
+    };
+    let block = target.and_then(|entity| block_query.get(entity).ok().map(|block| (entity, block)));
+
+    let Some((entity, (transform, block_type, mineable, timer))) = block else {
+        if node.display != Display::None {
+            node.display = Display::None;
+        }
+        return;
+    };
+
+    let tile = field_config.grid_coords(transform.translation);
+    let type_name = locale.t(match block_type {
+        BlockType::Grass => TextKey::BlockTypeGrass,
+        BlockType::Stone => TextKey::BlockTypeStone,
+        BlockType::Water => TextKey::BlockTypeWater,
+    });
+    let selectable = locale.t(if selectable_query.contains(entity) {
+        TextKey::Yes
+    } else {
+        TextKey::No
+    });
+    let timer = timer.map_or("-".to_string(), |timer| format!("{:.1}s", timer.time));
+
+    let mut info = locale.tf(
+        TextKey::BlockInspect,
+        &[&tile.x, &tile.y, &type_name, &selectable, &timer],
+    );
+    if let Some(mineable) = mineable {
+        info.push('\n');
+        info.push_str(&locale.tf(TextKey::BlockInspectMineable, &[&mineable.hits_remaining]));
+    }
+
+    if text.0 != info {
+        text.0 = info;
+    }
+    if node.display != Display::Flex {
+        node.display = Display::Flex;
+    }
+}
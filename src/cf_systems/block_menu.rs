@@ -0,0 +1,247 @@
+//! ブロックのアクションメニュー
+//!
+//! 選択可能なブロックか採掘できるブロックを右クリックすると、キツネのアクションメニューと同じように
+//! ブロックの近くに「片付ける」「タイマーをリセット」「調べる」「壊す」のボタンを表示する。
+//! 左クリックでばらばらに行っていたブロックへの操作を、1つのメニューから選べるようにする。
+//! メニューは他の場所をクリックするかEscキーで閉じる。
+
+use bevy::prelude::*;
+
+use crate::cf_systems::camera::main_camera;
+use crate::cf_systems::game_logic::{action_menu_position, take_block_object};
+use crate::cf_tool;
+use crate::components::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+
+/// 右クリックに応じてブロックのアクションメニューを開閉するシステム
+///
+/// ブロックが右クリックされた場合はメニューを開き直す（キツネのアクションメニューは閉じる）。
+/// アイテムを選択中の右クリックは選択の解除に使うため、移動モード中・憑依中と同じく開かない。
+/// 他の場所が左クリックされた場合はメニューを閉じる。
+#[allow(clippy::too_many_arguments)]
+pub fn handle_block_action_menu_click(
+    mut block_right_clicked: MessageReader<BlockRightClicked>,
+    mut block_clicked: MessageReader<BlockClicked>,
+    mut fox_clicked: MessageReader<FoxClicked>,
+    mut rock_clicked: MessageReader<RockClicked>,
+    mut mineable_clicked: MessageReader<MineableBlockClicked>,
+    mut empty_clicked: MessageReader<EmptySpaceClicked>,
+    mut commands: Commands,
+    menu_query: Query<Entity, With<BlockActionMenu>>,
+    fox_menu_query: Query<Entity, With<FoxActionMenu>>,
+    block_query: Query<&GlobalTransform, With<Block>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    (selected_slot, move_mode, possession_mode): (
+        Res<SelectedItemSlot>,
+        Res<FoxMoveMode>,
+        Res<PossessionMode>,
+    ),
+    locale: Res<Locale>,
+) {
+    let clicked_elsewhere = block_clicked.read().count()
+        + fox_clicked.read().count()
+        + rock_clicked.read().count()
+        + mineable_clicked.read().count()
+        + empty_clicked.read().count()
+        > 0;
+
+    let can_open =
+        selected_slot.slot_index.is_none() && !move_mode.is_active && !possession_mode.is_active;
+    let right_clicked = block_right_clicked
+        .read()
+        .last()
+        .map(|BlockRightClicked(block)| *block)
+        .filter(|_| can_open);
+
+    if let Some(block) = right_clicked {
+        for menu_entity in menu_query.iter().chain(fox_menu_query.iter()) {
+            commands.entity(menu_entity).despawn();
+        }
+
+        if let Ok(block_transform) = block_query.get(block)
+            && let Some((camera, camera_transform)) = main_camera(camera_query.single())
+            && let Ok(window) = window_query.single()
+        {
+            spawn_block_action_menu(
+                &mut commands,
+                block,
+                block_transform.translation(),
+                camera,
+                camera_transform,
+                window.size(),
+                *locale,
+            );
+        }
+    } else if clicked_elsewhere {
+        for menu_entity in menu_query.iter() {
+            commands.entity(menu_entity).despawn();
+        }
+    }
+}
+
+/// ブロックのアクションメニュー UI を生成する。
+///
+/// ブロックの3D位置を画面座標に変換し、キツネのアクションメニューと同じ規則で
+/// ウィンドウからはみ出さない位置に配置する。
+///
+/// - **片付ける**: ブロックの上の植物・柵・松明をアイテムスロットに戻す。
+/// - **タイマーをリセット**: ブロックのタイマーを0に戻す。
+/// - **調べる**: メニューを開いている間、ブロックの情報パネルを表示する（もう一度押すと隠す）。
+/// - **壊す**: 採掘できるブロックを選択中のツルハシで1回叩く。
+#[allow(clippy::too_many_arguments)]
+fn spawn_block_action_menu(
+    commands: &mut Commands,
+    block: Entity,
+    block_position: Vec3,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    window_size: Vec2,
+    locale: Locale,
+) {
+    let Ok(screen_pos) = camera.world_to_viewport(camera_transform, block_position) else {
+        return;
+    };
+
+    let menu_pos = action_menu_position(
+        screen_pos,
+        window_size,
+        Vec2::new(BLOCK_ACTION_MENU_WIDTH, BLOCK_ACTION_MENU_HEIGHT),
+    );
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(menu_pos.x),
+                top: Val::Px(menu_pos.y),
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+            BlockActionMenu {
+                block,
+                inspecting: false,
+            },
+        ))
+        .with_children(|parent| {
+            spawn_button!(parent, {
+                size: (70.0, 30.0),
+                text: locale.t(TextKey::ClearItemButton),
+                font_size: 12.0,
+                bg_color: (0.5, 0.4, 0.3),
+                border_color: (0.7, 0.6, 0.5),
+                component: BlockActionButton::ClearItem,
+            });
+
+            spawn_button!(parent, {
+                size: (90.0, 30.0),
+                text: locale.t(TextKey::ResetTimerButton),
+                font_size: 11.0,
+                bg_color: (0.3, 0.5, 0.7),
+                border_color: (0.5, 0.7, 0.9),
+                component: BlockActionButton::ResetTimer,
+            });
+
+            spawn_button!(parent, {
+                size: (70.0, 30.0),
+                text: locale.t(TextKey::InspectButton),
+                font_size: 12.0,
+                bg_color: (0.3, 0.6, 0.4),
+                border_color: (0.5, 0.8, 0.6),
+                component: BlockActionButton::Inspect,
+            });
+
+            spawn_button!(parent, {
+                size: (55.0, 30.0),
+                text: locale.t(TextKey::BreakButton),
+                font_size: 14.0,
+                bg_color: (0.6, 0.3, 0.3),
+                border_color: (0.8, 0.5, 0.5),
+                component: BlockActionButton::Break,
+            });
+        });
+}
+
+/// ブロックのアクションメニューのボタンクリックを処理するシステム
+///
+/// 「調べる」以外のボタンを押した後はメニューを閉じる。
+/// 「片付ける」は左クリックでの収穫・撤去と同じ処理を行い、
+/// 「壊す」は[`MineableBlockClicked`]を送信して左クリックでの採掘と同じ処理に任せる。
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn handle_block_action_buttons(
+    interaction_query: Query<(&Interaction, &BlockActionButton), Changed<Interaction>>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    mut commands: Commands,
+    mut menu_query: Query<(Entity, &mut BlockActionMenu)>,
+    mut timer_query: Query<&mut cf_tool::timer::Timer>,
+    mineable_query: Query<(), With<Mineable>>,
+    (plant_query, fence_query, torch_query): (
+        Query<(Entity, &Plant, &Transform)>,
+        Query<(Entity, &Fence, &Transform)>,
+        Query<(Entity, &Torch, &Transform)>,
+    ),
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut fenced_edges: ResMut<FencedEdges>,
+    mut history: ResMut<ActionHistory>,
+    mut inventory_full: MessageWriter<InventoryFull>,
+    mut mineable_clicked: MessageWriter<MineableBlockClicked>,
+    locale: Res<Locale>,
+) {
+    for (interaction, button_type) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut feedback_text) = feedback_text_query.single_mut() else {
+            return;
+        };
+        let Ok((menu_entity, mut menu)) = menu_query.single_mut() else {
+            return;
+        };
+        let block = menu.block;
+
+        match button_type {
+            BlockActionButton::ClearItem => {
+                if !take_block_object(
+                    &mut commands,
+                    block,
+                    &mut feedback_text,
+                    &plant_query,
+                    &fence_query,
+                    &torch_query,
+                    &mut item_slot_query,
+                    &mut fenced_edges,
+                    &mut history,
+                    &mut inventory_full,
+                    *locale,
+                ) {
+                    feedback_text.0 = locale.t(TextKey::BlockNothingToClear).to_string();
+                }
+            }
+            BlockActionButton::ResetTimer => {
+                if let Ok(mut timer) = timer_query.get_mut(block) {
+                    timer.time = 0.0;
+                    feedback_text.0 = locale.tf(TextKey::TimerReset, &[&timer.name]);
+                } else {
+                    feedback_text.0 = locale.t(TextKey::BlockNoTimer).to_string();
+                }
+            }
+            BlockActionButton::Inspect => {
+                menu.inspecting = !menu.inspecting;
+                continue;
+            }
+            BlockActionButton::Break => {
+                if mineable_query.contains(block) {
+                    mineable_clicked.write(MineableBlockClicked(block));
+                } else {
+                    feedback_text.0 = locale.t(TextKey::BlockNotBreakable).to_string();
+                }
+            }
+        }
+
+        commands.entity(menu_entity).despawn();
+    }
+}
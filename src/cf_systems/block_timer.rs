@@ -0,0 +1,132 @@
+//! ブロックのタイマー
+//!
+//! 選択可能なブロックはそれぞれタイマーを持ち、クリックするとリセットされる。
+//! 経過時間はブロック上面に重ねた半透明の板の色で表し、
+//! リセット直後の緑から時間が経つにつれて赤に変わっていく。
+//! 赤になりきった時（タイマーのしきい値に達した時）は土ぼこりを出して知らせる。
+
+use bevy::prelude::*;
+
+use crate::cf_systems::dust::{DustAssets, spawn_dust_puff};
+use crate::cf_tool;
+use crate::components::{Block, BlockTimerTint, Selectable};
+use crate::constants::*;
+use crate::events::TimerThresholdReached;
+
+/// タイマーの色の板に使う共有メッシュと、色の段階ごとのマテリアル
+#[derive(Resource)]
+pub struct BlockTimerTintAssets {
+    pub mesh: Handle<Mesh>,
+    pub materials: Vec<Handle<StandardMaterial>>,
+}
+
+impl FromWorld for BlockTimerTintAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Plane3d::new(Vec3::Y, Vec2::splat(BLOCK_HALF_SIZE)));
+
+        let fresh = Vec4::from(BLOCK_TIMER_FRESH_COLOR);
+        let stale = Vec4::from(BLOCK_TIMER_STALE_COLOR);
+        let mut assets = world.resource_mut::<Assets<StandardMaterial>>();
+        let materials = (0..BLOCK_TIMER_TINT_STEPS)
+            .map(|step| {
+                let t = step as f32 / (BLOCK_TIMER_TINT_STEPS - 1) as f32;
+                let color = fresh.lerp(stale, t);
+                assets.add(StandardMaterial {
+                    base_color: Color::srgba(color.x, color.y, color.z, color.w),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                })
+            })
+            .collect();
+
+        Self { mesh, materials }
+    }
+}
+
+impl BlockTimerTintAssets {
+    /// 経過時間に対応する色の段階のマテリアルを返す
+    pub fn material_for(&self, elapsed_secs: f32) -> &Handle<StandardMaterial> {
+        let t = (elapsed_secs / BLOCK_TIMER_STALE_SECS).clamp(0.0, 1.0);
+        let step = (t * (self.materials.len() - 1) as f32).round() as usize;
+        &self.materials[step]
+    }
+}
+
+/// タイマーを持たない選択可能なブロックに、タイマーと色の板を付けるシステム
+///
+/// フィールドの生成時だけでなく、建築エリアの拡張やフィールドの作り直しで
+/// 新しく選択可能になったブロックにも付ける。
+pub fn attach_block_timers(
+    mut commands: Commands,
+    block_query: Query<(Entity, &Transform, Has<cf_tool::timer::Timer>), With<Selectable>>,
+    assets: Res<BlockTimerTintAssets>,
+) {
+    for (block, block_transform, has_timer) in block_query.iter() {
+        if has_timer {
+            continue;
+        }
+
+        // ブロックはスケールされているため、上面のワールド座標からローカル座標を求める
+        let top = block_transform.translation + Vec3::Y * (BLOCK_HALF_SIZE + BLOCK_TIMER_TINT_LIFT);
+        let local = GlobalTransform::from(Transform::from_translation(top))
+            .reparented_to(&GlobalTransform::from(*block_transform));
+
+        let tint = commands
+            .spawn((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material_for(0.0).clone()),
+                local,
+                Pickable::IGNORE,
+                BlockTimerTint { block },
+            ))
+            .id();
+
+        commands
+            .entity(block)
+            .insert(cf_tool::timer::Timer {
+                time: 0.0,
+                name: "Block".to_string(),
+                threshold: Some(BLOCK_TIMER_STALE_SECS),
+            })
+            .add_child(tint);
+    }
+}
+
+/// ブロックのタイマーの経過時間に合わせて色の板のマテリアルを切り替えるシステム
+pub fn update_block_timer_tint(
+    timer_query: Query<&cf_tool::timer::Timer, With<Block>>,
+    mut tint_query: Query<(&BlockTimerTint, &mut MeshMaterial3d<StandardMaterial>)>,
+    assets: Res<BlockTimerTintAssets>,
+) {
+    for (tint, mut material) in tint_query.iter_mut() {
+        let Ok(timer) = timer_query.get(tint.block) else {
+            continue;
+        };
+
+        let target = assets.material_for(timer.time);
+        if material.0 != *target {
+            material.0 = target.clone();
+        }
+    }
+}
+
+/// ブロックのタイマーが[`BLOCK_TIMER_STALE_SECS`]に達した時に、
+/// ブロックの上に土ぼこりを出して知らせるシステム
+pub fn puff_stale_blocks(
+    mut threshold_reached: MessageReader<TimerThresholdReached>,
+    block_query: Query<&GlobalTransform, With<Block>>,
+    mut commands: Commands,
+    dust_assets: Res<DustAssets>,
+) {
+    for TimerThresholdReached(entity) in threshold_reached.read() {
+        let Ok(block_transform) = block_query.get(*entity) else {
+            continue;
+        };
+
+        let position = block_transform.translation() + Vec3::Y * BLOCK_HALF_SIZE;
+        spawn_dust_puff(&mut commands, &dust_assets, position, 0.0);
+    }
+}
@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+use crate::traits::JsonSave;
+
+/// Bキーで鉱石を消費して選択可能エリアを1周広げるシステム
+///
+/// アイテムスロットの鉱石を[`BUILD_AREA_EXPAND_ORE_COST`]個消費する。
+/// 実際にブロックを選択可能にするのは[`apply_build_area`]が担当する。
+#[allow(clippy::too_many_arguments)]
+pub fn expand_build_area(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut build_area: ResMut<BuildArea>,
+    field_config: Res<FieldConfig>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    camera_mode: Res<CameraMode>,
+) {
+    if camera_mode.is_spectator() || !keyboard_input.just_pressed(KeyCode::KeyB) {
+        return;
+    }
+
+    let Ok(mut feedback_text) = feedback_text_query.single_mut() else {
+        return;
+    };
+
+    if build_area.expansion >= field_config.max_expansion() {
+        feedback_text.0 = locale.t(TextKey::BuildAreaMaxed).to_string();
+        return;
+    }
+
+    let mut ore_slots: Vec<_> = item_slot_query
+        .iter_mut()
+        .filter(|slot| matches!(slot.item, Some(ItemType::Ore)))
+        .collect();
+
+    if ore_slots.len() < BUILD_AREA_EXPAND_ORE_COST {
+        feedback_text.0 = locale.tf(TextKey::NotEnoughOre, &[&BUILD_AREA_EXPAND_ORE_COST]);
+        return;
+    }
+
+    ore_slots.sort_by_key(|slot| slot.slot_index);
+    for slot in ore_slots.iter_mut().take(BUILD_AREA_EXPAND_ORE_COST) {
+        slot.item = None;
+
+        // 選択中のスロットの鉱石を消費した場合は選択を解除
+        if selected_slot.slot_index == Some(slot.slot_index) {
+            selected_slot.slot_index = None;
+            selected_slot.item_type = None;
+        }
+    }
+
+    build_area.expansion += 1;
+}
+
+/// 選択可能エリアが広がった時に、新しくエリアに入ったブロックを選択可能にするシステム
+///
+/// 選択可能にするのは草のブロックだけで、石や水のブロックはエリアに入ってもそのまま残す。
+/// レベルファイルの配置では、マスの`selectable`が`false`のブロックは選択可能にしない。
+/// 起動時は保存されていたエリアまで広げ、フィードバックは表示しない。
+#[allow(clippy::type_complexity)]
+pub fn apply_build_area(
+    mut commands: Commands,
+    build_area: Res<BuildArea>,
+    field_config: Res<FieldConfig>,
+    block_query: Query<(Entity, &Transform, &BlockType), (With<Block>, Without<Selectable>)>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if !build_area.is_changed() {
+        return;
+    }
+
+    let mut unlocked = 0;
+    for (entity, transform, block_type) in block_query.iter() {
+        if *block_type != BlockType::Grass {
+            continue;
+        }
+
+        let tile = field_config.grid_coords(transform.translation);
+        if field_config.tile_selectable(tile.x, tile.y, build_area.expansion) {
+            commands.entity(entity).insert(Selectable);
+            unlocked += 1;
+        }
+    }
+
+    if unlocked > 0
+        && !build_area.is_added()
+        && let Ok(mut feedback_text) = feedback_text_query.single_mut()
+    {
+        feedback_text.0 = locale.tf(TextKey::BuildAreaExpanded, &[&unlocked]);
+    }
+}
+
+/// Oキーで選択可能エリアの枠線の常時表示を切り替えるシステム
+///
+/// 起動直後の表示中に隠した場合は、その場で消す。
+pub fn toggle_build_area_outline(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut outline: ResMut<BuildAreaOutline>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyO) {
+        return;
+    }
+
+    outline.always_visible = !outline.always_visible;
+    outline.intro_remaining = 0.0;
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale
+            .t(if outline.always_visible {
+                TextKey::BuildAreaOutlineShown
+            } else {
+                TextKey::BuildAreaOutlineHidden
+            })
+            .to_string();
+    }
+}
+
+/// 選択可能エリアの境界に枠線を描画するシステム
+///
+/// 常時表示がオフの場合は、起動直後とエリアが広がった直後だけ表示し、
+/// 最後の[`BUILD_AREA_OUTLINE_FADE_SECS`]秒でフェードアウトさせる。
+/// 枠線は毎フレーム[`FieldConfig::selectable_rect`]から求めるため、
+/// エリアの拡張やフィールドの作り直しにそのまま追従する。
+pub fn draw_build_area_outline(
+    mut gizmos: Gizmos,
+    mut outline: ResMut<BuildAreaOutline>,
+    build_area: Res<BuildArea>,
+    field_config: Res<FieldConfig>,
+    time: Res<Time>,
+) {
+    if build_area.is_changed() && !build_area.is_added() {
+        outline.intro_remaining = BUILD_AREA_OUTLINE_INTRO_SECS;
+    }
+    outline.intro_remaining = (outline.intro_remaining - time.delta_secs()).max(0.0);
+
+    let opacity = if outline.always_visible {
+        1.0
+    } else {
+        (outline.intro_remaining / BUILD_AREA_OUTLINE_FADE_SECS).min(1.0)
+    };
+    if opacity <= 0.0 {
+        return;
+    }
+
+    let rect = field_config.selectable_rect(build_area.expansion);
+    // 矩形はXY平面上に描かれるので、X軸周りに倒して地面に沿わせる
+    let isometry = Isometry3d::new(
+        Vec3::new(
+            rect.center().x,
+            BLOCK_HALF_SIZE + BUILD_AREA_OUTLINE_LIFT,
+            rect.center().y,
+        ),
+        Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+    );
+    let color = Color::srgba(
+        BUILD_AREA_OUTLINE_COLOR.0,
+        BUILD_AREA_OUTLINE_COLOR.1,
+        BUILD_AREA_OUTLINE_COLOR.2,
+        BUILD_AREA_OUTLINE_COLOR.3 * opacity,
+    );
+    gizmos.rect(isometry, rect.size(), color);
+}
+
+/// 選択可能エリアが変わったらセーブファイルに書き出すシステム
+pub fn save_build_area(build_area: Res<BuildArea>) {
+    if !build_area.is_changed() || build_area.is_added() {
+        return;
+    }
+
+    if let Err(e) = build_area.save_to_file() {
+        warn!("Failed to save build area: {}", e);
+    }
+}
@@ -1,29 +1,90 @@
-use bevy::{input::mouse::MouseWheel, prelude::*};
-
-use crate::components::{Fox, MainCamera};
-use crate::constants::CAMERA_PITCH_LIMIT;
-use crate::resources::{CameraSettings, FoxMoveMode, MouseDragState, PossessionMode};
-use crate::traits::{camera_relative_movement, CameraRotation};
+use bevy::{
+    ecs::query::QuerySingleError,
+    input::mouse::{AccumulatedMouseMotion, MouseWheel},
+    log::warn_once,
+    prelude::*,
+    window::{CursorGrabMode, CursorOptions},
+};
+
+use crate::components::{Block, ClickFeedbackText, MainCamera, Rock};
+use crate::constants::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::{
+    CameraMode, CameraSettings, CameraTarget, CameraViewTransition, DashInputState, FieldConfig,
+    FoxMoveMode, MouseDragState, PossessionMode, SettingsMenuState,
+};
+use crate::traits::{
+    Aabb, CameraRotation, RayIntersectable, apply_stick_deadzone, camera_relative_movement,
+};
+
+/// [`MainCamera`]のクエリの`single()`・`single_mut()`の結果からカメラを取り出す
+///
+/// カメラが見つからない・複数ある場合は`None`を返す。そのままではカメラが黙って動かなくなるため、
+/// 設定ミスに気付けるよう、原因ごとに最初の1回だけ警告を出す。
+pub fn main_camera<T>(result: Result<T, QuerySingleError>) -> Option<T> {
+    match result {
+        Ok(item) => Some(item),
+        Err(QuerySingleError::NoEntities(_)) => {
+            warn_once!("MainCamera が見つかりません。カメラを使う処理をスキップします");
+            None
+        }
+        Err(QuerySingleError::MultipleEntities(_)) => {
+            warn_once!("MainCamera が複数あります。カメラを使う処理をスキップします");
+            None
+        }
+    }
+}
 
 /// マウスホイールでカメラのズームを処理するシステム（フリーカメラ - 前後移動）
+///
+/// ゲームパッドではRTでズームイン、LTでズームアウトする。
+/// 設定メニューを開いている間のホイールはメニューのスクロールに使う。
+/// Possessionモード中は追従カメラのキツネからの距離を変更する。
 pub fn camera_zoom(
     mut wheel_events: MessageReader<MouseWheel>,
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
     settings: Res<CameraSettings>,
-    possession_mode: Res<PossessionMode>,
+    mut possession_mode: ResMut<PossessionMode>,
+    settings_state: Res<SettingsMenuState>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time>,
 ) {
-    // Possessionモード中はズームを無効化
+    if settings_state.is_open {
+        wheel_events.clear();
+        return;
+    }
+
     if possession_mode.is_active {
+        for event in wheel_events.read() {
+            possession_mode.zoom(event.y * POSSESSION_ZOOM_STEP);
+        }
         return;
     }
 
     for event in wheel_events.read() {
-        if let Ok(mut transform) = camera_query.single_mut() {
+        if let Some(mut transform) = main_camera(camera_query.single_mut()) {
             let forward = transform.forward();
             let movement = *forward * event.y * settings.zoom_speed;
             transform.translation += movement;
         }
     }
+
+    for gamepad in gamepads.iter() {
+        let zoom_in = gamepad.get(GamepadButton::RightTrigger2).unwrap_or(0.0);
+        let zoom_out = gamepad.get(GamepadButton::LeftTrigger2).unwrap_or(0.0);
+        let amount = zoom_in - zoom_out;
+
+        if amount != 0.0
+            && let Some(mut transform) = main_camera(camera_query.single_mut())
+        {
+            let forward = transform.forward();
+            transform.translation += *forward
+                * amount
+                * settings.zoom_speed
+                * GAMEPAD_TRIGGER_ZOOM_RATE
+                * time.delta_secs();
+        }
+    }
 }
 
 /// 左マウスボタンドラッグでカメラ回転を処理するシステム（フリーカメラ）
@@ -57,7 +118,7 @@ pub fn camera_drag_rotation(
         if let Some(last_pos) = drag_state.last_position {
             let delta = cursor_position - last_pos;
 
-            if let Ok(mut transform) = camera_query.single_mut() {
+            if let Some(mut transform) = main_camera(camera_query.single_mut()) {
                 let rotation = CameraRotation::from_drag(
                     delta,
                     settings.mouse_sensitivity,
@@ -82,56 +143,61 @@ pub fn camera_keyboard_rotation(
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
     settings: Res<CameraSettings>,
     possession_mode: Res<PossessionMode>,
+    time: Res<Time>,
 ) {
     // Possessionモード中は矢印キーでのカメラ回転を無効化
     if possession_mode.is_active {
         return;
     }
 
-    let Ok(mut transform) = camera_query.single_mut() else {
+    let Some(mut transform) = main_camera(camera_query.single_mut()) else {
         return;
     };
 
     let mut yaw_delta = 0.0;
     let mut pitch_delta = 0.0;
+    let step = settings.keyboard_sensitivity * time.delta_secs();
 
     if keyboard_input.pressed(KeyCode::ArrowLeft) {
-        yaw_delta += settings.keyboard_sensitivity;
+        yaw_delta += step;
     }
     if keyboard_input.pressed(KeyCode::ArrowRight) {
-        yaw_delta -= settings.keyboard_sensitivity;
+        yaw_delta -= step;
     }
     if keyboard_input.pressed(KeyCode::ArrowUp) {
-        pitch_delta += settings.keyboard_sensitivity;
+        pitch_delta += step;
     }
     if keyboard_input.pressed(KeyCode::ArrowDown) {
-        pitch_delta -= settings.keyboard_sensitivity;
+        pitch_delta -= step;
     }
 
     if yaw_delta != 0.0 || pitch_delta != 0.0 {
-        let rotation = CameraRotation::from_keyboard(
-            yaw_delta,
-            pitch_delta,
-            &transform,
-            CAMERA_PITCH_LIMIT,
-        );
+        let rotation =
+            CameraRotation::from_keyboard(yaw_delta, pitch_delta, &transform, CAMERA_PITCH_LIMIT);
         transform.rotation = rotation.to_quat();
     }
 }
 
 /// WASDキーでカメラパンを処理するシステム（フリーカメラ移動）
+///
+/// ゲームパッドの左スティックでも同様に移動できる。
+/// 移動量は経過時間に比例させ、フレームレートによって速さが変わらないようにする。
 pub fn camera_keyboard_pan(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
     settings: Res<CameraSettings>,
     possession_mode: Res<PossessionMode>,
+    camera_mode: Res<CameraMode>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time>,
 ) {
     // Possessionモード中はフリーカメラ移動を無効化
-    if possession_mode.is_active {
+    // スペクテイターモード中は専用の移動システムに任せる
+    if possession_mode.is_active || camera_mode.is_spectator() {
         return;
     }
 
-    let Ok(mut transform) = camera_query.single_mut() else {
+    let Some(mut transform) = main_camera(camera_query.single_mut()) else {
         return;
     };
 
@@ -151,97 +217,408 @@ pub fn camera_keyboard_pan(
         movement += right_xz * settings.movement_speed;
     }
 
+    for gamepad in gamepads.iter() {
+        let stick = apply_stick_deadzone(gamepad.left_stick(), settings.gamepad_deadzone);
+        movement += (forward_xz * stick.y + right_xz * stick.x) * settings.movement_speed;
+    }
+
     if movement != Vec3::ZERO {
-        transform.translation += movement;
+        transform.translation += movement * time.delta_secs();
     }
 }
 
-/// Possessionモード時にカメラをキツネの視点に追従させるシステム
-pub fn possession_camera_follow(
+/// Fキーでスペクテイターモードを切り替えるシステム
+///
+/// Possessionモード中は切り替えない。
+pub fn toggle_spectator_mode(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     possession_mode: Res<PossessionMode>,
-    fox_query: Query<&GlobalTransform, With<Fox>>,
+    mut camera_mode: ResMut<CameraMode>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if possession_mode.is_active || !keyboard_input.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    let message = if camera_mode.is_spectator() {
+        *camera_mode = CameraMode::Build;
+        TextKey::SpectatorEnded
+    } else {
+        *camera_mode = CameraMode::Spectator;
+        TextKey::SpectatorStarted
+    };
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.t(message).to_string();
+    }
+}
+
+/// スペクテイターモード時のカメラ移動を処理するシステム
+///
+/// WASDはカメラの向きそのまま（上下の傾きを含む）に移動し、
+/// Eで上昇、Qで下降する。Shiftを押している間は移動が速くなる。
+/// フリーカメラと同じく、移動量は経過時間に比例させる。
+pub fn spectator_camera_movement(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    settings: Res<CameraSettings>,
+    camera_mode: Res<CameraMode>,
+    time: Res<Time>,
+) {
+    if !camera_mode.is_spectator() {
+        return;
+    }
+
+    let Some(mut transform) = main_camera(camera_query.single_mut()) else {
+        return;
+    };
+
+    let forward = *transform.forward();
+    let right = *transform.right();
+    let mut direction = Vec3::ZERO;
+
+    if keyboard_input.pressed(KeyCode::KeyW) {
+        direction += forward;
+    }
+    if keyboard_input.pressed(KeyCode::KeyS) {
+        direction -= forward;
+    }
+    if keyboard_input.pressed(KeyCode::KeyA) {
+        direction -= right;
+    }
+    if keyboard_input.pressed(KeyCode::KeyD) {
+        direction += right;
+    }
+    if keyboard_input.pressed(KeyCode::KeyE) {
+        direction += Vec3::Y;
+    }
+    if keyboard_input.pressed(KeyCode::KeyQ) {
+        direction -= Vec3::Y;
+    }
+
+    let mut speed = settings.movement_speed;
+    if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        speed *= SPECTATOR_BOOST_MULTIPLIER;
+    }
+
+    transform.translation += direction.normalize_or_zero() * speed * time.delta_secs();
+}
+
+/// ゲームパッドの右スティックでカメラ回転を処理するシステム
+///
+/// フリーカメラ・Possessionモードのどちらでも有効。
+/// スティックを倒し切った時の回転速度は矢印キーと同じ。
+pub fn camera_gamepad_rotation(
+    gamepads: Query<&Gamepad>,
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    settings: Res<CameraSettings>,
+    move_mode: Res<FoxMoveMode>,
+    time: Res<Time>,
 ) {
-    if !possession_mode.is_active {
+    if move_mode.is_active {
         return;
     }
 
-    let Some(fox_entity) = possession_mode.fox_entity else {
+    let Some(mut transform) = main_camera(camera_query.single_mut()) else {
+        return;
+    };
+
+    for gamepad in gamepads.iter() {
+        let stick = apply_stick_deadzone(gamepad.right_stick(), settings.gamepad_deadzone);
+        if stick == Vec2::ZERO {
+            continue;
+        }
+
+        let step = settings.keyboard_sensitivity * time.delta_secs();
+        let rotation = CameraRotation::from_keyboard(
+            -stick.x * step,
+            stick.y * step,
+            &transform,
+            CAMERA_PITCH_LIMIT,
+        );
+        transform.rotation = rotation.to_quat();
+    }
+}
+
+/// Possessionモードの開始・終了に合わせて追従カメラの対象を切り替えるシステム
+pub fn sync_possession_camera_target(
+    possession_mode: Res<PossessionMode>,
+    mut camera_target: ResMut<CameraTarget>,
+) {
+    if !possession_mode.is_changed() {
+        return;
+    }
+
+    camera_target.entity = if possession_mode.is_active {
+        possession_mode.fox_entity
+    } else {
+        None
+    };
+}
+
+/// 追従対象が設定されている時にカメラを対象の斜め後ろに追従させるシステム
+///
+/// Possessionモード中は[`PossessionMode`]の距離と高さを使う。
+/// 対象からカメラまでの間がブロックや岩に遮られている場合は、
+/// 最初に当たる障害物の手前までカメラを引き寄せ、遮られなくなったら元の距離に戻す。
+#[allow(clippy::type_complexity)]
+pub fn camera_follow_target(
+    camera_target: Res<CameraTarget>,
+    possession_mode: Res<PossessionMode>,
+    target_query: Query<&GlobalTransform>,
+    obstacle_query: Query<(&GlobalTransform, Has<Rock>), Or<(With<Block>, With<Rock>)>>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+) {
+    let Some(target_entity) = camera_target.entity else {
         return;
     };
 
-    let Ok(fox_transform) = fox_query.get(fox_entity) else {
+    let Ok(target_transform) = target_query.get(target_entity) else {
         return;
     };
 
-    let Ok(mut camera_transform) = camera_query.single_mut() else {
+    let Some(mut camera_transform) = main_camera(camera_query.single_mut()) else {
         return;
     };
 
-    let fox_position = fox_transform.translation();
+    let target_position = target_transform.translation();
+    let (distance, height) = if possession_mode.is_active {
+        (
+            possession_mode.camera_distance,
+            possession_mode.camera_height,
+        )
+    } else {
+        (CAMERA_FOLLOW_DISTANCE, CAMERA_FOLLOW_HEIGHT)
+    };
 
     // カメラの現在の回転を保持したまま、位置のみを更新
     let (current_yaw, _current_pitch, _) = camera_transform
         .rotation
         .to_euler(bevy::math::EulerRot::YXZ);
 
-    // カメラの距離と高さ（キツネ全体が見えるように調整）
-    let camera_distance = -40.0; // キツネからの水平距離（後方への距離）
-    let camera_height = 40.0; // キツネからの高さ（上方への距離）
-
-    // 水平方向（Yaw）と垂直方向（Pitch）を考慮したオフセットを計算
+    // 水平方向（Yaw）を考慮した後方へのオフセットを計算
     let yaw_offset = Vec3::new(
-        -current_yaw.sin() * camera_distance,
+        current_yaw.sin() * distance,
         0.0,
-        -current_yaw.cos() * camera_distance,
+        current_yaw.cos() * distance,
     );
 
-    // カメラをキツネの斜め後ろに配置（回転は変更しない）
-    camera_transform.translation = fox_position + yaw_offset + Vec3::new(0.0, camera_height, 0.0);
+    // カメラを対象の斜め後ろに配置（回転は変更しない）
+    let desired_position = target_position + yaw_offset + Vec3::new(0.0, height, 0.0);
+    camera_transform.translation = if possession_mode.is_active {
+        pull_in_from_obstacles(target_position, desired_position, &obstacle_query)
+    } else {
+        desired_position
+    };
+}
+
+/// 対象からカメラの位置までのレイを障害物と判定し、遮られていればカメラを手前に寄せる
+///
+/// レイはキツネの足元のブロックに当たらないよう、キツネの高さの半分だけ持ち上げて飛ばす。
+/// 寄せた後も[`POSSESSION_CAMERA_PULL_IN_MIN_DISTANCE`]より近づけない。
+/// 原点が内側に入っている障害物（キツネが重なっている岩など）は無視する。
+#[allow(clippy::type_complexity)]
+fn pull_in_from_obstacles(
+    target_position: Vec3,
+    desired_position: Vec3,
+    obstacle_query: &Query<(&GlobalTransform, Has<Rock>), Or<(With<Block>, With<Rock>)>>,
+) -> Vec3 {
+    let origin = target_position + Vec3::Y * FOX_HALF_SIZE;
+    let Ok(direction) = Dir3::new(desired_position - origin) else {
+        return desired_position;
+    };
+    let ray = Ray3d::new(origin, direction);
+    let distance = origin.distance(desired_position);
+
+    let nearest_hit = obstacle_query
+        .iter()
+        .map(|(transform, is_rock)| {
+            let position = transform.translation();
+            if is_rock {
+                Aabb::new(
+                    position,
+                    Vec3::new(ROCK_RADIUS, ROCK_CAMERA_OBSTACLE_HEIGHT / 2.0, ROCK_RADIUS),
+                )
+            } else {
+                Aabb::cube(position, BLOCK_HALF_SIZE)
+            }
+        })
+        .filter_map(|aabb| aabb.ray_intersect(&ray))
+        .filter(|&hit| hit > 0.0 && hit < distance)
+        .reduce(f32::min);
+
+    match nearest_hit {
+        Some(hit) => {
+            let pulled = (hit - POSSESSION_CAMERA_OBSTACLE_MARGIN)
+                .max(POSSESSION_CAMERA_PULL_IN_MIN_DISTANCE)
+                .min(distance);
+            ray.get_point(pulled)
+        }
+        None => desired_position,
+    }
 }
 
-/// Possessionモード時のマウスドラッグでカメラ回転を処理するシステム
+/// Possessionモード時のマウス移動でカメラ回転を処理するシステム
+///
+/// カーソルはロックされているため、ドラッグではなくマウスの移動量を直接使う。
 pub fn possession_camera_rotation(
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
-    mut drag_state: ResMut<MouseDragState>,
     settings: Res<CameraSettings>,
     possession_mode: Res<PossessionMode>,
 ) {
-    if !possession_mode.is_active {
+    if !possession_mode.is_active || mouse_motion.delta == Vec2::ZERO {
         return;
     }
 
-    let Ok(window) = window_query.single() else {
+    if let Some(mut transform) = main_camera(camera_query.single_mut()) {
+        let rotation = CameraRotation::from_drag(
+            mouse_motion.delta,
+            settings.possession_sensitivity,
+            &transform,
+            CAMERA_PITCH_LIMIT,
+        );
+        transform.rotation = rotation.to_quat();
+    }
+}
+
+/// 憑依中のキツネがダッシュしている間、カメラの視野角を広げるシステム
+///
+/// ダッシュの開始・終了に合わせて視野角を基準値と広げた値の間でなめらかに変化させる。
+/// 憑依を解除した時は基準の視野角にすぐ戻す。
+pub fn dash_fov_kick(
+    mut camera_query: Query<&mut Projection, With<MainCamera>>,
+    dash_state: Res<DashInputState>,
+    possession_mode: Res<PossessionMode>,
+    settings: Res<CameraSettings>,
+    time: Res<Time>,
+) {
+    let Some(mut projection) = main_camera(camera_query.single_mut()) else {
         return;
     };
+    let Projection::Perspective(perspective) = projection.as_ref() else {
+        return;
+    };
+    let current_fov = perspective.fov;
 
-    let Some(cursor_position) = window.cursor_position() else {
-        drag_state.is_dragging = false;
-        drag_state.last_position = None;
+    let fov = if !possession_mode.is_active {
+        CAMERA_BASE_FOV
+    } else {
+        let target_fov = if dash_state.is_dashing {
+            CAMERA_BASE_FOV + settings.dash_fov_kick.to_radians()
+        } else {
+            CAMERA_BASE_FOV
+        };
+        let t = (DASH_FOV_EASE_RATE * time.delta_secs()).min(1.0);
+        current_fov + (target_fov - current_fov) * t
+    };
+
+    if current_fov != fov
+        && let Projection::Perspective(perspective) = projection.as_mut()
+    {
+        perspective.fov = fov;
+    }
+}
+
+/// Possessionモードの開始・終了に合わせてカーソルをロック・解放するシステム
+///
+/// 憑依中はカーソルを非表示にしてウィンドウ内にロックし、
+/// 解除時（Escキーなど）に元に戻す。
+pub fn possession_cursor_grab(
+    possession_mode: Res<PossessionMode>,
+    mut cursor_query: Query<&mut CursorOptions, With<bevy::window::PrimaryWindow>>,
+) {
+    if !possession_mode.is_changed() {
+        return;
+    }
+
+    let Ok(mut cursor_options) = cursor_query.single_mut() else {
         return;
     };
 
-    if mouse_input.pressed(MouseButton::Left) {
-        if let Some(last_pos) = drag_state.last_position {
-            let delta = cursor_position - last_pos;
+    if possession_mode.is_active {
+        cursor_options.grab_mode = CursorGrabMode::Locked;
+        cursor_options.visible = false;
+    } else {
+        cursor_options.grab_mode = CursorGrabMode::None;
+        cursor_options.visible = true;
+    }
+}
 
-            if let Ok(mut transform) = camera_query.single_mut() {
-                let rotation = CameraRotation::from_drag(
-                    delta,
-                    settings.mouse_sensitivity,
-                    &transform,
-                    CAMERA_PITCH_LIMIT,
-                );
-                transform.rotation = rotation.to_quat();
-            }
-        }
+/// テンキーで視点プリセット（上・正面・側面）を選択するシステム
+///
+/// - Numpad7: 真上からの見下ろし
+/// - Numpad1: 正面（+Z方向から）
+/// - Numpad3: 側面（+X方向から）
+///
+/// フィールド全体が画面に収まる距離を計算し、補間の目標として設定する。
+pub fn camera_view_presets(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    camera_query: Query<&Projection, With<MainCamera>>,
+    field_config: Res<FieldConfig>,
+    possession_mode: Res<PossessionMode>,
+    mut view_transition: ResMut<CameraViewTransition>,
+) {
+    if possession_mode.is_active {
+        view_transition.target = None;
+        return;
+    }
 
-        drag_state.is_dragging = true;
-        drag_state.last_position = Some(cursor_position);
+    // 見下ろし角（水平からの角度）をキーから決定
+    let (elevation, azimuth) = if keyboard_input.just_pressed(KeyCode::Numpad7) {
+        // 真上はピッチ制限に合わせ、ドラッグ時に視点が跳ねないようにする
+        (CAMERA_PITCH_LIMIT, 0.0)
+    } else if keyboard_input.just_pressed(KeyCode::Numpad1) {
+        (CAMERA_VIEW_PRESET_ELEVATION, 0.0)
+    } else if keyboard_input.just_pressed(KeyCode::Numpad3) {
+        (CAMERA_VIEW_PRESET_ELEVATION, std::f32::consts::FRAC_PI_2)
     } else {
-        drag_state.is_dragging = false;
-        drag_state.last_position = None;
+        return;
+    };
+
+    let fov = match main_camera(camera_query.single()) {
+        Some(Projection::Perspective(perspective)) => perspective.fov,
+        _ => std::f32::consts::FRAC_PI_4,
+    };
+
+    // フィールドの対角線が視野に収まる距離
+    let half_extent = field_config.world_extent() * std::f32::consts::SQRT_2 / 2.0;
+    let distance = half_extent / (fov / 2.0).tan() * CAMERA_VIEW_PRESET_MARGIN;
+
+    let direction = Vec3::new(
+        azimuth.sin() * elevation.cos(),
+        elevation.sin(),
+        azimuth.cos() * elevation.cos(),
+    );
+
+    view_transition.target =
+        Some(Transform::from_translation(direction * distance).looking_at(Vec3::ZERO, Vec3::Y));
+}
+
+/// 視点プリセットの目標に向けてカメラを滑らかに補間するシステム
+pub fn apply_camera_view_transition(
+    mut view_transition: ResMut<CameraViewTransition>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    time: Res<Time>,
+) {
+    let Some(target) = view_transition.target else {
+        return;
+    };
+
+    let Some(mut transform) = main_camera(camera_query.single_mut()) else {
+        return;
+    };
+
+    let t = (CAMERA_VIEW_PRESET_LERP_SPEED * time.delta_secs()).min(1.0);
+    transform.translation = transform.translation.lerp(target.translation, t);
+    transform.rotation = transform.rotation.slerp(target.rotation, t);
+
+    // 十分に近づいたら目標に合わせて補間を終了
+    if transform.translation.distance(target.translation) < 0.1
+        && transform.rotation.angle_between(target.rotation) < 0.001
+    {
+        *transform = target;
+        view_transition.target = None;
     }
 }
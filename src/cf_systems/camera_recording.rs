@@ -0,0 +1,134 @@
+//! カメラの動きの記録・再生
+//!
+//! F5でメインカメラの動きの記録を開始・停止し、停止時にファイルへ保存する。
+//! F6で記録した動きを再生し、再生中はカメラの操作を上書きする。
+
+use bevy::prelude::*;
+
+use crate::cf_systems::camera::main_camera;
+use crate::components::{ClickFeedbackText, MainCamera};
+use crate::locale::{Locale, TextKey};
+use crate::resources::{CameraRecording, CameraRecordingState};
+use crate::traits::JsonSave;
+
+/// F5でカメラの動きの記録を開始・停止するシステム
+///
+/// 記録を停止した時にファイルへ保存する。再生中は何もしない。
+pub fn toggle_camera_recording(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut recording: ResMut<CameraRecording>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F5) {
+        return;
+    }
+
+    let message = match recording.state {
+        CameraRecordingState::Playing => return,
+        CameraRecordingState::Idle => {
+            recording.samples.clear();
+            recording.elapsed = 0.0;
+            recording.state = CameraRecordingState::Recording;
+            locale.t(TextKey::CameraRecordingStarted).to_string()
+        }
+        CameraRecordingState::Recording => {
+            recording.state = CameraRecordingState::Idle;
+            if let Err(e) = recording.save_to_file() {
+                eprintln!("カメラの動きの保存に失敗しました: {}", e);
+            }
+            locale.tf(
+                TextKey::CameraRecordingSaved,
+                &[&format!("{:.1}", recording.duration())],
+            )
+        }
+    };
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = message;
+    }
+}
+
+/// F6で記録したカメラの動きの再生を開始・停止するシステム
+///
+/// 記録中は何もしない。
+pub fn toggle_camera_playback(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut recording: ResMut<CameraRecording>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F6) {
+        return;
+    }
+
+    let message = match recording.state {
+        CameraRecordingState::Recording => return,
+        CameraRecordingState::Idle if recording.samples.is_empty() => TextKey::NoCameraRecording,
+        CameraRecordingState::Idle => {
+            recording.elapsed = 0.0;
+            recording.state = CameraRecordingState::Playing;
+            TextKey::CameraPlaybackStarted
+        }
+        CameraRecordingState::Playing => {
+            recording.state = CameraRecordingState::Idle;
+            TextKey::CameraPlaybackFinished
+        }
+    };
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.t(message).to_string();
+    }
+}
+
+/// 再生中に、経過時間に合わせて補間した姿勢でカメラを上書きするシステム
+///
+/// 記録の最後まで再生したら再生を終える。
+pub fn play_camera_path(
+    mut recording: ResMut<CameraRecording>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    time: Res<Time>,
+) {
+    if recording.state != CameraRecordingState::Playing {
+        return;
+    }
+
+    recording.elapsed += time.delta_secs();
+
+    if let Some(sample) = recording.sample_at(recording.elapsed)
+        && let Some(mut transform) = main_camera(camera_query.single_mut())
+    {
+        *transform = sample;
+    }
+
+    if recording.elapsed >= recording.duration() {
+        recording.state = CameraRecordingState::Idle;
+        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+            feedback_text.0 = locale.t(TextKey::CameraPlaybackFinished).to_string();
+        }
+    }
+}
+
+/// 記録中に、毎フレームのカメラの姿勢を記録に積むシステム
+pub fn record_camera_path(
+    mut recording: ResMut<CameraRecording>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    time: Res<Time>,
+) {
+    if recording.state != CameraRecordingState::Recording {
+        return;
+    }
+
+    let Some(transform) = main_camera(camera_query.single()) else {
+        return;
+    };
+
+    // 最初のサンプルは0秒に置き、再生開始時にすぐ記録開始時の姿勢になるようにする
+    if !recording.samples.is_empty() {
+        recording.elapsed += time.delta_secs();
+    }
+    let elapsed = recording.elapsed;
+    recording.samples.push((elapsed, *transform));
+}
@@ -0,0 +1,506 @@
+//! デバッグ用コマンドコンソール
+//!
+//! `~`（バッククォート）キーで開閉し、テキスト入力欄に入力したコマンドを
+//! 既存のリソースやエンティティに直接反映する。
+//!
+//! - `spawn fox` - キツネを1匹生成する（既にいる場合は横にずらす）
+//! - `weather <rain|clear>` - 天候を切り替える
+//! - `give <item> <数>` - アイテムスロットにアイテムを追加する
+//! - `field <サイズ>` - フィールドを指定サイズで作り直す
+//! - `tp <x> <y> <z>` - キツネを指定座標に移動する
+//! - `entities` - エンティティ数とブロック数を表示する
+//! - `settings <export|import> <パス>` - カメラ設定を指定したファイルに書き出す・から読み込む
+//! - `level export <名前>` - 今のフィールドを`assets/levels/<名前>.ron`に書き出す
+//! - `rain <radius|length|alpha> <値>` / `rain save` - 雨粒の見た目を変える・保存する
+
+use std::path::Path;
+
+use bevy::input::keyboard::KeyboardInput;
+use bevy::prelude::*;
+
+use crate::cf_systems::mining::StoneBlockAssets;
+use crate::cf_systems::setup::{spawn_field, spawn_fox, spawn_rocks};
+use crate::cf_systems::text_input::spawn_text_input;
+use crate::cf_systems::water::WaterAssets;
+use crate::components::*;
+use crate::constants::*;
+use crate::events::TextInputSubmitted;
+use crate::level::{FieldLevel, LevelFile, LevelTile};
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+use crate::traits::JsonSave;
+
+/// バッククォートキーでデバッグコンソールを開閉するシステム
+///
+/// 入力欄にフォーカスがある間もキーを拾えるよう、キーボードのメッセージを直接読む。
+/// コンソール以外の入力欄（キツネの名前など）に入力中は開閉しない。
+pub fn toggle_debug_console(
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut commands: Commands,
+    mut console_state: ResMut<DebugConsoleState>,
+    console_query: Query<Entity, With<DebugConsole>>,
+    other_input_query: Query<&TextInput, Without<DebugConsoleInput>>,
+) {
+    if other_input_query.iter().any(|input| input.focused) {
+        keyboard_events.clear();
+        return;
+    }
+
+    let toggled = keyboard_events
+        .read()
+        .any(|event| event.key_code == KeyCode::Backquote && event.state.is_pressed());
+    if !toggled {
+        return;
+    }
+
+    if console_state.is_open {
+        for entity in console_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        console_state.is_open = false;
+    } else {
+        spawn_debug_console(&mut commands);
+        console_state.is_open = true;
+    }
+}
+
+/// デバッグコンソールのUIを生成する
+///
+/// 入力欄は生成直後からフォーカスされた状態にする。
+fn spawn_debug_console(commands: &mut Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(70.0),
+                left: Val::Px(10.0),
+                width: Val::Px(DEBUG_CONSOLE_WIDTH),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            DebugConsole,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.9, 0.8)),
+                DebugConsoleLog,
+            ));
+
+            spawn_text_input(
+                parent,
+                "",
+                DEBUG_CONSOLE_INPUT_MAX_LENGTH,
+                DEBUG_CONSOLE_WIDTH - 16.0,
+            )
+            .insert(DebugConsoleInput)
+            .entry::<TextInput>()
+            .and_modify(|mut input| input.focused = true);
+        });
+}
+
+/// コンソールの入力欄で確定されたコマンドを実行待ちに積むシステム
+///
+/// 続けて入力できるよう、入力欄を空にしてフォーカスを戻す。
+pub fn submit_debug_console_command(
+    mut submitted: MessageReader<TextInputSubmitted>,
+    mut input_query: Query<&mut TextInput, With<DebugConsoleInput>>,
+    mut console_state: ResMut<DebugConsoleState>,
+) {
+    for event in submitted.read() {
+        let Ok(mut input) = input_query.get_mut(event.input) else {
+            continue;
+        };
+
+        input.value.clear();
+        input.focused = true;
+
+        let line = event.value.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        console_state.print(format!("> {}", line));
+        console_state.pending.push(line.to_string());
+    }
+}
+
+/// 実行待ちのコマンドを実行し、結果をログに出力するシステム
+///
+/// コマンドはフィールドの作り直しなど複数のリソースとエンティティに触れるため、
+/// ワールドに直接アクセスする排他システムで処理する。
+pub fn run_debug_console_commands(world: &mut World) {
+    let pending = std::mem::take(&mut world.resource_mut::<DebugConsoleState>().pending);
+    if pending.is_empty() {
+        return;
+    }
+
+    let locale = *world.resource::<Locale>();
+    for line in pending {
+        let output = execute_console_command(world, &line, locale);
+        world.resource_mut::<DebugConsoleState>().print(output);
+    }
+}
+
+/// コンソールのログ表示を更新するシステム
+pub fn update_debug_console_log(
+    console_state: Res<DebugConsoleState>,
+    mut log_query: Query<&mut Text, With<DebugConsoleLog>>,
+) {
+    if !console_state.is_changed() {
+        return;
+    }
+
+    let skip = console_state
+        .log
+        .len()
+        .saturating_sub(DEBUG_CONSOLE_VISIBLE_LINES);
+    let text = console_state
+        .log
+        .iter()
+        .skip(skip)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    for mut log_text in log_query.iter_mut() {
+        log_text.0 = text.clone();
+    }
+}
+
+/// コマンド1行を解析して実行し、結果のメッセージを返す
+fn execute_console_command(world: &mut World, line: &str, locale: Locale) -> String {
+    let args: Vec<&str> = line.split_whitespace().collect();
+
+    match args.as_slice() {
+        ["help"] => locale.t(TextKey::ConsoleHelp).to_string(),
+        ["spawn", "fox"] => console_spawn_fox(world, locale),
+        ["weather", weather] => match *weather {
+            "rain" | "clear" => {
+                world.resource_mut::<WeatherState>().is_raining = *weather == "rain";
+                locale.tf(TextKey::ConsoleWeatherSet, &[weather])
+            }
+            _ => locale.tf(TextKey::ConsoleInvalidArgs, &[&"weather <rain|clear>"]),
+        },
+        ["give", item, count] => match (parse_item(item), count.parse::<usize>()) {
+            (Some(item), Ok(count)) => console_give(world, item, count, locale),
+            _ => locale.tf(
+                TextKey::ConsoleInvalidArgs,
+                &[&"give <seed|produce|ore|fence|torch|pickaxe> <count>"],
+            ),
+        },
+        ["field", size] => match size.parse::<i32>() {
+            Ok(size) if (FIELD_SIZE_MIN..=FIELD_SIZE_MAX).contains(&size) => {
+                console_rebuild_field(world, size);
+                locale.tf(TextKey::ConsoleFieldResized, &[&size, &size])
+            }
+            _ => locale.tf(
+                TextKey::ConsoleInvalidArgs,
+                &[&format!("field <{}..={}>", FIELD_SIZE_MIN, FIELD_SIZE_MAX)],
+            ),
+        },
+        ["tp", x, y, z] => match (x.parse::<f32>(), y.parse::<f32>(), z.parse::<f32>()) {
+            (Ok(x), Ok(y), Ok(z)) => console_teleport_fox(world, Vec3::new(x, y, z), locale),
+            _ => locale.tf(TextKey::ConsoleInvalidArgs, &[&"tp <x> <y> <z>"]),
+        },
+        ["entities"] => console_count_entities(world, locale),
+        ["settings", "export", path @ ..] if !path.is_empty() => {
+            console_export_settings(world, &path.join(" "), locale)
+        }
+        ["settings", "import", path @ ..] if !path.is_empty() => {
+            console_import_settings(world, &path.join(" "), locale)
+        }
+        ["level", "export", name] if FieldLevel::is_valid_name(name) => {
+            console_export_level(world, name, locale)
+        }
+        ["level", ..] => locale.tf(TextKey::ConsoleInvalidArgs, &[&"level export <name>"]),
+        ["rain", "save"] => match world.resource::<RainConfig>().save_to_file() {
+            Ok(()) => locale.t(TextKey::ConsoleRainConfigSaved).to_string(),
+            Err(e) => locale.tf(TextKey::ConsoleRainConfigSaveFailed, &[&e]),
+        },
+        ["rain", property, value] => match value.parse::<f32>() {
+            Ok(value) => console_set_rain_config(world, property, value, locale),
+            Err(_) => locale.tf(
+                TextKey::ConsoleInvalidArgs,
+                &[&"rain <radius|length|alpha> <value>"],
+            ),
+        },
+        ["rain", ..] => locale.tf(
+            TextKey::ConsoleInvalidArgs,
+            &[&"rain <radius|length|alpha> <value> / rain save"],
+        ),
+        ["settings", ..] => locale.tf(
+            TextKey::ConsoleInvalidArgs,
+            &[&"settings <export|import> <path>"],
+        ),
+        _ => locale.tf(TextKey::ConsoleUnknownCommand, &[&line]),
+    }
+}
+
+/// コマンドのアイテム名を[`ItemType`]に変換する（キツネは対象外）
+fn parse_item(name: &str) -> Option<ItemType> {
+    match name.to_lowercase().as_str() {
+        "seed" => Some(ItemType::Seed),
+        "produce" => Some(ItemType::Produce),
+        "ore" => Some(ItemType::Ore),
+        "fence" => Some(ItemType::Fence),
+        "torch" => Some(ItemType::Torch),
+        "pickaxe" => Some(ItemType::new_pickaxe()),
+        _ => None,
+    }
+}
+
+/// キツネを初期位置に生成する
+///
+/// 移動モードでまとめて動かすキツネを試せるよう、既にいても何匹でも生成する。
+fn console_spawn_fox(world: &mut World, locale: Locale) -> String {
+    let mut fox_query = world.query_filtered::<Entity, With<Fox>>();
+    let fox_count = fox_query.iter(world).count();
+
+    // 既にいるキツネと重ならないよう、2匹目以降は横にずらして生成する
+    let field_config = world.resource::<FieldConfig>();
+    let offset = Vec3::X * FOX_FORMATION_SPACING * fox_count as f32;
+    let position = field_config.fox_spawn_position() + offset;
+    let position = position.with_y(field_config.elevation_at(position) + FOX_INITIAL_HEIGHT);
+
+    let asset_server = world.resource::<AssetServer>().clone();
+    spawn_fox(&mut world.commands(), &asset_server, position);
+    world.flush();
+    locale.t(TextKey::ConsoleFoxSpawned).to_string()
+}
+
+/// 空いているアイテムスロットにアイテムを追加する
+///
+/// 鉱石の場合は拾った時と同じく所持資源の鉱石数も増やす。
+fn console_give(world: &mut World, item: ItemType, count: usize, locale: Locale) -> String {
+    let mut slot_query = world.query::<&mut ItemSlot>();
+    let mut slots: Vec<_> = slot_query.iter_mut(world).collect();
+    slots.sort_by_key(|slot| slot.slot_index);
+
+    let mut given = 0;
+    for slot in slots
+        .iter_mut()
+        .filter(|slot| slot.item.is_none())
+        .take(count)
+    {
+        slot.item = Some(item.clone());
+        given += 1;
+    }
+
+    if matches!(item, ItemType::Ore) {
+        world.resource_mut::<Resources>().ore += given as u32;
+    }
+
+    let mut message = locale.tf(
+        TextKey::ConsoleItemsGiven,
+        &[&locale.item_name(&item), &given],
+    );
+    if given < count {
+        message.push(' ');
+        message.push_str(locale.t(TextKey::SlotsFull));
+    }
+    message
+}
+
+/// フィールドを指定サイズで作り直す
+///
+/// ブロックとその上に置かれた植物・柵・松明、岩、落ちているアイテムや水たまりなどの
+/// 演出を削除してから生成し直し、
+/// 柵の通行判定と水のマス、建築エリアの拡張、キツネの行動範囲、操作履歴も初期状態に戻す。
+/// `--level`で読み込んだ配置は使わず、手順生成で作り直す。
+fn console_rebuild_field(world: &mut World, size: i32) {
+    let mut field_entities = world.query_filtered::<Entity, Or<(
+        With<Block>,
+        With<BlockHighlight>,
+        With<Rock>,
+        With<RockRespawnTimer>,
+        With<Plant>,
+        With<Fence>,
+        With<Torch>,
+        With<DroppedItem>,
+        With<Puddle>,
+        With<Ripple>,
+        With<RainSplash>,
+        With<PlacementPreview>,
+    )>>();
+    let entities: Vec<Entity> = field_entities.iter(world).collect();
+    for entity in entities {
+        world.despawn(entity);
+    }
+
+    world.resource_mut::<FencedEdges>().clear();
+    world.resource_mut::<BuildArea>().expansion = 0;
+    world.resource_mut::<ActionHistory>().clear();
+
+    let asset_server = world.resource::<AssetServer>().clone();
+    // 作り直したフィールドはレベルファイルではなく手順生成にする
+    let field_config = FieldConfig {
+        size,
+        batched: world.resource::<FieldConfig>().batched,
+        level: None,
+    };
+    let stone_assets = world.resource::<StoneBlockAssets>().clone();
+    let water_assets = world.resource::<WaterAssets>().clone();
+    let mut water_tiles = WaterTiles::default();
+    world.resource_scope(|world, mut rng: Mut<GameRng>| {
+        let mut commands = world.commands();
+        spawn_field(
+            &mut commands,
+            &asset_server,
+            &stone_assets,
+            &water_assets,
+            &mut water_tiles,
+            &field_config,
+            &mut rng,
+        );
+        spawn_rocks(&mut commands, &asset_server, &field_config, 0, &mut rng);
+    });
+    // 作り直したフィールドに合わせて、キツネの行動範囲も初期状態に戻す
+    let mut leash_query = world.query_filtered::<&mut Leash, With<Fox>>();
+    for mut leash in leash_query.iter_mut(world) {
+        *leash = Leash::for_field(&field_config);
+    }
+
+    world.insert_resource(field_config);
+    world.insert_resource(water_tiles);
+    world.flush();
+}
+
+/// エンティティ数とブロック数を表示する
+///
+/// [`FieldConfig::batched`]の有無でエンティティ数を比べるのに使う。
+fn console_count_entities(world: &mut World, locale: Locale) -> String {
+    let mut block_query = world.query_filtered::<(), With<Block>>();
+    let blocks = block_query.iter(world).count();
+    let entities = world.entities().count_spawned();
+    locale.tf(TextKey::ConsoleEntityCount, &[&entities, &blocks])
+}
+
+/// 雨粒の太さ・長さ・本降りの不透明度を変える
+///
+/// 範囲外の値は範囲に収めてから適用する。保存するには`rain save`を使う。
+fn console_set_rain_config(
+    world: &mut World,
+    property: &str,
+    value: f32,
+    locale: Locale,
+) -> String {
+    let mut rain_config = world.resource_mut::<RainConfig>();
+    let applied = match property {
+        "radius" => {
+            rain_config.capsule_radius =
+                value.clamp(RAIN_CAPSULE_RADIUS_MIN, RAIN_CAPSULE_RADIUS_MAX);
+            rain_config.capsule_radius
+        }
+        "length" => {
+            rain_config.capsule_height =
+                value.clamp(RAIN_CAPSULE_HEIGHT_MIN, RAIN_CAPSULE_HEIGHT_MAX);
+            rain_config.capsule_height
+        }
+        "alpha" => {
+            rain_config.color.3 = value.clamp(0.0, 1.0);
+            rain_config.color.3
+        }
+        _ => {
+            return locale.tf(
+                TextKey::ConsoleInvalidArgs,
+                &[&"rain <radius|length|alpha> <value>"],
+            );
+        }
+    };
+    locale.tf(TextKey::ConsoleRainConfigChanged, &[&property, &applied])
+}
+
+/// 今のフィールドのブロックをレベルファイルに書き出す
+///
+/// `--level`で読み込むと同じ配置になるよう、すべてのブロックの種類・選択可能か・高さを保存する。
+/// ブロックの上の設置物や岩は保存しない。
+fn console_export_level(world: &mut World, name: &str, locale: Locale) -> String {
+    let mut block_query =
+        world.query_filtered::<(&Transform, &BlockType, Has<Selectable>), With<Block>>();
+    let field_config = world.resource::<FieldConfig>();
+    let mut tiles: Vec<LevelTile> = block_query
+        .iter(world)
+        .map(|(transform, block_type, selectable)| {
+            let tile = field_config.grid_coords(transform.translation);
+            LevelTile {
+                x: tile.x,
+                z: tile.y,
+                kind: *block_type,
+                selectable,
+                height: (transform.translation.y / BLOCK_SIZE).round() as i32,
+            }
+        })
+        .collect();
+    tiles.sort_by_key(|tile| (tile.x, tile.z));
+    let block_count = tiles.len();
+
+    let level = LevelFile {
+        size: field_config.size,
+        tiles,
+    };
+    match level.save(name) {
+        Ok(path) => locale.tf(
+            TextKey::ConsoleLevelExported,
+            &[&path.display(), &block_count],
+        ),
+        Err(e) => locale.tf(TextKey::ConsoleLevelExportFailed, &[&name, &e]),
+    }
+}
+
+/// 現在のカメラ設定を指定したパスに書き出す
+fn console_export_settings(world: &mut World, path: &str, locale: Locale) -> String {
+    match world
+        .resource::<CameraSettings>()
+        .save_to_path(Path::new(path))
+    {
+        Ok(()) => locale.tf(TextKey::ConsoleSettingsExported, &[&path]),
+        Err(e) => locale.tf(TextKey::ConsoleSettingsExportFailed, &[&path, &e]),
+    }
+}
+
+/// 指定したパスからカメラ設定を読み込んで適用する
+///
+/// ウィンドウの大きさとフルスクリーンは今の状態を保つ。
+fn console_import_settings(world: &mut World, path: &str, locale: Locale) -> String {
+    match CameraSettings::load_from_path(Path::new(path)) {
+        Ok(loaded) => {
+            let mut settings = world.resource_mut::<CameraSettings>();
+            *settings = CameraSettings {
+                window_width: settings.window_width,
+                window_height: settings.window_height,
+                fullscreen: settings.fullscreen,
+                ..loaded
+            };
+            locale.tf(TextKey::ConsoleSettingsImported, &[&path])
+        }
+        Err(e) => locale.tf(TextKey::ConsoleSettingsImportFailed, &[&path, &e]),
+    }
+}
+
+/// キツネを指定座標に移動する
+///
+/// 憑依中のキツネがいればそのキツネを、いなければ最初に見つかったキツネを動かす。
+fn console_teleport_fox(world: &mut World, position: Vec3, locale: Locale) -> String {
+    let possessed = world.resource::<PossessionMode>().fox_entity;
+    let mut fox_query = world.query_filtered::<(Entity, &mut Transform), With<Fox>>();
+    let Some((_, mut transform)) = fox_query
+        .iter_mut(world)
+        .max_by_key(|(entity, _)| Some(*entity) == possessed)
+    else {
+        return locale.t(TextKey::ConsoleNoFox).to_string();
+    };
+
+    transform.translation = position;
+    locale.tf(
+        TextKey::ConsoleTeleported,
+        &[&position.x, &position.y, &position.z],
+    )
+}
@@ -0,0 +1,72 @@
+//! 足元の土ぼこり
+//!
+//! 憑依中のキツネがダッシュしている間、足元から小さな土ぼこりを出す。
+//! 粒は共有のメッシュとマテリアルを使い、縮みながら舞い上がって消える。
+
+use bevy::prelude::*;
+
+use crate::components::Dust;
+use crate::constants::*;
+
+/// 土ぼこりの描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct DustAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for DustAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::from_length(DUST_PARTICLE_SIZE));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgba(DUST_COLOR.0, DUST_COLOR.1, DUST_COLOR.2, DUST_COLOR.3),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            });
+        Self { mesh, material }
+    }
+}
+
+/// 足元に土ぼこりを1回分（[`DUST_PUFF_COUNT`]粒）出す
+///
+/// 粒は`seed`でずらした向きに放射状に広がる。
+pub fn spawn_dust_puff(commands: &mut Commands, assets: &DustAssets, position: Vec3, seed: f32) {
+    for i in 0..DUST_PUFF_COUNT {
+        let angle = seed + i as f32 * std::f32::consts::TAU / DUST_PUFF_COUNT as f32;
+        let velocity = Vec3::new(angle.cos(), 0.0, angle.sin()) * DUST_SPREAD_SPEED
+            + Vec3::Y * DUST_RISE_SPEED;
+        commands.spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_translation(position),
+            Pickable::IGNORE,
+            Dust {
+                velocity,
+                lifetime: DUST_LIFETIME,
+            },
+        ));
+    }
+}
+
+/// 土ぼこりを動かし、縮めながら消すシステム
+pub fn update_dust(
+    mut commands: Commands,
+    mut dust_query: Query<(Entity, &mut Transform, &mut Dust)>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut dust) in dust_query.iter_mut() {
+        dust.lifetime -= time.delta_secs();
+        if dust.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation += dust.velocity * time.delta_secs();
+        transform.scale = Vec3::splat(dust.lifetime / DUST_LIFETIME);
+    }
+}
@@ -0,0 +1,241 @@
+use bevy::prelude::*;
+
+use crate::cf_systems::items::store_item_in_empty_slot;
+use crate::components::*;
+use crate::constants::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::{Calendar, FieldConfig, FoxMoveMode, PossessionMode, WeatherState};
+
+/// 植物の描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct PlantAssets {
+    pub mesh: Handle<Mesh>,
+    pub growing_material: Handle<StandardMaterial>,
+    pub mature_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for PlantAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Sphere::new(PLANT_RADIUS));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let growing_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(
+                PLANT_GROWING_COLOR.0,
+                PLANT_GROWING_COLOR.1,
+                PLANT_GROWING_COLOR.2,
+            ),
+            ..default()
+        });
+        let mature_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(
+                PLANT_MATURE_COLOR.0,
+                PLANT_MATURE_COLOR.1,
+                PLANT_MATURE_COLOR.2,
+            ),
+            ..default()
+        });
+        Self {
+            mesh,
+            growing_material,
+            mature_material,
+        }
+    }
+}
+
+/// 成長度に応じた植物のTransformを計算する
+///
+/// `block_pos`はXZが植える位置、Yがブロックの中心の高さ。
+/// ブロック上面に接地したまま、成長度に応じて大きくなる。
+fn plant_transform(block_pos: Vec3, growth: f32) -> Transform {
+    let scale = PLANT_MIN_SCALE + (1.0 - PLANT_MIN_SCALE) * growth.clamp(0.0, 1.0);
+    Transform::from_xyz(
+        block_pos.x,
+        block_pos.y + BLOCK_HALF_SIZE + PLANT_RADIUS * scale,
+        block_pos.z,
+    )
+    .with_scale(Vec3::splat(scale))
+}
+
+/// ブロックに植物を植える
+///
+/// 種を植える時は`growth`に0を渡す。元に戻す操作で植物を復元する時は
+/// 削除前の成長度を渡し、見た目も成長度に合わせる。
+pub fn spawn_plant(
+    commands: &mut Commands,
+    assets: &PlantAssets,
+    block_entity: Entity,
+    block_pos: Vec3,
+    growth: f32,
+) -> Entity {
+    let plant = Plant {
+        growth,
+        block: block_entity,
+    };
+    let material = if plant.is_mature() {
+        assets.mature_material.clone()
+    } else {
+        assets.growing_material.clone()
+    };
+
+    commands
+        .spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(material),
+            plant_transform(block_pos, growth),
+            Pickable::IGNORE,
+            plant,
+        ))
+        .id()
+}
+
+/// 植物の成長を進めるシステム
+///
+/// 雨天時は `PLANT_RAIN_GROWTH_MULTIPLIER` 倍の速さで成長し、
+/// 冬は[`crate::resources::Season::growth_multiplier`]の倍率で遅くなる。
+/// 完全に成長すると収穫可能な色に切り替わる。
+pub fn update_plant_growth(
+    mut plant_query: Query<(
+        &mut Plant,
+        &mut Transform,
+        &mut MeshMaterial3d<StandardMaterial>,
+    )>,
+    block_query: Query<&Transform, (With<Block>, Without<Plant>)>,
+    plant_assets: Res<PlantAssets>,
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    time: Res<Time>,
+) {
+    let growth_rate = if weather.is_raining {
+        PLANT_RAIN_GROWTH_MULTIPLIER / PLANT_GROWTH_SECS
+    } else {
+        1.0 / PLANT_GROWTH_SECS
+    } * calendar.season.growth_multiplier();
+
+    for (mut plant, mut transform, mut material) in plant_query.iter_mut() {
+        if plant.is_mature() {
+            continue;
+        }
+
+        plant.growth = (plant.growth + growth_rate * time.delta_secs()).min(1.0);
+        if let Ok(block_transform) = block_query.get(plant.block) {
+            let block_pos = transform.translation.with_y(block_transform.translation.y);
+            *transform = plant_transform(block_pos, plant.growth);
+        }
+
+        if plant.is_mature() {
+            material.0 = plant_assets.mature_material.clone();
+        }
+    }
+}
+
+/// お腹が空いた何もしていないキツネを、最も近い実った植物へ向かわせるシステム
+///
+/// 対象は表示中で、憑依・移動モード・歩行中・追従中のいずれでもないキツネ。
+/// 移動は[`FoxPathTarget`]を設定して`fox_path_to`に任せ、近づいたら[`eat_plant`]が食べる。
+/// 途中で諦めたキツネは、[`FoodSeekCooldown`]が外れるまで探しに行かない。
+#[allow(clippy::type_complexity)]
+pub fn seek_food_when_hungry(
+    mut commands: Commands,
+    mut fox_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Visibility,
+            &FoxStats,
+            Option<&mut FoodSeekCooldown>,
+        ),
+        (With<Fox>, Without<FoxPathTarget>, Without<FollowTarget>),
+    >,
+    plant_query: Query<(&Plant, &Transform), Without<Fox>>,
+    possession_mode: Res<PossessionMode>,
+    move_mode: Res<FoxMoveMode>,
+    field_config: Res<FieldConfig>,
+    time: Res<Time>,
+) {
+    for (fox_entity, fox_transform, visibility, stats, cooldown) in fox_query.iter_mut() {
+        if let Some(mut cooldown) = cooldown {
+            cooldown.remaining_secs -= time.delta_secs();
+            if cooldown.remaining_secs > 0.0 {
+                continue;
+            }
+            commands.entity(fox_entity).remove::<FoodSeekCooldown>();
+        }
+
+        let possessed = possession_mode.is_active && possession_mode.fox_entity == Some(fox_entity);
+        if stats.hunger < FOX_HUNGRY_THRESHOLD
+            || *visibility == Visibility::Hidden
+            || possessed
+            || move_mode.is_active
+        {
+            continue;
+        }
+
+        let fox_position = fox_transform.translation;
+        let food = plant_query
+            .iter()
+            .filter(|(plant, _)| plant.is_mature())
+            .map(|(_, transform)| transform.translation)
+            .min_by(|a, b| {
+                a.distance_squared(fox_position)
+                    .total_cmp(&b.distance_squared(fox_position))
+            });
+        if let Some(food) = food {
+            // 植物が持ち上げたマスにある場合は、そのマスの上を目指す
+            commands.entity(fox_entity).insert(FoxPathTarget {
+                target: food.with_y(field_config.elevation_at(food) + FOX_INITIAL_HEIGHT),
+                stuck_secs: 0.0,
+            });
+        }
+    }
+}
+
+/// キツネが近くの実った植物を食べるシステム
+///
+/// 憑依中のキツネはいつでも、それ以外のキツネはお腹が空いている時だけ、
+/// [`FOX_EAT_DISTANCE`]以内に近づいた植物を食べる。食べると空腹度が
+/// [`FOX_EAT_HUNGER_RELIEF`]だけ減り、空いているアイテムスロットがあれば種を1つ戻す。
+pub fn eat_plant(
+    mut commands: Commands,
+    possession_mode: Res<PossessionMode>,
+    mut fox_query: Query<(Entity, &Transform, &Visibility, &mut FoxStats), With<Fox>>,
+    plant_query: Query<(Entity, &Plant, &Transform), Without<Fox>>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    // 同じフレームに2匹が同じ植物を食べないよう、食べた植物を覚えておく
+    let mut eaten = Vec::new();
+
+    for (fox_entity, fox_transform, visibility, mut stats) in fox_query.iter_mut() {
+        let possessed = possession_mode.is_active && possession_mode.fox_entity == Some(fox_entity);
+        if *visibility == Visibility::Hidden || (!possessed && stats.hunger < FOX_HUNGRY_THRESHOLD)
+        {
+            continue;
+        }
+
+        let fox_position = fox_transform.translation.xz();
+        let Some((plant_entity, _, _)) = plant_query.iter().find(|(entity, plant, transform)| {
+            !eaten.contains(entity)
+                && plant.is_mature()
+                && transform.translation.xz().distance(fox_position) <= FOX_EAT_DISTANCE
+        }) else {
+            continue;
+        };
+
+        eaten.push(plant_entity);
+        commands.entity(plant_entity).despawn();
+        stats.hunger = (stats.hunger - FOX_EAT_HUNGER_RELIEF).max(0.0);
+        let seed_returned =
+            store_item_in_empty_slot(&mut item_slot_query, ItemType::Seed).is_some();
+
+        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+            feedback_text.0 = if seed_returned {
+                locale.t(TextKey::FoxAtePlantSeedReturned).to_string()
+            } else {
+                locale.t(TextKey::FoxAtePlant).to_string()
+            };
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::FencedEdges;
+
+/// 柵の描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct FenceAssets {
+    pub post_mesh: Handle<Mesh>,
+    pub rail_mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for FenceAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let post_mesh = meshes.add(Cuboid::new(FENCE_POST_SIZE, FENCE_HEIGHT, FENCE_POST_SIZE));
+        let rail_mesh = meshes.add(Cuboid::new(
+            BLOCK_SIZE,
+            FENCE_RAIL_THICKNESS,
+            FENCE_RAIL_THICKNESS,
+        ));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgb(FENCE_COLOR.0, FENCE_COLOR.1, FENCE_COLOR.2),
+                ..default()
+            });
+        Self {
+            post_mesh,
+            rail_mesh,
+            material,
+        }
+    }
+}
+
+/// ブロックの辺の1つに柵を設置し、その辺を通行不可として登録する
+///
+/// 柵の親エンティティはブロック中心に置き、辺の向きに回転させる。
+/// 子の支柱と横木はマス中心から+Z側の辺に並べる。
+pub fn spawn_fence(
+    commands: &mut Commands,
+    assets: &FenceAssets,
+    fenced_edges: &mut FencedEdges,
+    block_entity: Entity,
+    block_pos: Vec3,
+    tile: IVec2,
+    side: FenceSide,
+) -> Entity {
+    fenced_edges.insert(tile, side);
+
+    let base_y = block_pos.y + BLOCK_HALF_SIZE + FENCE_HEIGHT / 2.0;
+
+    commands
+        .spawn((
+            Transform::from_xyz(block_pos.x, base_y, block_pos.z).with_rotation(side.rotation()),
+            Visibility::default(),
+            Fence {
+                block: block_entity,
+                tile,
+                side,
+            },
+        ))
+        .with_children(|parent| {
+            // 辺の両端の支柱
+            for sx in [-1.0, 1.0] {
+                parent.spawn((
+                    Mesh3d(assets.post_mesh.clone()),
+                    MeshMaterial3d(assets.material.clone()),
+                    Transform::from_xyz(sx * BLOCK_HALF_SIZE, 0.0, BLOCK_HALF_SIZE),
+                    Pickable::IGNORE,
+                ));
+            }
+
+            // 上下2段の横木
+            for rail_y in [-0.25, 0.25] {
+                parent.spawn((
+                    Mesh3d(assets.rail_mesh.clone()),
+                    MeshMaterial3d(assets.material.clone()),
+                    Transform::from_xyz(0.0, rail_y * FENCE_HEIGHT, BLOCK_HALF_SIZE),
+                    Pickable::IGNORE,
+                ));
+            }
+        })
+        .id()
+}
@@ -0,0 +1,67 @@
+//! キツネの成長
+//!
+//! キツネは子ギツネの大きさで生まれ、フィールドで過ごした時間に応じて
+//! [`FOX_GROWTH_STAGES`]の段階ごとに大きくなる。アイテムボックスに
+//! 格納されている（非表示の）間は成長が止まる。
+
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::locale::{Locale, TextKey};
+
+impl FoxAge {
+    /// 経過時間に対応する成長段階
+    pub fn stage_for(seconds: f32) -> u8 {
+        FOX_GROWTH_STAGES
+            .iter()
+            .rposition(|&(threshold, _)| seconds >= threshold)
+            .unwrap_or(0) as u8
+    }
+
+    /// 成長段階に対応するキツネの拡大率
+    pub fn scale(stage: u8) -> f32 {
+        let index = (stage as usize).min(FOX_GROWTH_STAGES.len() - 1);
+        FOX_SCALE * FOX_GROWTH_STAGES[index].1
+    }
+
+    /// 最後の段階（大人）まで育ったか
+    pub fn is_adult(&self) -> bool {
+        self.stage as usize >= FOX_GROWTH_STAGES.len() - 1
+    }
+}
+
+/// キツネの年齢を進め、成長段階が上がったら大きくするシステム
+///
+/// 段階が上がった時はフィードバックメッセージで知らせる。
+pub fn grow_fox(
+    time: Res<Time>,
+    mut fox_query: Query<(&mut FoxAge, &mut Transform, &Visibility, Option<&FoxName>), With<Fox>>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    for (mut age, mut transform, visibility, name) in fox_query.iter_mut() {
+        if *visibility == Visibility::Hidden || age.is_adult() {
+            continue;
+        }
+
+        age.seconds += time.delta_secs();
+        let stage = FoxAge::stage_for(age.seconds);
+        if stage == age.stage {
+            continue;
+        }
+
+        age.stage = stage;
+        transform.scale = Vec3::splat(FoxAge::scale(stage));
+
+        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+            let name = name.map_or("Fox", |name| name.0.as_str());
+            let message = if age.is_adult() {
+                TextKey::FoxGrownUp
+            } else {
+                TextKey::FoxGrew
+            };
+            feedback_text.0 = locale.tf(message, &[&name]);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+
+impl FoxMood {
+    /// 天候と雨宿りの状態、空腹度から機嫌を決める
+    ///
+    /// 晴れていればご機嫌、雨でも雨宿りしていればふつう、雨に濡れていると不機嫌になる。
+    /// お腹が空いている（空腹度が[`FOX_HUNGRY_THRESHOLD`]以上の）間は、そこから1段階機嫌が悪くなる。
+    /// 嵐におびえている間は、雨宿りしていなければおびえた状態になる。
+    pub fn evaluate(is_raining: bool, sheltered: bool, spooked: bool, hunger: f32) -> Self {
+        let mood = match (is_raining, sheltered) {
+            _ if spooked && !sheltered => return FoxMood::Scared,
+            (false, _) => FoxMood::Happy,
+            (true, true) => FoxMood::Neutral,
+            (true, false) => FoxMood::Unhappy,
+        };
+        if hunger < FOX_HUNGRY_THRESHOLD {
+            return mood;
+        }
+        match mood {
+            FoxMood::Happy => FoxMood::Neutral,
+            _ => FoxMood::Unhappy,
+        }
+    }
+
+    /// 機嫌に応じたキツネの色（ティント）
+    pub fn tint(self) -> Color {
+        let (r, g, b) = match self {
+            FoxMood::Happy => FOX_MOOD_HAPPY_TINT,
+            FoxMood::Neutral => FOX_MOOD_NEUTRAL_TINT,
+            FoxMood::Unhappy => FOX_MOOD_UNHAPPY_TINT,
+            FoxMood::Scared => FOX_MOOD_SCARED_TINT,
+        };
+        Color::srgb(r, g, b)
+    }
+}
+
+/// キツネの空腹度を時間とともに増やすシステム
+pub fn increase_fox_hunger(mut fox_query: Query<&mut FoxStats, With<Fox>>, time: Res<Time>) {
+    for mut stats in fox_query.iter_mut() {
+        stats.hunger = (stats.hunger + FOX_HUNGER_RATE * time.delta_secs()).min(1.0);
+    }
+}
+
+/// キツネの機嫌を天候と雨宿りの状態、空腹度から更新するシステム
+#[allow(clippy::type_complexity)]
+pub fn update_fox_mood(
+    weather: Res<WeatherState>,
+    mut fox_query: Query<(&mut FoxMood, &FoxStats, Has<Sheltered>, Has<Spooked>), With<Fox>>,
+) {
+    for (mut mood, stats, sheltered, spooked) in fox_query.iter_mut() {
+        let new_mood = FoxMood::evaluate(weather.is_raining, sheltered, spooked, stats.hunger);
+        if *mood != new_mood {
+            *mood = new_mood;
+        }
+    }
+}
+
+/// 嵐の間にキツネを[`Spooked`]にし、雨が弱まったら落ち着かせるシステム
+///
+/// 雨の強さが[`FOX_SPOOK_RAIN_INTENSITY`]以上の間に稲光が走るとおびえ始める。
+/// 強い雨が続く間はおびえたままで、弱まってから[`FOX_SPOOK_CALM_DOWN_SECS`]経つと元に戻る。
+pub fn update_fox_spooked(
+    mut commands: Commands,
+    weather: Res<WeatherState>,
+    lightning: Res<Lightning>,
+    time: Res<Time>,
+    mut fox_query: Query<(Entity, Option<&mut Spooked>), With<Fox>>,
+) {
+    let stormy = weather.intensity >= FOX_SPOOK_RAIN_INTENSITY;
+
+    for (fox_entity, spooked) in fox_query.iter_mut() {
+        match spooked {
+            Some(mut spooked) if stormy => {
+                spooked.calm_down_secs = FOX_SPOOK_CALM_DOWN_SECS;
+            }
+            Some(mut spooked) => {
+                spooked.calm_down_secs -= time.delta_secs();
+                if spooked.calm_down_secs <= 0.0 {
+                    commands.entity(fox_entity).remove::<Spooked>();
+                }
+            }
+            None if stormy && lightning.flash > 0.0 => {
+                commands.entity(fox_entity).insert(Spooked {
+                    calm_down_secs: FOX_SPOOK_CALM_DOWN_SECS,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+/// キツネのモデルのマテリアルを機嫌に応じた色に染めるシステム
+///
+/// 共有のglTFマテリアルを染めると他のキツネまで同じ色になるので、
+/// 初めて見つけたメッシュのマテリアルはキツネごとに複製し、[`FoxMoodMaterial`]に元の色を覚えておく。
+/// シーンの読み込みが終わる前は子孫にメッシュがないため、
+/// 色が異なるマテリアルだけを毎フレーム確認して書き換える。
+pub fn apply_fox_mood_tint(
+    mut commands: Commands,
+    fox_query: Query<(Entity, &FoxMood), With<Fox>>,
+    children_query: Query<&Children>,
+    mut material_query: Query<(
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&FoxMoodMaterial>,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (fox_entity, mood) in fox_query.iter() {
+        let tint = mood.tint().to_srgba();
+
+        for descendant in children_query.iter_descendants(fox_entity) {
+            let Ok((mut material_handle, mood_material)) = material_query.get_mut(descendant)
+            else {
+                continue;
+            };
+            let base_color = match mood_material {
+                Some(mood_material) => mood_material.base_color,
+                None => {
+                    let Some(shared) = materials.get(&material_handle.0).cloned() else {
+                        continue;
+                    };
+                    let base_color = shared.base_color;
+                    material_handle.0 = materials.add(shared);
+                    commands
+                        .entity(descendant)
+                        .insert(FoxMoodMaterial { base_color });
+                    base_color
+                }
+            };
+
+            let base = base_color.to_srgba();
+            let color = Color::srgba(
+                base.red * tint.red,
+                base.green * tint.green,
+                base.blue * tint.blue,
+                base.alpha,
+            );
+            let needs_update = materials
+                .get(&material_handle.0)
+                .is_some_and(|material| material.base_color != color);
+            if needs_update && let Some(material) = materials.get_mut(&material_handle.0) {
+                material.base_color = color;
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use bevy::prelude::*;
+
+use crate::cf_systems::camera::main_camera;
+use crate::cf_systems::game_logic::move_fox;
+use crate::cf_systems::items::camera_focus_point;
+use crate::components::*;
+use crate::constants::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+
+/// Mキーで移動モードでのキツネの動かし方（歩く/瞬間移動）を切り替えるシステム
+///
+/// 移動モード中は切り替えない。
+pub fn toggle_fox_move_style(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut move_style: ResMut<FoxMoveStyle>,
+    move_mode: Res<FoxMoveMode>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if move_mode.is_active || !keyboard_input.just_pressed(KeyCode::KeyM) {
+        return;
+    }
+
+    move_style.toggle();
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        let message = match *move_style {
+            FoxMoveStyle::Walk => TextKey::FoxMoveStyleWalk,
+            FoxMoveStyle::Teleport => TextKey::FoxMoveStyleTeleport,
+        };
+        feedback_text.0 = locale.t(message).to_string();
+    }
+}
+
+/// [`FoxPathTarget`]が設定されたキツネを移動先まで歩かせるシステム
+///
+/// 憑依時と同じ[`move_fox`]で1フレームずつ進め、途中の岩は押し返すように避ける。
+/// [`Leash`]の範囲の外へは出ず、移動先が範囲外なら境界で立ち止まって諦める。
+/// 到着するか、柵などで進めない状態が[`FOX_PATH_GIVE_UP_SECS`]秒続いたら移動を終える。
+/// 諦めた場合は[`FoodSeekCooldown`]を付け、しばらく食べ物を探しに行かないようにする。
+/// 憑依・格納・再び掴まれた場合は移動を取りやめる。
+/// 嵐におびえている（[`Spooked`]）間は[`FOX_SPOOKED_SPEED_FACTOR`]倍の速さで走る。
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn fox_path_to(
+    mut commands: Commands,
+    mut fox_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut FoxPathTarget,
+            &Visibility,
+            Has<Spooked>,
+        ),
+        With<Fox>,
+    >,
+    leash_query: Query<&Leash>,
+    rock_query: Query<&GlobalTransform, With<Rock>>,
+    fenced_edges: Res<FencedEdges>,
+    field_config: Res<FieldConfig>,
+    possession_mode: Res<PossessionMode>,
+    move_mode: Res<FoxMoveMode>,
+    time: Res<Time>,
+) {
+    for (fox_entity, mut fox_transform, mut path, visibility, spooked) in fox_query.iter_mut() {
+        let cancelled = possession_mode.is_active
+            || move_mode.contains(fox_entity)
+            || *visibility == Visibility::Hidden;
+
+        let to_target = (path.target - fox_transform.translation).with_y(0.0);
+        if cancelled || to_target.length() <= FOX_PATH_ARRIVE_DISTANCE {
+            commands.entity(fox_entity).remove::<FoxPathTarget>();
+            continue;
+        }
+
+        // 近くの岩から離れる向きを移動先への向きに加える
+        let position = fox_transform.translation.with_y(0.0);
+        let avoid_radius = ROCK_RADIUS + FOX_PATH_AVOID_MARGIN;
+        let avoidance: Vec3 = rock_query
+            .iter()
+            .filter_map(|rock_transform| {
+                let away = position - rock_transform.translation().with_y(0.0);
+                let distance = away.length();
+                (distance < avoid_radius && distance > f32::EPSILON)
+                    .then(|| away / distance * (1.0 - distance / avoid_radius))
+            })
+            .sum();
+
+        let direction = (to_target.normalize() + avoidance * FOX_PATH_AVOID_STRENGTH)
+            .normalize_or(to_target.normalize());
+        let speed = if spooked {
+            FOX_WALK_SPEED * FOX_SPOOKED_SPEED_FACTOR
+        } else {
+            FOX_WALK_SPEED
+        };
+        let step_length = (speed * time.delta_secs()).min(to_target.length());
+
+        let before = fox_transform.translation;
+        let mut moved = move_fox(
+            &mut fox_transform,
+            direction * step_length,
+            &fenced_edges,
+            &field_config,
+        );
+        if let Ok(leash) = leash_query.get(fox_entity) {
+            // 境界で押し戻されて進めなかった場合も、進めない状態として扱う
+            fox_transform.translation = leash.clamp(fox_transform.translation);
+            moved &= fox_transform.translation.distance_squared(before) > f32::EPSILON;
+        }
+
+        if moved {
+            path.stuck_secs = 0.0;
+        } else {
+            path.stuck_secs += time.delta_secs();
+            if path.stuck_secs >= FOX_PATH_GIVE_UP_SECS {
+                commands
+                    .entity(fox_entity)
+                    .remove::<FoxPathTarget>()
+                    .insert(FoodSeekCooldown {
+                        remaining_secs: FOX_FOOD_SEEK_COOLDOWN_SECS,
+                    });
+            }
+        }
+    }
+}
+
+/// [`FollowTarget`]を持つキツネを、カメラの注視点について行かせるシステム
+///
+/// 注視点から[`FOX_FOLLOW_DISTANCE`]より離れたら[`FoxPathTarget`]を設定して歩かせ、
+/// 歩いている間は移動先を注視点に合わせ続ける。移動は[`fox_path_to`]に任せる。
+/// Xキーを押すか、憑依・格納・再び掴まれた場合は追従をやめる。
+#[allow(clippy::too_many_arguments)]
+pub fn follow_camera_focus(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut fox_query: Query<
+        (Entity, &Transform, &Visibility, Option<&mut FoxPathTarget>),
+        With<FollowTarget>,
+    >,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    possession_mode: Res<PossessionMode>,
+    move_mode: Res<FoxMoveMode>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    let stop_pressed = keyboard_input.just_pressed(KeyCode::KeyX);
+    let focus = main_camera(camera_query.single()).and_then(camera_focus_point);
+
+    for (fox_entity, fox_transform, visibility, path) in fox_query.iter_mut() {
+        if stop_pressed {
+            commands
+                .entity(fox_entity)
+                .remove::<(FollowTarget, FoxPathTarget)>();
+            if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                feedback_text.0 = locale.t(TextKey::FoxFollowStopped).to_string();
+            }
+            continue;
+        }
+        if possession_mode.is_active
+            || move_mode.contains(fox_entity)
+            || *visibility == Visibility::Hidden
+        {
+            commands.entity(fox_entity).remove::<FollowTarget>();
+            continue;
+        }
+
+        let Some(focus) = focus else {
+            continue;
+        };
+        match path {
+            Some(mut path) => {
+                if path.target != focus {
+                    path.target = focus;
+                }
+            }
+            None => {
+                let distance = (focus - fox_transform.translation).with_y(0.0).length();
+                if distance > FOX_FOLLOW_DISTANCE {
+                    commands.entity(fox_entity).insert(FoxPathTarget {
+                        target: focus,
+                        stuck_secs: 0.0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// キツネから最も近い雨宿りの場所（岩の縁）を探す
+///
+/// 岩の中心からキツネの方向へ岩の半径だけ進んだ位置を返す。岩がない場合は`None`。
+pub fn find_shelter(
+    fox_position: Vec3,
+    rock_positions: impl Iterator<Item = Vec3>,
+) -> Option<Vec3> {
+    let fox_xz = fox_position.with_y(0.0);
+    rock_positions
+        .map(|rock| rock.with_y(0.0))
+        .min_by(|a, b| a.distance(fox_xz).total_cmp(&b.distance(fox_xz)))
+        .map(|rock| {
+            let direction = (fox_xz - rock).normalize_or(Vec3::X);
+            (rock + direction * ROCK_RADIUS).with_y(FOX_INITIAL_HEIGHT)
+        })
+}
+
+/// 雨が降り始めたらキツネを岩の陰へ向かわせ、止んだら元の場所へ戻すシステム
+///
+/// 何もしていない（表示中で、憑依・移動モード・歩行中のいずれでもない）キツネだけが対象。
+/// 降り始めに雨宿りしなかったキツネも、嵐におびえた（[`Spooked`]になった）時点で岩の陰へ向かう。
+/// 移動は[`FoxPathTarget`]を設定して[`fox_path_to`]に任せる。
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn seek_shelter_in_rain(
+    mut commands: Commands,
+    weather: Res<WeatherState>,
+    fox_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Visibility,
+            Has<FoxPathTarget>,
+            Has<Spooked>,
+            Has<Sheltered>,
+        ),
+        With<Fox>,
+    >,
+    rock_query: Query<&GlobalTransform, With<Rock>>,
+    possession_mode: Res<PossessionMode>,
+    move_mode: Res<FoxMoveMode>,
+    mut was_raining: Local<bool>,
+    mut was_spooked: Local<bool>,
+    mut return_position: Local<Option<Vec3>>,
+) {
+    let rain_changed = weather.is_raining != *was_raining;
+    *was_raining = weather.is_raining;
+
+    let Ok((fox_entity, fox_transform, visibility, walking, spooked, sheltered)) =
+        fox_query.single()
+    else {
+        return;
+    };
+
+    // 雨宿りしていないキツネがおびえ始めたら、雨の降り始めを待たずに岩の陰へ向かう
+    let hurry_to_shelter = spooked && !*was_spooked && !sheltered;
+    *was_spooked = spooked;
+    if !rain_changed && !hurry_to_shelter {
+        return;
+    }
+
+    let idle = *visibility != Visibility::Hidden
+        && !walking
+        && !possession_mode.is_active
+        && !move_mode.is_active;
+    if !idle {
+        // おびえ始めた時は、降り始めに覚えた戻り先を残しておく
+        if rain_changed {
+            *return_position = None;
+        }
+        return;
+    }
+
+    let target = if weather.is_raining {
+        let shelter = find_shelter(
+            fox_transform.translation,
+            rock_query.iter().map(|t| t.translation()),
+        );
+        if shelter.is_some() && return_position.is_none() {
+            *return_position = Some(fox_transform.translation);
+        }
+        shelter
+    } else {
+        return_position.take()
+    };
+
+    if let Some(target) = target {
+        commands.entity(fox_entity).insert(FoxPathTarget {
+            target,
+            stuck_secs: 0.0,
+        });
+    }
+}
+
+/// 雨の間に岩の近くにいるキツネに[`Sheltered`]を付け外しするシステム
+pub fn update_fox_shelter(
+    mut commands: Commands,
+    weather: Res<WeatherState>,
+    fox_query: Query<(Entity, &Transform, Has<Sheltered>), With<Fox>>,
+    rock_query: Query<&GlobalTransform, With<Rock>>,
+) {
+    for (fox_entity, fox_transform, was_sheltered) in fox_query.iter() {
+        let fox_xz = fox_transform.translation.with_y(0.0);
+        let sheltered = weather.is_raining
+            && rock_query.iter().any(|rock| {
+                rock.translation().with_y(0.0).distance(fox_xz)
+                    <= ROCK_RADIUS + FOX_SHELTER_DISTANCE
+            });
+
+        if sheltered && !was_sheltered {
+            commands.entity(fox_entity).insert(Sheltered);
+        } else if !sheltered && was_sheltered {
+            commands.entity(fox_entity).remove::<Sheltered>();
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! キツネの選択表示
+//!
+//! 憑依中・移動中・アクションメニューを開いている・Shiftクリックでまとめて選んだキツネに
+//! [`FoxSelected`]を付け、足元に輪を表示する。ブロックの[`BlockHighlighted`]と同じく、
+//! マーカーの付け外しと表示用エンティティの生成・削除をまとめて行う。
+//!
+//! 輪をキツネの子にすると機嫌の色（[`crate::cf_systems::apply_fox_mood_tint`]）で
+//! 染まってしまうため、別のエンティティにして毎フレームキツネの足元に合わせる。
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+
+/// 選択中のキツネの足元に出す輪の共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct FoxSelectionAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for FoxSelectionAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(Annulus::new(
+            FOX_SELECTION_RING_INNER_RADIUS,
+            FOX_SELECTION_RING_OUTER_RADIUS,
+        ));
+        let (r, g, b, a) = FOX_SELECTION_RING_COLOR;
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgba(r, g, b, a),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                double_sided: true,
+                cull_mode: None,
+                ..default()
+            });
+        Self { mesh, material }
+    }
+}
+
+/// キツネの選択状態に合わせて[`FoxSelected`]と足元の輪を付け外しするシステム
+///
+/// 憑依を解除した時や移動を終えた時、アクションメニューを閉じた時は、
+/// 選択の条件から外れるのでここで輪も消える。
+#[allow(clippy::too_many_arguments)]
+pub fn update_fox_selection(
+    mut commands: Commands,
+    fox_query: Query<(Entity, Has<FoxSelected>), With<Fox>>,
+    ring_query: Query<(Entity, &FoxSelectionRing)>,
+    action_menu_query: Query<&FoxActionMenu>,
+    possession_mode: Res<PossessionMode>,
+    move_mode: Res<FoxMoveMode>,
+    group_selection: Res<FoxGroupSelection>,
+    assets: Res<FoxSelectionAssets>,
+) {
+    for (fox_entity, was_selected) in fox_query.iter() {
+        let selected = (possession_mode.is_active
+            && possession_mode.fox_entity == Some(fox_entity))
+            || (move_mode.is_active && move_mode.contains(fox_entity))
+            || action_menu_query.iter().any(|menu| menu.0 == fox_entity)
+            || group_selection.contains(fox_entity);
+        if selected == was_selected {
+            continue;
+        }
+
+        if selected {
+            commands.entity(fox_entity).insert(FoxSelected);
+            commands.spawn((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::default(),
+                Pickable::IGNORE,
+                FoxSelectionRing(fox_entity),
+            ));
+        } else {
+            commands.entity(fox_entity).remove::<FoxSelected>();
+        }
+    }
+
+    // 選択が外れたキツネや、いなくなったキツネの輪を消す
+    for (ring_entity, ring) in ring_query.iter() {
+        let still_selected = fox_query
+            .get(ring.0)
+            .is_ok_and(|(_, was_selected)| was_selected);
+        if !still_selected {
+            commands.entity(ring_entity).despawn();
+        }
+    }
+}
+
+/// 足元の輪をキツネの位置・大きさ・表示状態に合わせるシステム
+///
+/// 輪の大きさはキツネの成長に合わせて縮める。
+pub fn follow_fox_selection_ring(
+    fox_query: Query<(&Transform, &Visibility), Without<FoxSelectionRing>>,
+    mut ring_query: Query<(&FoxSelectionRing, &mut Transform, &mut Visibility)>,
+) {
+    for (ring, mut transform, mut visibility) in ring_query.iter_mut() {
+        let Ok((fox_transform, fox_visibility)) = fox_query.get(ring.0) else {
+            continue;
+        };
+
+        transform.translation = fox_transform.translation + Vec3::Y * FOX_SELECTION_RING_HEIGHT;
+        transform.rotation = Quat::from_rotation_x(-FRAC_PI_2);
+        transform.scale = Vec3::splat(fox_transform.scale.x / FOX_SCALE);
+        visibility.set_if_neq(*fox_visibility);
+    }
+}
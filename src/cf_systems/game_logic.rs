@@ -1,105 +1,67 @@
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
 
+use crate::cf_systems::audio::{SoundEffects, play_fox_bark};
+use crate::cf_systems::camera::main_camera;
+use crate::cf_systems::dust::{DustAssets, spawn_dust_puff};
+use crate::cf_systems::farming::{PlantAssets, spawn_plant};
+use crate::cf_systems::fence::{FenceAssets, spawn_fence};
+use crate::cf_systems::items::store_item_in_empty_slot;
+use crate::cf_systems::text_input::spawn_text_input;
+use crate::cf_systems::torch::{TorchAssets, spawn_torch};
+use crate::cf_systems::water::{WaterAssets, spawn_ripple};
 use crate::cf_tool;
 use crate::components::*;
 use crate::constants::*;
+use crate::events::*;
+use crate::locale::{Locale, TextKey};
 use crate::resources::*;
-use crate::traits::{camera_relative_movement, Aabb, RayIntersectable};
-
-/// レイとボックス（AABB）の交差判定を行う。
-///
-/// 後方互換性のためのヘルパー関数。内部では`Aabb`の`RayIntersectable`実装を使用。
-///
-/// # Arguments
-///
-/// * `ray` - 判定対象のレイ。原点と方向を持つ。
-/// * `box_center` - ボックスの中心座標。
-/// * `half_extents` - ボックスの各軸方向の半分のサイズ。
-///
-/// # Returns
-///
-/// * `Some(f32)` - レイがボックスと交差する場合、レイの原点から交点までの距離。
-/// * `None` - レイがボックスと交差しない場合。
-#[inline]
-pub fn ray_box_intersection(ray: &Ray3d, box_center: Vec3, half_extents: Vec3) -> Option<f32> {
-    Aabb::new(box_center, half_extents).ray_intersect(ray)
-}
+use crate::traits::{GameMode, apply_stick_deadzone, camera_relative_movement};
 
 /// マウスカーソルがブロックの上にホバーした際にハイライト表示を行う。
 ///
-/// カーソル位置からレイキャストを行い、選択可能なブロックと交差するかを判定する。
-/// 交差したブロックに対してハイライト用の半透明キューブを表示し、
+/// ホバー中のブロックはピッキングのオブザーバーが[`HoveredBlock`]に記録するので、
+/// それが選択可能なブロックであればハイライト用の半透明キューブを表示し、
 /// 移動モードの状態に応じて色を変更する（通常時：白、移動モード時：緑）。
+/// 岩などに遮られたブロックはホバー扱いにならない。
 ///
-/// カーソルが画面外にある場合や、ホバー対象が変わった場合は
+/// ホバー対象がなくなった場合や変わった場合は
 /// 既存のハイライトを削除して新しいハイライトを生成する。
 ///
 /// # Arguments
 ///
-/// * `window_query` - プライマリウィンドウの情報を取得するクエリ。
-/// * `camera_query` - メインカメラとその座標変換情報を取得するクエリ。
-/// * `block_query` - すべてのブロックエンティティとその座標を取得するクエリ。
-/// * `selectable_query` - 選択可能なブロックのみを絞り込むクエリ。
+/// * `hovered_block` - カーソルが乗っているブロックを保持するリソース。
+/// * `block_query` - 選択可能なブロックエンティティとその座標を取得するクエリ。
 /// * `commands` - エンティティの生成・削除を行うコマンドバッファ。
 /// * `highlight_query` - 既存のハイライトエンティティを取得するクエリ。
 /// * `highlighted_block_query` - 現在ハイライトされているブロックを取得するクエリ。
 /// * `material_assets` - マテリアルアセットの管理リソース。
 /// * `meshes` - メッシュアセットの管理リソース。
 /// * `move_mode` - キツネの移動モード状態を保持するリソース。
+/// * `camera_mode` - スペクテイターモード中はハイライトを消して何もしない。
 #[allow(clippy::too_many_arguments)]
 pub fn block_hover_highlight(
-    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
-    block_query: Query<(Entity, &GlobalTransform), With<Block>>,
-    selectable_query: Query<Entity, With<Selectable>>,
+    hovered_block: Res<HoveredBlock>,
+    block_query: Query<&GlobalTransform, (With<Block>, With<Selectable>)>,
     mut commands: Commands,
     highlight_query: Query<Entity, With<BlockHighlight>>,
     highlighted_block_query: Query<Entity, (With<Block>, With<BlockHighlighted>)>,
     mut material_assets: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
     move_mode: Res<FoxMoveMode>,
+    camera_mode: Res<CameraMode>,
 ) {
-    let Ok(window) = window_query.single() else {
-        return;
-    };
-
-    let Some(cursor_position) = window.cursor_position() else {
-        for highlight_entity in highlight_query.iter() {
-            commands.entity(highlight_entity).despawn();
-        }
-        for block_entity in highlighted_block_query.iter() {
-            commands.entity(block_entity).remove::<BlockHighlighted>();
-        }
-        return;
-    };
-
-    let Ok((camera, camera_transform)) = camera_query.single() else {
-        return;
-    };
-
-    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
-        return;
-    };
-
-    let mut hovering_block_entity = None;
-    let mut block_position = None;
-    let mut closest_distance = f32::MAX;
-
-    for (entity, block_transform) in block_query.iter() {
-        if !selectable_query.contains(entity) {
-            continue;
-        }
-
-        let block_pos = block_transform.translation();
-
-        if let Some(distance) = ray_box_intersection(&ray, block_pos, Vec3::splat(BLOCK_HALF_SIZE))
-            && distance < closest_distance
-        {
-            closest_distance = distance;
-            hovering_block_entity = Some(entity);
-            block_position = Some(block_pos);
-        }
-    }
+    let hovering = hovered_block
+        .entity
+        .filter(|_| !camera_mode.is_spectator())
+        .and_then(|entity| {
+            block_query
+                .get(entity)
+                .ok()
+                .map(|transform| (entity, transform.translation()))
+        });
+    let hovering_block_entity = hovering.map(|(entity, _)| entity);
+    let block_position = hovering.map(|(_, position)| position);
 
     let currently_highlighted = highlighted_block_query.iter().next();
 
@@ -146,6 +108,7 @@ pub fn block_hover_highlight(
                     ))),
                     MeshMaterial3d(highlight_material),
                     Transform::from_xyz(pos.x, pos.y, pos.z),
+                    Pickable::IGNORE,
                     BlockHighlight,
                 ));
             }
@@ -161,118 +124,402 @@ pub fn block_hover_highlight(
     }
 }
 
-/// マウス左クリックによるブロックおよびキツネの操作を処理する。
+/// クリックに応じてキツネのアクションメニューを開閉する。
 ///
-/// このシステムは以下の複数の機能を統合して処理する：
-/// 1. アイテムスロットから選択したアイテムをブロックに設置
-/// 2. 移動モード中のキツネをブロックに設置
-/// 3. キツネをクリックしてアクションメニューを表示
-/// 4. ブロックのタイマーをリセット
-///
-/// クリック対象はレイキャストで判定し、ブロックとキツネの両方を対象とする。
-/// UI ボタンがクリックされた場合は処理をスキップする。
-///
-/// # Arguments
-///
-/// * `mouse_input` - マウスボタンの入力状態。
-/// * `window_query` - プライマリウィンドウの情報を取得するクエリ。
-/// * `camera_query` - メインカメラとその座標変換情報を取得するクエリ。
-/// * `block_query` - すべてのブロックエンティティとその座標を取得するクエリ。
-/// * `selectable_query` - 選択可能なブロックのみを絞り込むクエリ。
-/// * `fox_query` - キツネエンティティとその座標を取得するクエリ。
-/// * `timer_query` - タイマーコンポーネントを持つエンティティを取得するクエリ。
-/// * `feedback_text_query` - フィードバック用のテキスト UI を取得するクエリ。
-/// * `commands` - エンティティの生成・削除を行うコマンドバッファ。
-/// * `action_menu_query` - キツネのアクションメニュー UI を取得するクエリ。
-/// * `move_mode` - キツネの移動モード状態を保持するリソース。
-/// * `fox_transform_query` - キツネの座標変換を変更するクエリ。
-/// * `button_interaction_query` - UI ボタンのインタラクション状態を取得するクエリ。
-/// * `selected_slot` - 現在選択中のアイテムスロット情報を保持するリソース。
-/// * `item_slot_query` - すべてのアイテムスロットを取得するクエリ。
+/// キツネがクリックされた場合はメニューを開き直して鳴き声を鳴らし、
+/// それ以外の場所がクリックされた場合は（移動モード中を除き）メニューとまとめての選択を閉じる。
+/// Shiftを押しながらキツネをクリックした場合は、メニューを開かずに
+/// [`FoxGroupSelection`]へ加える（選ばれていれば外す）。
 #[allow(clippy::too_many_arguments)]
-pub fn block_click_handler(
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
-    block_query: Query<(Entity, &GlobalTransform), With<Block>>,
-    selectable_query: Query<Entity, With<Selectable>>,
-    fox_query: Query<(Entity, &GlobalTransform), With<Fox>>,
-    mut timer_query: Query<&mut cf_tool::timer::Timer>,
-    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+pub fn handle_fox_action_menu_click(
+    mut fox_clicked: MessageReader<FoxClicked>,
+    mut block_clicked: MessageReader<BlockClicked>,
+    mut rock_clicked: MessageReader<RockClicked>,
+    mut empty_clicked: MessageReader<EmptySpaceClicked>,
     mut commands: Commands,
     action_menu_query: Query<Entity, With<FoxActionMenu>>,
-    mut move_mode: ResMut<FoxMoveMode>,
-    mut fox_transform_query: Query<&mut Transform, With<Fox>>,
-    button_interaction_query: Query<&Interaction, With<Button>>,
-    mut selected_slot: ResMut<SelectedItemSlot>,
-    mut item_slot_query: Query<&mut ItemSlot>,
+    fox_query: Query<(&GlobalTransform, Option<&FoxName>), With<Fox>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    move_mode: Res<FoxMoveMode>,
+    locale: Res<Locale>,
+    sounds: Res<SoundEffects>,
+    time: Res<Time>,
+    mut last_bark: Local<Option<f32>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut group_selection: ResMut<FoxGroupSelection>,
 ) {
-    if !mouse_input.just_pressed(MouseButton::Left) {
-        return;
-    }
+    let clicked_elsewhere =
+        block_clicked.read().count() + rock_clicked.read().count() + empty_clicked.read().count()
+            > 0;
 
-    for interaction in button_interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
+    if let Some(FoxClicked(fox_entity)) = fox_clicked.read().last() {
+        if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            group_selection.toggle(*fox_entity);
             return;
         }
-    }
 
-    let Ok(window) = window_query.single() else {
-        return;
-    };
+        for menu_entity in action_menu_query.iter() {
+            commands.entity(menu_entity).despawn();
+        }
 
-    let Some(cursor_position) = window.cursor_position() else {
-        return;
-    };
+        if let Ok((fox_transform, fox_name)) = fox_query.get(*fox_entity)
+            && let Some((camera, camera_transform)) = main_camera(camera_query.single())
+            && let Ok(window) = window_query.single()
+        {
+            spawn_fox_action_menu(
+                &mut commands,
+                *fox_entity,
+                fox_name.map_or("", |name| name.0.as_str()),
+                fox_transform.translation(),
+                camera,
+                camera_transform,
+                window.size(),
+                *locale,
+            );
+            play_fox_bark(&mut commands, &sounds, time.elapsed_secs(), &mut last_bark);
+        }
+    } else if clicked_elsewhere && !move_mode.is_active {
+        for menu_entity in action_menu_query.iter() {
+            commands.entity(menu_entity).despawn();
+        }
+        group_selection.foxes.clear();
+    }
+}
 
-    let Ok((camera, camera_transform)) = camera_query.single() else {
+/// ブロックやキツネのクリックに応じて収穫・タイマーリセットを行う。
+///
+/// 移動モード中やアイテム選択中は、設置処理を優先するため何もしない。
+/// 植物が植えられたブロックは収穫（未成熟なら成長度を表示）し、
+/// 柵や松明のあるブロックはそれを撤去してアイテムスロットに戻す（いずれも操作履歴に記録する）。
+/// それ以外はクリックした対象のタイマーをリセットする。
+#[allow(clippy::too_many_arguments)]
+pub fn handle_click_interaction(
+    mut block_clicked: MessageReader<BlockClicked>,
+    mut fox_clicked: MessageReader<FoxClicked>,
+    mut commands: Commands,
+    mut timer_query: Query<&mut cf_tool::timer::Timer>,
+    plant_query: Query<(Entity, &Plant, &Transform)>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    move_mode: Res<FoxMoveMode>,
+    selected_slot: Res<SelectedItemSlot>,
+    locale: Res<Locale>,
+    fence_query: Query<(Entity, &Fence, &Transform)>,
+    mut fenced_edges: ResMut<FencedEdges>,
+    torch_query: Query<(Entity, &Torch, &Transform)>,
+    mut history: ResMut<ActionHistory>,
+    mut inventory_full: MessageWriter<InventoryFull>,
+) {
+    let clicked_entities: Vec<Entity> = block_clicked
+        .read()
+        .map(|event| event.entity)
+        .chain(fox_clicked.read().map(|FoxClicked(entity)| *entity))
+        .collect();
+
+    if move_mode.is_active || selected_slot.item_type.is_some() {
         return;
-    };
+    }
 
-    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+    let Ok(mut feedback_text) = feedback_text_query.single_mut() else {
         return;
     };
 
-    let mut closest_entity = None;
-    let mut closest_distance = f32::MAX;
-    let mut is_fox = false;
-    let mut fox_position = None;
-
-    for (entity, block_transform) in block_query.iter() {
-        if !selectable_query.contains(entity) {
+    for clicked_entity in clicked_entities {
+        if take_block_object(
+            &mut commands,
+            clicked_entity,
+            &mut feedback_text,
+            &plant_query,
+            &fence_query,
+            &torch_query,
+            &mut item_slot_query,
+            &mut fenced_edges,
+            &mut history,
+            &mut inventory_full,
+            *locale,
+        ) {
             continue;
         }
 
-        let block_pos = block_transform.translation();
+        if let Ok(mut timer) = timer_query.get_mut(clicked_entity) {
+            timer.time = 0.0;
+            feedback_text.0 = locale.tf(TextKey::TimerReset, &[&timer.name]);
+        } else {
+            feedback_text.0 = "".to_string();
+        }
+    }
+}
 
-        if let Some(distance) = ray_box_intersection(&ray, block_pos, Vec3::splat(BLOCK_HALF_SIZE))
-            .filter(|&distance| distance < closest_distance)
+/// ブロックの上の植物・柵・松明をアイテムスロットに戻す
+///
+/// 成熟した植物は収穫し、柵と松明は撤去する（いずれも操作履歴に記録する）。
+/// 未成熟の植物は成長度を表示するだけにする。空きスロットがない場合は[`InventoryFull`]を送信する。
+///
+/// # Returns
+/// * `true` - ブロックの上に何か置かれていた（戻せたかどうかに関わらない）
+/// * `false` - ブロックの上に何もない
+#[allow(clippy::too_many_arguments)]
+pub fn take_block_object(
+    commands: &mut Commands,
+    block: Entity,
+    feedback_text: &mut Text,
+    plant_query: &Query<(Entity, &Plant, &Transform)>,
+    fence_query: &Query<(Entity, &Fence, &Transform)>,
+    torch_query: &Query<(Entity, &Torch, &Transform)>,
+    item_slot_query: &mut Query<&mut ItemSlot>,
+    fenced_edges: &mut FencedEdges,
+    history: &mut ActionHistory,
+    inventory_full: &mut MessageWriter<InventoryFull>,
+    locale: Locale,
+) -> bool {
+    if let Some((plant_entity, plant, transform)) = plant_query
+        .iter()
+        .find(|(_, plant, _)| plant.block == block)
+    {
+        if !plant.is_mature() {
+            feedback_text.0 = locale.tf(
+                TextKey::PlantGrowing,
+                &[&format!("{:.0}", plant.growth * 100.0)],
+            );
+        } else if let Some(slot_index) =
+            store_item_in_empty_slot(item_slot_query, ItemType::Produce)
         {
-            closest_distance = distance;
-            closest_entity = Some(entity);
+            commands.entity(plant_entity).despawn();
+            history.push(HistoryAction::ObjectRemoved {
+                object: PlacedObject::Plant {
+                    growth: plant.growth,
+                },
+                block,
+                position: transform.translation,
+                item: ItemType::Produce,
+                slot_index,
+            });
+            feedback_text.0 = locale.t(TextKey::CropHarvested).to_string();
+        } else {
+            inventory_full.write(InventoryFull);
         }
+    } else if let Some((fence_entity, fence, transform)) = fence_query
+        .iter()
+        .find(|(_, fence, _)| fence.block == block)
+    {
+        if let Some(slot_index) = store_item_in_empty_slot(item_slot_query, ItemType::Fence) {
+            fenced_edges.remove(fence.tile, fence.side);
+            commands.entity(fence_entity).despawn();
+            history.push(HistoryAction::ObjectRemoved {
+                object: PlacedObject::Fence { side: fence.side },
+                block,
+                position: transform.translation,
+                item: ItemType::Fence,
+                slot_index,
+            });
+            feedback_text.0 = locale.t(TextKey::FenceRemoved).to_string();
+        } else {
+            inventory_full.write(InventoryFull);
+        }
+    } else if let Some((torch_entity, _, transform)) = torch_query
+        .iter()
+        .find(|(_, torch, _)| torch.block == block)
+    {
+        if let Some(slot_index) = store_item_in_empty_slot(item_slot_query, ItemType::Torch) {
+            commands.entity(torch_entity).despawn();
+            history.push(HistoryAction::ObjectRemoved {
+                object: PlacedObject::Torch,
+                block,
+                position: transform.translation,
+                item: ItemType::Torch,
+                slot_index,
+            });
+            feedback_text.0 = locale.t(TextKey::TorchRemoved).to_string();
+        } else {
+            inventory_full.write(InventoryFull);
+        }
+    } else {
+        return false;
     }
 
-    if !move_mode.is_active && selected_slot.item_type.is_none() {
-        for (entity, fox_transform) in fox_query.iter() {
-            let fox_pos = fox_transform.translation();
+    true
+}
 
-            if let Some(distance) = ray_box_intersection(&ray, fox_pos, Vec3::splat(FOX_HALF_SIZE))
-                .filter(|&distance| distance < closest_distance)
-            {
-                closest_distance = distance;
-                closest_entity = Some(entity);
-                is_fox = true;
-                fox_position = Some(fox_pos);
-            }
-        }
+/// 選択中のアイテムをクリックしたブロックに設置する。
+///
+/// 種の場合はブロックに植え、キツネの場合は格納していたキツネを再配置する。
+/// キツネ・種・松明の位置は[`PlacementGrid`]の区画にスナップする（柵はクリック位置に最も近いブロックの辺に立てる）。
+/// 設置した操作は元に戻せるよう[`ActionHistory`]に記録する。
+/// 設置に成功した場合のみスロットからアイテムを消費する。
+/// 何もない場所をクリックした場合は選択を解除する。
+#[allow(clippy::too_many_arguments)]
+pub fn handle_item_placement(
+    mut block_clicked: MessageReader<BlockClicked>,
+    mut empty_clicked: MessageReader<EmptySpaceClicked>,
+    mut commands: Commands,
+    block_query: Query<&GlobalTransform, With<Block>>,
+    mut fox_query: Query<(Entity, &mut Transform, &Visibility), With<Fox>>,
+    plant_query: Query<&Plant>,
+    plant_assets: Res<PlantAssets>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    fence_assets: Res<FenceAssets>,
+    mut fenced_edges: ResMut<FencedEdges>,
+    (field_config, placement_grid, mut history, mut object_built): (
+        Res<FieldConfig>,
+        Res<PlacementGrid>,
+        ResMut<ActionHistory>,
+        MessageWriter<ObjectBuilt>,
+    ),
+    torch_assets: Res<TorchAssets>,
+    torch_query: Query<&Torch>,
+) {
+    if empty_clicked.read().count() > 0 && selected_slot.slot_index.is_some() {
+        selected_slot.slot_index = None;
+        selected_slot.item_type = None;
     }
 
-    if let Some(clicked_entity) = closest_entity {
-        if let Some(item_type) = &selected_slot.item_type
-            && !is_fox
-            && let Some(slot_idx) = selected_slot.slot_index
-        {
+    for BlockClicked {
+        entity: clicked_entity,
+        hit_point,
+    } in block_clicked.read()
+    {
+        let (Some(item_type), Some(slot_idx)) =
+            (selected_slot.item_type.clone(), selected_slot.slot_index)
+        else {
+            continue;
+        };
+
+        let Ok(block_transform) = block_query.get(*clicked_entity) else {
+            continue;
+        };
+        let block_pos = block_transform.translation();
+        let snapped_pos = placement_grid.snap(block_pos, *hit_point);
+        let tile = field_config.grid_coords(block_pos);
+        let fence_side = FenceSide::nearest(block_pos, *hit_point);
+        let is_planted = plant_query
+            .iter()
+            .any(|plant| plant.block == *clicked_entity);
+        let has_torch = torch_query
+            .iter()
+            .any(|torch| torch.block == *clicked_entity);
+        let is_occupied = is_planted || has_torch;
+
+        // 設置に成功した場合のみスロットからアイテムを消費する
+        let consumed = match item_type {
+            ItemType::Fox => {
+                // スロットに格納されているのは非表示のキツネ
+                if let Some((fox_entity, mut fox_transform, _)) = fox_query
+                    .iter_mut()
+                    .find(|(_, _, visibility)| **visibility == Visibility::Hidden)
+                {
+                    fox_transform.translation = snapped_pos + Vec3::Y * FOX_INITIAL_HEIGHT;
+                    commands.entity(fox_entity).insert(Visibility::Visible);
+                    history.push(HistoryAction::FoxMoved {
+                        fox: fox_entity,
+                        from: FoxLocation::Slot(slot_idx),
+                        to: FoxLocation::Field(fox_transform.translation),
+                    });
+
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::ItemPlaced).to_string();
+                    }
+                }
+                true
+            }
+            ItemType::Seed => {
+                if is_planted {
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::AlreadyPlanted).to_string();
+                    }
+                    false
+                } else if is_occupied {
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::BlockOccupied).to_string();
+                    }
+                    false
+                } else {
+                    let plant_entity = spawn_plant(
+                        &mut commands,
+                        &plant_assets,
+                        *clicked_entity,
+                        snapped_pos,
+                        0.0,
+                    );
+                    history.push(HistoryAction::ObjectPlaced {
+                        object: PlacedObject::Plant { growth: 0.0 },
+                        entity: plant_entity,
+                        block: *clicked_entity,
+                        position: snapped_pos,
+                        item: ItemType::Seed,
+                        slot_index: slot_idx,
+                    });
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::SeedPlanted).to_string();
+                    }
+                    true
+                }
+            }
+            ItemType::Fence => {
+                if fenced_edges.contains(tile, fence_side) {
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::BlockOccupied).to_string();
+                    }
+                    false
+                } else {
+                    let fence_entity = spawn_fence(
+                        &mut commands,
+                        &fence_assets,
+                        &mut fenced_edges,
+                        *clicked_entity,
+                        block_pos,
+                        tile,
+                        fence_side,
+                    );
+                    history.push(HistoryAction::ObjectPlaced {
+                        object: PlacedObject::Fence { side: fence_side },
+                        entity: fence_entity,
+                        block: *clicked_entity,
+                        position: block_pos,
+                        item: ItemType::Fence,
+                        slot_index: slot_idx,
+                    });
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::FencePlaced).to_string();
+                    }
+                    true
+                }
+            }
+            ItemType::Torch => {
+                if is_occupied {
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::BlockOccupied).to_string();
+                    }
+                    false
+                } else {
+                    let torch_entity =
+                        spawn_torch(&mut commands, &torch_assets, *clicked_entity, snapped_pos);
+                    history.push(HistoryAction::ObjectPlaced {
+                        object: PlacedObject::Torch,
+                        entity: torch_entity,
+                        block: *clicked_entity,
+                        position: snapped_pos,
+                        item: ItemType::Torch,
+                        slot_index: slot_idx,
+                    });
+                    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                        feedback_text.0 = locale.t(TextKey::TorchPlaced).to_string();
+                    }
+                    true
+                }
+            }
+            ItemType::Produce | ItemType::Ore | ItemType::Pickaxe { .. } => {
+                if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                    feedback_text.0 = locale.t(TextKey::ItemNotPlaceable).to_string();
+                }
+                false
+            }
+        };
+
+        if consumed {
             for mut slot in item_slot_query.iter_mut() {
                 if slot.slot_index == slot_idx {
                     slot.item = None;
@@ -280,97 +527,188 @@ pub fn block_click_handler(
                 }
             }
 
-            match item_type {
-                ItemType::Fox => {
-                    if let Ok((fox_entity, _)) = fox_query.single()
-                        && let Ok(mut fox_transform) = fox_transform_query.get_mut(fox_entity)
-                    {
-                        if let Ok((_, block_transform)) = block_query.get(clicked_entity) {
-                            let block_pos = block_transform.translation();
-                            fox_transform.translation =
-                                Vec3::new(block_pos.x, FOX_INITIAL_HEIGHT, block_pos.z);
-                        }
-                        commands.entity(fox_entity).insert(Visibility::Visible);
+            if !matches!(item_type, ItemType::Fox) {
+                object_built.write(ObjectBuilt);
+            }
+        }
+
+        selected_slot.slot_index = None;
+        selected_slot.item_type = None;
+    }
+}
+
+/// 移動モード中にクリックしたブロックへキツネを移動させる。
+///
+/// 移動先は[`PlacementGrid`]の区画にスナップする。
+/// [`FoxMoveStyle::Walk`]の場合は[`FoxPathTarget`]を設定して歩かせ（[`fox_path_to`](crate::cf_systems::fox_path_to)が担当）、
+/// [`FoxMoveStyle::Teleport`]の場合は掴んでいるキツネをその場に設置する。
+/// 複数のキツネを移動している場合は、クリックした位置を中心に隊列を組んで並べ、
+/// まとめて1つの操作として履歴に記録する。
+/// 隊列の位置が選択可能なブロックの上でない（または水の上の）キツネは、
+/// クリックした位置へ寄せて並べる。
+///
+/// 選択可能なブロック以外（水のブロックを含む）をクリックした場合はエラーメッセージを表示し、
+/// 移動モードを継続する。
+#[allow(clippy::too_many_arguments)]
+pub fn handle_move_mode_placement(
+    mut block_clicked: MessageReader<BlockClicked>,
+    mut rock_clicked: MessageReader<RockClicked>,
+    mut empty_clicked: MessageReader<EmptySpaceClicked>,
+    block_query: Query<&GlobalTransform, With<Block>>,
+    selectable_query: Query<&GlobalTransform, (With<Block>, With<Selectable>)>,
+    mut fox_transform_query: Query<&mut Transform, With<Fox>>,
+    mut move_mode: ResMut<FoxMoveMode>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    placement_grid: Res<PlacementGrid>,
+    mut history: ResMut<ActionHistory>,
+    (mut commands, move_style, field_config, water_tiles): (
+        Commands,
+        Res<FoxMoveStyle>,
+        Res<FieldConfig>,
+        Res<WaterTiles>,
+    ),
+) {
+    let mut missed = rock_clicked.read().count() + empty_clicked.read().count() > 0;
+
+    if !move_mode.is_active || !move_mode.is_holding {
+        block_clicked.clear();
+        return;
+    }
 
-                        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-                            feedback_text.0 = "アイテムを設置しました！".to_string();
+    // キツネを立たせられるマス（選択可能で水でないブロック）
+    let standable_tiles: HashSet<IVec2> = selectable_query
+        .iter()
+        .map(|transform| field_config.grid_coords(transform.translation()))
+        .filter(|tile| !water_tiles.tiles.contains(tile))
+        .collect();
+    let mut clicked = block_clicked.read().last();
+    // 水のブロックには立たせられないので、選択可能なブロック以外をクリックした場合と同じ扱いにする
+    if let Some(block) = clicked
+        && !block_query.get(block.entity).is_ok_and(|transform| {
+            standable_tiles.contains(&field_config.grid_coords(transform.translation()))
+        })
+    {
+        clicked = None;
+        missed = true;
+    }
+
+    if let Some(clicked) = clicked {
+        if !move_mode.foxes.is_empty() {
+            if let Ok(block_transform) = block_query.get(clicked.entity) {
+                let pos = placement_grid.snap(block_transform.translation(), clicked.hit_point);
+                let mut moves = Vec::with_capacity(move_mode.foxes.len());
+
+                for (index, &(fox_entity, original_position)) in move_mode.foxes.iter().enumerate()
+                {
+                    let Ok(mut fox_transform) = fox_transform_query.get_mut(fox_entity) else {
+                        continue;
+                    };
+                    // 隊列の位置が立てないマスにかかる場合は、半分の距離、クリックした位置の順に寄せる
+                    let offset = move_mode.formation_offset(index);
+                    let position = [offset, offset * 0.5, Vec3::ZERO]
+                        .into_iter()
+                        .map(|offset| pos + offset)
+                        .find(|position| {
+                            standable_tiles.contains(&field_config.grid_coords(*position))
+                        })
+                        .unwrap_or(pos);
+                    // 隊列の位置が持ち上げたマスにかかる場合は、そのマスの上に立たせる
+                    let target =
+                        position.with_y(field_config.elevation_at(position) + FOX_INITIAL_HEIGHT);
+
+                    match *move_style {
+                        FoxMoveStyle::Walk => {
+                            commands.entity(fox_entity).insert(FoxPathTarget {
+                                target,
+                                stuck_secs: 0.0,
+                            });
                         }
+                        FoxMoveStyle::Teleport => fox_transform.translation = target,
                     }
-                }
-            }
 
-            selected_slot.slot_index = None;
-            selected_slot.item_type = None;
-            return;
-        }
-
-        if move_mode.is_active && move_mode.is_holding && !is_fox {
-            if let Some(fox_entity) = move_mode.fox_entity
-                && let Ok(mut fox_transform) = fox_transform_query.get_mut(fox_entity)
-            {
-                if let Ok((_, block_transform)) = block_query.get(clicked_entity) {
-                    let block_pos = block_transform.translation();
-                    fox_transform.translation =
-                        Vec3::new(block_pos.x, FOX_INITIAL_HEIGHT, block_pos.z);
+                    moves.push((
+                        fox_entity,
+                        FoxLocation::Field(original_position),
+                        FoxLocation::Field(target),
+                    ));
                 }
 
-                if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-                    feedback_text.0 = "キツネを設置しました！".to_string();
+                if let [(fox, from, to)] = moves[..] {
+                    history.push(HistoryAction::FoxMoved { fox, from, to });
+                } else if !moves.is_empty() {
+                    history.push(HistoryAction::FoxesMoved(moves));
                 }
             }
 
-            move_mode.is_active = false;
-            move_mode.is_holding = false;
-            move_mode.fox_entity = None;
-            return;
+            if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+                let message = match *move_style {
+                    FoxMoveStyle::Walk => TextKey::FoxWalking,
+                    FoxMoveStyle::Teleport => TextKey::FoxPlaced,
+                };
+                feedback_text.0 = locale.t(message).to_string();
+            }
         }
 
-        if is_fox && !move_mode.is_active {
-            for menu_entity in action_menu_query.iter() {
-                commands.entity(menu_entity).despawn();
-            }
+        move_mode.deactivate();
+    } else if missed && let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.t(TextKey::SelectableBlocksOnly).to_string();
+    }
+}
 
-            if let Some(pos) = fox_position {
-                spawn_fox_action_menu(&mut commands, pos, camera, camera_transform);
-            }
-        } else if !is_fox && !move_mode.is_active {
-            for menu_entity in action_menu_query.iter() {
-                commands.entity(menu_entity).despawn();
-            }
-        }
+/// Gキーで配置グリッドの分割数を切り替えるシステム
+pub fn cycle_placement_grid(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut placement_grid: ResMut<PlacementGrid>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyG) {
+        return;
+    }
 
-        if !move_mode.is_active {
-            if let Ok(mut timer) = timer_query.get_mut(clicked_entity) {
-                timer.time = 0.0;
-                if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-                    feedback_text.0 = format!("{} clicked! Timer reset!", timer.name);
-                }
-            } else if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-                feedback_text.0 = "".to_string();
-            }
-        }
-    } else if move_mode.is_active && move_mode.is_holding {
-        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-            feedback_text.0 = "選択可能なブロックにのみ設置できます！".to_string();
-        }
-    } else {
-        for menu_entity in action_menu_query.iter() {
-            commands.entity(menu_entity).despawn();
-        }
-        if selected_slot.item_type.is_some() {
-            selected_slot.slot_index = None;
-            selected_slot.item_type = None;
-        }
+    placement_grid.cycle();
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.tf(
+            TextKey::PlacementGridChanged,
+            &[&placement_grid.divisions, &placement_grid.divisions],
+        );
     }
 }
 
+/// 対象の画面座標からアクションメニューの左上座標を計算する。
+///
+/// 通常は対象の左上に表示し、画面端からはみ出す場合はオフセットの向きを
+/// 反転させる。それでも収まらない場合はウィンドウ内にクランプする。
+/// キツネとブロックのアクションメニューで共通に使う。
+pub fn action_menu_position(screen_pos: Vec2, window_size: Vec2, menu_size: Vec2) -> Vec2 {
+    let mut left = screen_pos.x - FOX_ACTION_MENU_OFFSET_X;
+    if left + menu_size.x > window_size.x {
+        left = screen_pos.x + FOX_ACTION_MENU_OFFSET_X - menu_size.x;
+    }
+
+    let mut top = screen_pos.y - FOX_ACTION_MENU_OFFSET_Y;
+    if top < 0.0 {
+        top = screen_pos.y + FOX_ACTION_MENU_OFFSET_Y - menu_size.y;
+    }
+
+    let max = (window_size - menu_size).max(Vec2::ZERO);
+    Vec2::new(left.clamp(0.0, max.x), top.clamp(0.0, max.y))
+}
+
 /// キツネのアクションメニュー UI を生成する。
 ///
 /// キツネがクリックされた際に呼び出され、キツネの3D位置を画面座標に変換して
-/// その近くに「Move」と「Box」の2つのボタンを持つメニューを表示する。
+/// その近くに「Move」「Box」「Possession」「Follow」「Cancel」のボタンと、
+/// 名前を変更するテキスト入力欄を持つメニューを表示する。
+/// メニューはウィンドウからはみ出さない位置に配置される。
 ///
 /// - **Move ボタン**: キツネを移動モードにして、別のブロックに設置可能にする。
 /// - **Box ボタン**: キツネをアイテムスロットに格納して非表示にする。
+/// - **Possession ボタン**: キツネに憑依して直接操作する。
+/// - **Follow ボタン**: キツネをカメラの注視点について来させる（もう一度押すとやめる）。
+/// - **Cancel ボタン**: 何もせずにメニューを閉じる。
 ///
 /// # Arguments
 ///
@@ -378,55 +716,96 @@ pub fn block_click_handler(
 /// * `fox_position` - キツネの3D ワールド座標。
 /// * `camera` - メニュー表示位置を計算するためのカメラ。
 /// * `camera_transform` - カメラのグローバル座標変換。
+/// * `window_size` - メニューを画面内に収めるためのウィンドウサイズ。
+/// * `locale` - ボタンの表示言語。
+#[allow(clippy::too_many_arguments)]
 fn spawn_fox_action_menu(
     commands: &mut Commands,
+    fox_entity: Entity,
+    fox_name: &str,
     fox_position: Vec3,
     camera: &Camera,
     camera_transform: &GlobalTransform,
+    window_size: Vec2,
+    locale: Locale,
 ) {
     let Ok(screen_pos) = camera.world_to_viewport(camera_transform, fox_position) else {
         return;
     };
 
+    let menu_pos = action_menu_position(
+        screen_pos,
+        window_size,
+        Vec2::new(FOX_ACTION_MENU_WIDTH, FOX_ACTION_MENU_HEIGHT),
+    );
+
     commands
         .spawn((
             Node {
                 position_type: PositionType::Absolute,
-                left: Val::Px(screen_pos.x - 60.0),
-                top: Val::Px(screen_pos.y - 80.0),
-                flex_direction: FlexDirection::Row,
-                column_gap: Val::Px(10.0),
+                left: Val::Px(menu_pos.x),
+                top: Val::Px(menu_pos.y),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
                 ..default()
             },
-            FoxActionMenu,
+            FoxActionMenu(fox_entity),
         ))
-        .with_children(|parent| {
-            spawn_button!(parent, {
-                size: (55.0, 30.0),
-                text: "Move",
-                font_size: 14.0,
-                bg_color: (0.3, 0.5, 0.7),
-                border_color: (0.5, 0.7, 0.9),
-                component: FoxActionButton::Move,
-            });
+        .with_children(|menu| {
+            menu.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(10.0),
+                ..default()
+            })
+            .with_children(|parent| {
+                spawn_button!(parent, {
+                    size: (55.0, 30.0),
+                    text: locale.t(TextKey::MoveButton),
+                    font_size: 14.0,
+                    bg_color: (0.3, 0.5, 0.7),
+                    border_color: (0.5, 0.7, 0.9),
+                    component: FoxActionButton::Move,
+                });
 
-            spawn_button!(parent, {
-                size: (55.0, 30.0),
-                text: "Box",
-                font_size: 14.0,
-                bg_color: (0.5, 0.4, 0.3),
-                border_color: (0.7, 0.6, 0.5),
-                component: FoxActionButton::Box,
-            });
+                spawn_button!(parent, {
+                    size: (55.0, 30.0),
+                    text: locale.t(TextKey::BoxButton),
+                    font_size: 14.0,
+                    bg_color: (0.5, 0.4, 0.3),
+                    border_color: (0.7, 0.6, 0.5),
+                    component: FoxActionButton::Box,
+                });
+
+                spawn_button!(parent, {
+                    size: (80.0, 30.0),
+                    text: locale.t(TextKey::PossessionButton),
+                    font_size: 12.0,
+                    bg_color: (0.6, 0.3, 0.6),
+                    border_color: (0.8, 0.5, 0.8),
+                    component: FoxActionButton::Possession,
+                });
+
+                spawn_button!(parent, {
+                    size: (70.0, 30.0),
+                    text: locale.t(TextKey::FollowButton),
+                    font_size: 12.0,
+                    bg_color: (0.3, 0.6, 0.4),
+                    border_color: (0.5, 0.8, 0.6),
+                    component: FoxActionButton::Follow,
+                });
 
-            spawn_button!(parent, {
-                size: (80.0, 30.0),
-                text: "Possession",
-                font_size: 12.0,
-                bg_color: (0.6, 0.3, 0.6),
-                border_color: (0.8, 0.5, 0.8),
-                component: FoxActionButton::Possession,
+                spawn_button!(parent, {
+                    size: (55.0, 30.0),
+                    text: locale.t(TextKey::CancelButton),
+                    font_size: 14.0,
+                    bg_color: (0.4, 0.4, 0.4),
+                    border_color: (0.6, 0.6, 0.6),
+                    component: FoxActionButton::Cancel,
+                });
             });
+
+            spawn_text_input(menu, fox_name, FOX_NAME_MAX_LENGTH, FOX_ACTION_MENU_WIDTH)
+                .insert(FoxNameInput(fox_entity));
         });
 }
 
@@ -436,8 +815,12 @@ fn spawn_fox_action_menu(
 ///
 /// - **Move ボタン**: キツネを移動モードに切り替え、カーソルに追従させる。
 ///   その後、ブロックをクリックすることでキツネを設置できる。
+///   [`FoxGroupSelection`]で選んだキツネがいれば、まとめて移動させる。
 /// - **Box ボタン**: キツネを空いているアイテムスロットに格納し、
-///   キツネを非表示にする。スロットが満杯の場合はエラーメッセージを表示。
+///   キツネを非表示にする。スロットが満杯の場合は[`InventoryFull`]を送信する。
+/// - **Follow ボタン**: メニューを開いたキツネに[`FollowTarget`]を付け、
+///   カメラの注視点について来させる。既について来ている場合は外す。
+/// - **Cancel ボタン**: 何もせずにアクションメニューを閉じる。
 ///
 /// ボタンクリック後はアクションメニューを自動的に閉じる。
 ///
@@ -448,81 +831,135 @@ fn spawn_fox_action_menu(
 /// * `move_mode` - キツネの移動モード状態を保持するリソース。
 /// * `fox_query` - キツネエンティティを取得するクエリ。
 /// * `commands` - エンティティの削除（メニュー閉じる）などを行うコマンドバッファ。
-/// * `action_menu_query` - キツネのアクションメニュー UI を取得するクエリ。
+/// * `action_menu_query` - キツネのアクションメニュー UI と対象のキツネを取得するクエリ。
 /// * `item_slot_query` - すべてのアイテムスロットを取得するクエリ。
+/// * `follow_query` - キツネが既について来ているかを調べるクエリ。
+/// * `group_selection` - Shiftクリックでまとめて選んだキツネ。
 pub fn handle_fox_action_buttons(
     interaction_query: Query<(&Interaction, &FoxActionButton), Changed<Interaction>>,
     mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
     mut move_mode: ResMut<FoxMoveMode>,
     mut possession_mode: ResMut<crate::resources::PossessionMode>,
-    fox_query: Query<Entity, With<Fox>>,
+    fox_query: Query<(Entity, &Transform, &Visibility), With<Fox>>,
     mut commands: Commands,
-    action_menu_query: Query<Entity, With<FoxActionMenu>>,
+    action_menu_query: Query<(Entity, &FoxActionMenu)>,
     mut item_slot_query: Query<&mut ItemSlot>,
     camera_query: Query<&Transform, With<MainCamera>>,
+    locale: Res<Locale>,
+    mut history: ResMut<ActionHistory>,
+    mut fox_possessed: MessageWriter<FoxPossessed>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut inventory_full: MessageWriter<InventoryFull>,
+    follow_query: Query<Has<FollowTarget>, With<Fox>>,
+    mut group_selection: ResMut<FoxGroupSelection>,
 ) {
+    // ボタンはメニューを開いたキツネに対して働く
+    let menu_fox = action_menu_query
+        .iter()
+        .next()
+        .and_then(|(_, FoxActionMenu(fox_entity))| fox_query.get(*fox_entity).ok());
+
     for (interaction, button_type) in interaction_query.iter() {
         if *interaction == Interaction::Pressed
             && let Ok(mut feedback_text) = feedback_text_query.single_mut()
         {
             match button_type {
                 FoxActionButton::Move => {
-                    if let Ok(fox_entity) = fox_query.single() {
-                        move_mode.is_active = true;
-                        move_mode.is_holding = true;
-                        move_mode.fox_entity = Some(fox_entity);
-                        feedback_text.0 =
-                            "移動モード: 移動先をクリックして設置してください".to_string();
-
-                        for menu_entity in action_menu_query.iter() {
+                    if let Some((fox_entity, fox_transform, _)) = menu_fox {
+                        // メニューを開いたキツネを先頭に、まとめて選んだキツネを隊列に加える
+                        let followers = group_selection
+                            .foxes
+                            .iter()
+                            .filter(|&&fox| fox != fox_entity)
+                            .filter_map(|&fox| fox_query.get(fox).ok())
+                            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+                            .map(|(fox, transform, _)| (fox, transform.translation));
+                        move_mode.start(
+                            std::iter::once((fox_entity, fox_transform.translation))
+                                .chain(followers)
+                                .collect(),
+                        );
+                        group_selection.foxes.clear();
+                        feedback_text.0 = locale.t(TextKey::MoveModeStarted).to_string();
+
+                        for (menu_entity, _) in action_menu_query.iter() {
                             commands.entity(menu_entity).despawn();
                         }
                     }
                 }
                 FoxActionButton::Box => {
-                    if let Ok(fox_entity) = fox_query.single() {
+                    if let Some((fox_entity, fox_transform, _)) = menu_fox {
                         let mut slots: Vec<_> = item_slot_query.iter_mut().collect();
                         slots.sort_by_key(|slot| slot.slot_index);
 
-                        let mut stored = false;
-                        for mut slot in slots {
-                            if slot.item.is_none() {
-                                slot.item = Some(ItemType::Fox);
-                                commands.entity(fox_entity).insert(Visibility::Hidden);
-                                feedback_text.0 =
-                                    "キツネをアイテムエリアに格納しました！".to_string();
-                                stored = true;
-                                break;
+                        // 選択中の空きスロットがあればそこに、なければ最初の空きスロットに格納する
+                        let preferred = selected_slot.slot_index.filter(|&index| {
+                            slots
+                                .iter()
+                                .any(|slot| slot.slot_index == index && slot.item.is_none())
+                        });
+                        let target = slots.into_iter().find(|slot| match preferred {
+                            Some(index) => slot.slot_index == index,
+                            None => slot.item.is_none(),
+                        });
+
+                        if let Some(mut slot) = target {
+                            slot.item = Some(ItemType::Fox);
+                            commands.entity(fox_entity).insert(Visibility::Hidden);
+                            history.push(HistoryAction::FoxMoved {
+                                fox: fox_entity,
+                                from: FoxLocation::Field(fox_transform.translation),
+                                to: FoxLocation::Slot(slot.slot_index),
+                            });
+                            feedback_text.0 = locale.t(TextKey::FoxStored).to_string();
+                            if selected_slot.item_type.is_none() {
+                                *selected_slot = SelectedItemSlot::default();
                             }
+                        } else {
+                            inventory_full.write(InventoryFull);
                         }
 
-                        if !stored {
-                            feedback_text.0 = "アイテムスロットがいっぱいです！".to_string();
-                        }
-
-                        for menu_entity in action_menu_query.iter() {
+                        for (menu_entity, _) in action_menu_query.iter() {
                             commands.entity(menu_entity).despawn();
                         }
                     }
                 }
                 FoxActionButton::Possession => {
-                    if let Ok(fox_entity) = fox_query.single() {
-                        // 現在のカメラ位置を保存
-                        if let Ok(camera_transform) = camera_query.single() {
-                            possession_mode.previous_camera_transform = Some(*camera_transform);
-                        }
-
-                        possession_mode.is_active = true;
-                        possession_mode.fox_entity = Some(fox_entity);
-                        possession_mode.camera_offset = Vec3::new(0.0, 2.0, -3.0);
-                        feedback_text.0 =
-                            "Possessionモード: WASDキーでキツネを操作できます (Escで解除)".to_string();
-
-                        for menu_entity in action_menu_query.iter() {
+                    if let Some((fox_entity, _, _)) = menu_fox {
+                        start_possession(
+                            &mut possession_mode,
+                            fox_entity,
+                            main_camera(camera_query.single()),
+                        );
+                        fox_possessed.write(FoxPossessed);
+                        feedback_text.0 = locale.t(TextKey::PossessionStarted).to_string();
+
+                        for (menu_entity, _) in action_menu_query.iter() {
                             commands.entity(menu_entity).despawn();
                         }
                     }
                 }
+                FoxActionButton::Follow => {
+                    for (menu_entity, FoxActionMenu(fox_entity)) in action_menu_query.iter() {
+                        if let Ok(following) = follow_query.get(*fox_entity) {
+                            if following {
+                                commands
+                                    .entity(*fox_entity)
+                                    .remove::<(FollowTarget, FoxPathTarget)>();
+                                feedback_text.0 = locale.t(TextKey::FoxFollowStopped).to_string();
+                            } else {
+                                commands.entity(*fox_entity).insert(FollowTarget);
+                                feedback_text.0 = locale.t(TextKey::FoxFollowStarted).to_string();
+                            }
+                        }
+                        commands.entity(menu_entity).despawn();
+                    }
+                }
+                FoxActionButton::Cancel => {
+                    for (menu_entity, _) in action_menu_query.iter() {
+                        commands.entity(menu_entity).despawn();
+                    }
+                }
             }
         }
     }
@@ -531,25 +968,31 @@ pub fn handle_fox_action_buttons(
 /// 移動モード中にキツネをカーソル位置に追従させる。
 ///
 /// キツネが移動モード（`move_mode.is_active && move_mode.is_holding`）の場合、
-/// カーソル位置からレイキャストを行い、Y 座標が `FOX_INITIAL_HEIGHT` の平面との
-/// 交点にキツネを配置する。実際には少し浮かせて表示するため、
-/// `FOX_HOVER_HEIGHT` を加算した高さに設定する。
+/// カーソル位置からレイキャストを行い、ブロック上面（持ち上げたマスはその上面）との
+/// 交点にキツネを配置する。高さはそのマスのブロックに立たせた時の高さに、
+/// 少し浮かせて表示するための `FOX_HOVER_HEIGHT` を加算したものにする。
+/// 複数のキツネを掴んでいる場合は、交点を中心に隊列を組ませる。
 ///
-/// カーソルが画面外にある場合は処理をスキップする。
+/// カーソルが画面外にある場合や、歩いて移動させる設定（[`FoxMoveStyle::Walk`]）の場合は
+/// 処理をスキップする。
 ///
 /// # Arguments
 ///
 /// * `move_mode` - キツネの移動モード状態を保持するリソース。
+/// * `move_style` - 移動モードでのキツネの動かし方。
 /// * `window_query` - プライマリウィンドウの情報を取得するクエリ。
 /// * `camera_query` - メインカメラとその座標変換情報を取得するクエリ。
 /// * `fox_transform_query` - キツネの座標変換を変更するクエリ。
+/// * `field_config` - マスごとのブロックの高さを調べるフィールドの設定。
 pub fn fox_follow_cursor(
     move_mode: Res<FoxMoveMode>,
+    move_style: Res<FoxMoveStyle>,
     window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
     camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     mut fox_transform_query: Query<&mut Transform, With<Fox>>,
+    field_config: Res<FieldConfig>,
 ) {
-    if !move_mode.is_active || !move_mode.is_holding {
+    if !move_mode.is_active || !move_mode.is_holding || *move_style == FoxMoveStyle::Walk {
         return;
     }
 
@@ -561,7 +1004,7 @@ pub fn fox_follow_cursor(
         return;
     };
 
-    let Ok((camera, camera_transform)) = camera_query.single() else {
+    let Some((camera, camera_transform)) = main_camera(camera_query.single()) else {
         return;
     };
 
@@ -569,18 +1012,167 @@ pub fn fox_follow_cursor(
         return;
     };
 
-    let plane_y = FOX_INITIAL_HEIGHT;
-    let t = (plane_y - ray.origin.y) / ray.direction.y;
+    let Some(intersection_point) = field_config.ray_block_top(ray) else {
+        return;
+    };
+
+    for (index, &(fox_entity, _)) in move_mode.foxes.iter().enumerate() {
+        let Ok(mut fox_transform) = fox_transform_query.get_mut(fox_entity) else {
+            continue;
+        };
+        let position = intersection_point + move_mode.formation_offset(index);
+        let fox_height = field_config.elevation_at(position) + FOX_INITIAL_HEIGHT;
+        fox_transform.translation.x = position.x;
+        fox_transform.translation.z = position.z;
+        fox_transform.translation.y = fox_height + FOX_HOVER_HEIGHT;
+    }
+}
 
-    if t > 0.0 {
-        let intersection_point = ray.origin + *ray.direction * t;
+/// キツネへの憑依を開始する
+///
+/// 解除時に戻せるよう、現在のカメラ位置を保存しておく。
+fn start_possession(
+    possession_mode: &mut PossessionMode,
+    fox_entity: Entity,
+    camera_transform: Option<&Transform>,
+) {
+    if let Some(camera_transform) = camera_transform {
+        possession_mode.previous_camera_transform = Some(*camera_transform);
+    }
 
-        if let Some(fox_entity) = move_mode.fox_entity
-            && let Ok(mut fox_transform) = fox_transform_query.get_mut(fox_entity)
-        {
-            fox_transform.translation.x = intersection_point.x;
-            fox_transform.translation.z = intersection_point.z;
-            fox_transform.translation.y = plane_y + FOX_HOVER_HEIGHT;
+    possession_mode.is_active = true;
+    possession_mode.fox_entity = Some(fox_entity);
+    possession_mode.camera_offset = Vec3::new(0.0, 2.0, -3.0);
+}
+
+/// キツネへの憑依を解除する
+///
+/// カメラを憑依前の位置に戻し、ダッシュ状態もリセットする。
+fn end_possession(
+    possession_mode: &mut PossessionMode,
+    camera_transform: Option<Mut<Transform>>,
+    dash_state: &mut DashInputState,
+) {
+    // カメラを元の位置に戻す
+    if let Some(previous_transform) = possession_mode.previous_camera_transform
+        && let Some(mut camera_transform) = camera_transform
+    {
+        *camera_transform = previous_transform;
+    }
+
+    // Possessionモードを解除
+    possession_mode.is_active = false;
+    possession_mode.fox_entity = None;
+    possession_mode.previous_camera_transform = None;
+
+    // ダッシュ状態もリセット
+    *dash_state = DashInputState::default();
+}
+
+/// Pキーかゲームパッドの決定ボタン（South）で憑依モードを切り替えるシステム
+///
+/// 憑依していない場合は、アクションメニューで選択中のキツネに憑依する。
+/// 選択中のキツネがいなければ、表示中のキツネが1匹だけの場合にそのキツネに憑依する。
+/// 憑依中の場合は解除する。開始・解除の処理はPossessionボタン・Escキーと共通。
+/// スペクテイターモード中は憑依を開始しない。
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_possession(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut commands: Commands,
+    mut possession_mode: ResMut<PossessionMode>,
+    fox_query: Query<(Entity, &Visibility, Has<FoxSelected>), With<Fox>>,
+    action_menu_query: Query<Entity, With<FoxActionMenu>>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    mut dash_state: ResMut<DashInputState>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    camera_mode: Res<CameraMode>,
+    mut fox_possessed: MessageWriter<FoxPossessed>,
+) {
+    let pressed = keyboard_input.just_pressed(KeyCode::KeyP)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+    if camera_mode.is_spectator() || !pressed {
+        return;
+    }
+
+    let visible_foxes = || {
+        fox_query
+            .iter()
+            .filter(|(_, visibility, _)| **visibility != Visibility::Hidden)
+    };
+    let target_fox = visible_foxes()
+        .find(|(_, _, selected)| *selected)
+        .or_else(|| {
+            let mut foxes = visible_foxes();
+            foxes.next().filter(|_| foxes.next().is_none())
+        })
+        .map(|(fox_entity, _, _)| fox_entity);
+
+    let message = if possession_mode.is_active {
+        end_possession(
+            &mut possession_mode,
+            main_camera(camera_query.single_mut()),
+            &mut dash_state,
+        );
+        TextKey::PossessionEnded
+    } else if let Some(fox_entity) = target_fox {
+        start_possession(
+            &mut possession_mode,
+            fox_entity,
+            main_camera(camera_query.single()),
+        );
+        fox_possessed.write(FoxPossessed);
+        for menu_entity in action_menu_query.iter() {
+            commands.entity(menu_entity).despawn();
+        }
+        TextKey::PossessionStarted
+    } else {
+        return;
+    };
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.t(message).to_string();
+    }
+}
+
+/// キツネの名前入力欄で確定された名前をキツネに設定するシステム
+///
+/// 空白だけの名前は無視する。タイマー表示の名前も合わせて変更し、メニューを閉じる。
+pub fn apply_fox_name_input(
+    mut submitted: MessageReader<TextInputSubmitted>,
+    mut commands: Commands,
+    name_input_query: Query<&FoxNameInput>,
+    mut timer_query: Query<&mut cf_tool::timer::Timer, With<Fox>>,
+    action_menu_query: Query<Entity, With<FoxActionMenu>>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    for event in submitted.read() {
+        let Ok(FoxNameInput(fox_entity)) = name_input_query.get(event.input) else {
+            continue;
+        };
+
+        let name = event.value.trim();
+        if name.is_empty() {
+            continue;
+        }
+
+        commands
+            .entity(*fox_entity)
+            .insert(FoxName(name.to_string()));
+        if let Ok(mut timer) = timer_query.get_mut(*fox_entity) {
+            timer.name = name.to_string();
+        }
+
+        for menu_entity in action_menu_query.iter() {
+            commands.entity(menu_entity).despawn();
+        }
+
+        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+            feedback_text.0 = locale.tf(TextKey::FoxRenamed, &[&name]);
         }
     }
 }
@@ -592,46 +1184,140 @@ pub fn exit_possession_mode(
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
     mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
     mut dash_state: ResMut<crate::resources::DashInputState>,
+    locale: Res<Locale>,
 ) {
     if !possession_mode.is_active {
         return;
     }
 
     if keyboard_input.just_pressed(KeyCode::Escape) {
-        // カメラを元の位置に戻す
-        if let Some(previous_transform) = possession_mode.previous_camera_transform {
-            if let Ok(mut camera_transform) = camera_query.single_mut() {
-                *camera_transform = previous_transform;
-            }
-        }
-
-        // Possessionモードを解除
-        possession_mode.is_active = false;
-        possession_mode.fox_entity = None;
-        possession_mode.previous_camera_transform = None;
-
-        // ダッシュ状態もリセット
-        dash_state.is_dashing = false;
-        dash_state.last_tap_time = None;
-        dash_state.last_key = None;
+        end_possession(
+            &mut possession_mode,
+            main_camera(camera_query.single_mut()),
+            &mut dash_state,
+        );
 
         // フィードバックメッセージを表示
         if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-            feedback_text.0 = "Possessionモードを解除しました".to_string();
+            feedback_text.0 = locale.t(TextKey::PossessionEnded).to_string();
         }
     }
 }
 
+/// 憑依を解除した直後に、憑依前の操作の状態を整えるシステム
+///
+/// Escでもゲームパッドでも解除できるため、解除の経路に関わらずここでまとめて処理する。
+/// - 開いたままのアクションメニューを閉じる
+/// - マウスのドラッグ状態を消す（ロック中に押したボタンでドラッグ扱いにならないよう）
+/// - ホバー中のブロックを現在のピッキング結果から取り直す
+///
+/// 憑依中はカーソルがロックされてポインターのOver/Outが届かないため、
+/// 解除前と同じブロックにカーソルがあるとハイライトが出なかった。
+/// （再現手順: ブロックにカーソルを乗せたまま憑依し、Escで解除する）
+#[allow(clippy::too_many_arguments)]
+pub fn cleanup_after_possession(
+    possession_mode: Res<crate::resources::PossessionMode>,
+    mut was_possessing: Local<bool>,
+    mut commands: Commands,
+    action_menu_query: Query<Entity, With<FoxActionMenu>>,
+    mut drag_state: ResMut<MouseDragState>,
+    mut hovered_block: ResMut<HoveredBlock>,
+    hover_map: Res<bevy::picking::hover::HoverMap>,
+    block_query: Query<(), With<Block>>,
+    parent_query: Query<&ChildOf>,
+) {
+    let exited = *was_possessing && !possession_mode.is_active;
+    *was_possessing = possession_mode.is_active;
+    if !exited {
+        return;
+    }
+
+    for menu_entity in action_menu_query.iter() {
+        commands.entity(menu_entity).despawn();
+    }
+
+    drag_state.is_dragging = false;
+    drag_state.last_position = None;
+
+    // ブロックのシーンではメッシュが子孫にあるため、祖先までたどってブロックを探す
+    hovered_block.entity = hover_map
+        .get(&bevy::picking::pointer::PointerId::Mouse)
+        .into_iter()
+        .flat_map(|hovered| hovered.keys())
+        .find_map(|&entity| {
+            std::iter::once(entity)
+                .chain(parent_query.iter_ancestors(entity))
+                .find(|&ancestor| block_query.contains(ancestor))
+        });
+}
+
+/// Possessionモード時にTキーで憑依中のキツネを初期位置に戻すシステム
+///
+/// 引っかかって動けなくなった時などのためのリセット。
+/// ダッシュの状態と歩いて向かっている移動先も解除する。
+#[allow(clippy::too_many_arguments)]
+pub fn reset_possessed_fox(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    possession_mode: Res<crate::resources::PossessionMode>,
+    mut fox_query: Query<&mut Transform, With<Fox>>,
+    mut dash_state: ResMut<crate::resources::DashInputState>,
+    mut commands: Commands,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    field_config: Res<FieldConfig>,
+) {
+    if !possession_mode.is_active || !keyboard_input.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+
+    let Some(fox_entity) = possession_mode.fox_entity else {
+        return;
+    };
+    let Ok(mut fox_transform) = fox_query.get_mut(fox_entity) else {
+        return;
+    };
+
+    fox_transform.translation = field_config.fox_spawn_position();
+    *dash_state = DashInputState::default();
+    commands
+        .entity(fox_entity)
+        .remove::<FoxPathTarget>()
+        .insert(FoxPhysics::default());
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.t(TextKey::FoxResetToSpawn).to_string();
+    }
+}
+
 /// Possessionモード時にWASDキーでキツネを移動させるシステム
+///
+/// ゲームパッドの左スティックでも移動でき、スティックを押し込むとダッシュする。
+/// 入力で速度を直接決めず、[`FoxPhysics`]の速度を入力方向へ加速・摩擦で減速させて動かす。
+/// ダッシュ中は最高速度と加速度が上がる。キツネは速度の向きを向く。
+/// 柵のある辺は越えられず、柵に沿って滑るように移動する（完全に阻まれたら止まる）。
+/// 水のブロックの上では[`WATER_SPEED_FACTOR`]倍の速さになり、土ぼこりの代わりに波紋が出る。
+#[allow(clippy::too_many_arguments)]
 pub fn fox_possession_movement(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    settings: Res<CameraSettings>,
+    (fenced_edges, field_config): (Res<FencedEdges>, Res<FieldConfig>),
+    water_tiles: Res<WaterTiles>,
     possession_mode: Res<crate::resources::PossessionMode>,
-    mut fox_query: Query<&mut Transform, With<Fox>>,
+    mut fox_query: Query<(&mut Transform, &mut FoxPhysics, Option<&Leash>), With<Fox>>,
     camera_query: Query<&Transform, (With<MainCamera>, Without<Fox>)>,
     time: Res<Time>,
     mut dash_state: ResMut<crate::resources::DashInputState>,
+    mut commands: Commands,
+    (dust_assets, water_assets): (Res<DustAssets>, Res<WaterAssets>),
+    mut dust_timer: Local<f32>,
+    mut ripple_timer: Local<f32>,
 ) {
     if !possession_mode.is_active {
+        // 次に憑依した時に前回の慣性が残らないようにする
+        for (_, mut physics, _) in fox_query.iter_mut() {
+            physics.set_if_neq(FoxPhysics::default());
+        }
         return;
     }
 
@@ -639,51 +1325,27 @@ pub fn fox_possession_movement(
         return;
     };
 
-    let Ok(mut fox_transform) = fox_query.get_mut(fox_entity) else {
+    let Ok((mut fox_transform, mut physics, leash)) = fox_query.get_mut(fox_entity) else {
         return;
     };
 
-    let Ok(camera_transform) = camera_query.single() else {
+    let Some(camera_transform) = main_camera(camera_query.single()) else {
         return;
     };
 
     let current_time = time.elapsed_secs();
 
     // ダブルタップ検出
-    let movement_keys = [KeyCode::KeyW, KeyCode::KeyS, KeyCode::KeyA, KeyCode::KeyD];
-
-    for key in movement_keys.iter() {
-        if keyboard_input.just_pressed(*key) {
-            if let Some(last_time) = dash_state.last_tap_time {
-                if let Some(last_key) = dash_state.last_key {
-                    // 同じキーが短時間に2回押された場合、ダッシュ開始
-                    if last_key == *key && (current_time - last_time) < dash_state.dash_timeout {
-                        dash_state.is_dashing = true;
-                    }
-                }
-            }
-            dash_state.last_tap_time = Some(current_time);
-            dash_state.last_key = Some(*key);
-        }
+    if DashInputState::tap_started(&keyboard_input) {
+        dash_state.register_tap(current_time, settings.dash_timeout);
     }
 
-    // いずれかのキーが離されたらダッシュ解除
-    if !keyboard_input.pressed(KeyCode::KeyW)
-        && !keyboard_input.pressed(KeyCode::KeyS)
-        && !keyboard_input.pressed(KeyCode::KeyA)
-        && !keyboard_input.pressed(KeyCode::KeyD)
-    {
+    // 移動キーがすべて離されたらダッシュ解除
+    if !keyboard_input.any_pressed(DashInputState::MOVEMENT_KEYS) {
         dash_state.is_dashing = false;
     }
 
     let mut movement = Vec3::ZERO;
-    let base_speed = 15.0;
-    let dash_speed = 50.0;
-    let movement_speed = if dash_state.is_dashing {
-        dash_speed
-    } else {
-        base_speed
-    };
 
     // カメラの向きを基準にした前方と右方向を計算（Y軸は無視）
     let (forward_xz, right_xz) = camera_relative_movement(camera_transform);
@@ -700,18 +1362,119 @@ pub fn fox_possession_movement(
     if keyboard_input.pressed(KeyCode::KeyD) {
         movement += right_xz;
     }
-
     if movement != Vec3::ZERO {
-        movement = movement.normalize() * movement_speed * time.delta_secs();
-        fox_transform.translation += movement;
-
-        // キツネを移動方向に向ける（モデルの前後が逆なので180度回転を追加）
-        let target_rotation = Transform::IDENTITY
-            .looking_to(movement, Vec3::Y)
-            .rotation
-            .normalize();
-        // Y軸周りに180度回転させる
-        let correction = Quat::from_rotation_y(std::f32::consts::PI);
-        fox_transform.rotation = target_rotation * correction;
+        movement = movement.normalize();
+    }
+
+    // ゲームパッドの左スティック（傾きに応じて速度が変わる。押し込んでいる間はダッシュ）
+    for gamepad in gamepads.iter() {
+        let stick = apply_stick_deadzone(gamepad.left_stick(), settings.gamepad_deadzone);
+        movement += forward_xz * stick.y + right_xz * stick.x;
+        if gamepad.pressed(GamepadButton::LeftThumb) {
+            dash_state.is_dashing = true;
+        }
     }
+
+    let movement_speed = if dash_state.is_dashing {
+        FOX_DASH_SPEED
+    } else {
+        FOX_WALK_SPEED
+    };
+
+    let acceleration = if dash_state.is_dashing {
+        FOX_DASH_ACCELERATION
+    } else {
+        FOX_WALK_ACCELERATION
+    };
+
+    // 足元のマスが水のブロックなら、その水面の高さ
+    let fox_tile = field_config.grid_coords(fox_transform.translation);
+    let water_surface = water_tiles
+        .tiles
+        .contains(&fox_tile)
+        .then(|| field_config.surface_height(fox_transform.translation, &[]));
+    let speed_factor = if water_surface.is_some() {
+        WATER_SPEED_FACTOR
+    } else {
+        1.0
+    };
+
+    physics.steer(
+        movement.clamp_length_max(1.0),
+        movement_speed * speed_factor,
+        acceleration,
+        time.delta_secs(),
+    );
+
+    if physics.velocity != Vec3::ZERO {
+        let step = physics.velocity * time.delta_secs();
+        let moved = move_fox(&mut fox_transform, step, &fenced_edges, &field_config);
+        if !moved {
+            physics.velocity = Vec3::ZERO;
+        }
+        if let Some(leash) = leash {
+            fox_transform.translation = leash.clamp(fox_transform.translation);
+        }
+
+        // 水の上では一定間隔で足元に波紋を出す
+        if let Some(surface) = water_surface
+            && moved
+        {
+            *ripple_timer -= time.delta_secs();
+            if *ripple_timer <= 0.0 {
+                *ripple_timer = RIPPLE_EMIT_INTERVAL;
+                spawn_ripple(
+                    &mut commands,
+                    &water_assets,
+                    fox_transform.translation.with_y(surface + RIPPLE_LIFT),
+                );
+            }
+        }
+
+        // ダッシュ中は一定間隔で足元に土ぼこりを出す（水の上では出さない）
+        if moved && dash_state.is_dashing && water_surface.is_none() {
+            *dust_timer -= time.delta_secs();
+            if *dust_timer <= 0.0 {
+                *dust_timer = DUST_EMIT_INTERVAL;
+                spawn_dust_puff(
+                    &mut commands,
+                    &dust_assets,
+                    fox_transform.translation,
+                    current_time,
+                );
+            }
+        }
+    }
+}
+
+/// キツネを1フレーム分移動させ、移動方向に向ける
+///
+/// 柵のある辺を越える場合は、軸ごとに分けて通れる方向だけ移動する。
+///
+/// # Returns
+/// * 柵に阻まれずに少しでも移動できたか
+pub fn move_fox(
+    fox_transform: &mut Transform,
+    movement: Vec3,
+    fenced_edges: &FencedEdges,
+    field_config: &FieldConfig,
+) -> bool {
+    let from = fox_transform.translation;
+    let moved = [movement, Vec3::X * movement.x, Vec3::Z * movement.z]
+        .into_iter()
+        .find(|step| !fenced_edges.blocks_move(field_config, from, from + *step));
+    if let Some(step) = moved {
+        fox_transform.translation += step;
+    }
+
+    // キツネを移動方向に向ける（モデルの前後が逆なので180度回転を追加）
+    let target_rotation = Transform::IDENTITY
+        .looking_to(movement, Vec3::Y)
+        .rotation
+        .normalize();
+    // Y軸周りに180度回転させる
+    let correction = Quat::from_rotation_y(std::f32::consts::PI);
+    fox_transform.rotation = target_rotation * correction;
+
+    moved.is_some_and(|step| step != Vec3::ZERO)
 }
@@ -0,0 +1,110 @@
+//! グラフィック設定の反映
+//!
+//! 設定メニューで変更した影の有無と解像度、手動で設定した向きを太陽光（[`SunLight`]）に、
+//! 環境光の明るさを[`GlobalAmbientLight`]に適用する。
+
+use bevy::light::DirectionalLightShadowMap;
+use bevy::prelude::*;
+
+use crate::components::SunLight;
+use crate::constants::*;
+use crate::resources::CameraSettings;
+
+/// 影の設定を太陽光とシャドウマップに反映するシステム
+///
+/// 設定が変わった時と、太陽光が生成された時に適用する。
+pub fn apply_shadow_settings(
+    settings: Res<CameraSettings>,
+    mut sun_query: Query<(&mut DirectionalLight, Ref<SunLight>)>,
+    mut shadow_map: ResMut<DirectionalLightShadowMap>,
+) {
+    let sun_added = sun_query.iter().any(|(_, sun)| sun.is_added());
+    if !settings.is_changed() && !sun_added {
+        return;
+    }
+
+    for (mut light, _) in sun_query.iter_mut() {
+        if light.shadows_enabled != settings.shadows_enabled {
+            light.shadows_enabled = settings.shadows_enabled;
+        }
+    }
+
+    let size = shadow_map_size_step(settings.shadow_map_size, 0) as usize;
+    if shadow_map.size != size {
+        shadow_map.size = size;
+    }
+}
+
+/// シャドウマップの解像度を[`SHADOW_MAP_SIZES`]の中で`step`段階ずらす
+///
+/// 一覧にない値（手で書き換えた設定ファイルなど）は既定の解像度から数える。
+pub fn shadow_map_size_step(size: u32, step: isize) -> u32 {
+    let index = SHADOW_MAP_SIZES
+        .iter()
+        .position(|&s| s == size)
+        .or_else(|| {
+            SHADOW_MAP_SIZES
+                .iter()
+                .position(|&s| s == SHADOW_MAP_DEFAULT_SIZE)
+        })
+        .unwrap_or(0);
+    let index = index
+        .saturating_add_signed(step)
+        .min(SHADOW_MAP_SIZES.len() - 1);
+    SHADOW_MAP_SIZES[index]
+}
+
+/// 太陽光の向き
+///
+/// 手動の向きが有効なら設定の方位と高さから、無効なら既定の向き（自動）にする。
+pub fn sun_rotation(settings: &CameraSettings) -> Quat {
+    if settings.manual_sun {
+        Quat::from_euler(
+            EulerRot::YXZ,
+            settings.sun_azimuth.to_radians(),
+            -settings.sun_elevation.to_radians(),
+            0.0,
+        )
+    } else {
+        Quat::from_euler(
+            EulerRot::XYZ,
+            -std::f32::consts::FRAC_PI_4,
+            std::f32::consts::FRAC_PI_4,
+            0.0,
+        )
+    }
+}
+
+/// 太陽の向きの設定を太陽光に反映するシステム
+///
+/// スクリーンショット用に向きを固定できるよう、手動の向きが有効な間は設定の方位と高さを使い、
+/// 無効にすると自動の向きに戻す。
+pub fn apply_sun_angle(
+    settings: Res<CameraSettings>,
+    mut sun_query: Query<&mut Transform, With<SunLight>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    let rotation = sun_rotation(&settings);
+    for mut transform in sun_query.iter_mut() {
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
+
+/// 環境光の明るさの設定を反映するシステム
+pub fn apply_ambient_light(
+    settings: Res<CameraSettings>,
+    mut ambient_light: ResMut<GlobalAmbientLight>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    if ambient_light.brightness != settings.ambient_brightness {
+        ambient_light.brightness = settings.ambient_brightness;
+    }
+}
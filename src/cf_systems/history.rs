@@ -0,0 +1,410 @@
+//! 元に戻す・やり直し
+//!
+//! 設置・撤去・採掘などの操作は[`ActionHistory`]に記録され、
+//! Ctrl+Zで元に戻し、Ctrl+Y（またはCtrl+Shift+Z）でやり直す。
+//! 各操作を取り消すと逆向きの操作が反対側の履歴に積まれる。
+
+use bevy::prelude::*;
+
+use crate::cf_systems::farming::{PlantAssets, spawn_plant};
+use crate::cf_systems::fence::{FenceAssets, spawn_fence};
+use crate::cf_systems::items::DroppedItemAssets;
+use crate::cf_systems::mining::break_rock;
+use crate::cf_systems::setup::spawn_rock;
+use crate::cf_systems::torch::{TorchAssets, spawn_torch};
+use crate::components::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+
+/// Ctrl+Z / Ctrl+Yで操作を元に戻す・やり直すシステム
+///
+/// 取り消しはアイテムスロットや複数種類のエンティティに触れるため、
+/// ワールドに直接アクセスする排他システムで処理する。
+/// 移動モード・憑依モード・スペクテイターモード中は処理しない。
+pub fn handle_undo_redo(world: &mut World) {
+    let keyboard_input = world.resource::<ButtonInput<KeyCode>>();
+    if !keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    let shift = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let undo = !shift && keyboard_input.just_pressed(KeyCode::KeyZ);
+    let redo = keyboard_input.just_pressed(KeyCode::KeyY)
+        || (shift && keyboard_input.just_pressed(KeyCode::KeyZ));
+    if !undo && !redo {
+        return;
+    }
+
+    if world.resource::<FoxMoveMode>().is_active
+        || world.resource::<PossessionMode>().is_active
+        || world.resource::<CameraMode>().is_spectator()
+    {
+        return;
+    }
+
+    let message = step_history(world, undo);
+
+    // スロットの中身が変わるため、選択中のアイテムは解除する
+    *world.resource_mut::<SelectedItemSlot>() = SelectedItemSlot::default();
+
+    let locale = *world.resource::<Locale>();
+    let mut feedback_query = world.query_filtered::<&mut Text, With<ClickFeedbackText>>();
+    if let Ok(mut feedback_text) = feedback_query.single_mut(world) {
+        feedback_text.0 = locale.t(message).to_string();
+    }
+}
+
+/// 履歴から操作を1つ取り出して取り消し、逆向きの操作を反対側の履歴に積む
+///
+/// 取り消せなくなった操作（対象が消えた・スロットが埋まったなど）は破棄する。
+fn step_history(world: &mut World, undo: bool) -> TextKey {
+    let action = {
+        let mut history = world.resource_mut::<ActionHistory>();
+        if undo {
+            history.undo_stack.pop()
+        } else {
+            history.redo_stack.pop()
+        }
+    };
+    let Some(action) = action else {
+        return if undo {
+            TextKey::NothingToUndo
+        } else {
+            TextKey::NothingToRedo
+        };
+    };
+
+    let Some(inverse) = revert_action(world, action) else {
+        return TextKey::CannotUndo;
+    };
+
+    let mut history = world.resource_mut::<ActionHistory>();
+    if undo {
+        history.redo_stack.push(inverse);
+        TextKey::Undone
+    } else {
+        history.undo_stack.push(inverse);
+        TextKey::Redone
+    }
+}
+
+/// 操作を取り消し、取り消しを表す逆向きの操作を返す
+///
+/// 取り消せるかを先に確認し、取り消せない場合は何も変更せずに`None`を返す。
+fn revert_action(world: &mut World, action: HistoryAction) -> Option<HistoryAction> {
+    match action {
+        HistoryAction::FoxMoved { fox, from, to } => {
+            let (from, to) = revert_fox_move(world, fox, from, to)?;
+            Some(HistoryAction::FoxMoved { fox, from, to })
+        }
+        HistoryAction::FoxesMoved(moves) => {
+            // まとめて移動するのはフィールド上のキツネだけなので、キツネが残っていれば取り消せる。
+            // 1匹でも消えていれば、どのキツネも動かさない
+            if moves
+                .iter()
+                .any(|(fox, _, _)| world.get::<Transform>(*fox).is_none())
+            {
+                return None;
+            }
+            let inverse = moves
+                .into_iter()
+                .filter_map(|(fox, from, to)| {
+                    let (from, to) = revert_fox_move(world, fox, from, to)?;
+                    Some((fox, from, to))
+                })
+                .collect();
+            Some(HistoryAction::FoxesMoved(inverse))
+        }
+        HistoryAction::ObjectPlaced {
+            object,
+            entity,
+            block,
+            position,
+            item,
+            slot_index,
+        } => {
+            world.get_entity(entity).ok()?;
+            let slot_index = find_empty_slot(world, slot_index)?;
+
+            // やり直した時に同じ成長度で植え直せるよう、現在の成長度を記録する
+            let object = match world.get::<Plant>(entity) {
+                Some(plant) => PlacedObject::Plant {
+                    growth: plant.growth,
+                },
+                None => object,
+            };
+            if let Some(fence) = world.get::<Fence>(entity) {
+                let (tile, side) = (fence.tile, fence.side);
+                world.resource_mut::<FencedEdges>().remove(tile, side);
+            }
+            world.despawn(entity);
+            set_slot_item(world, slot_index, Some(item.clone()));
+
+            Some(HistoryAction::ObjectRemoved {
+                object,
+                block,
+                position,
+                item,
+                slot_index,
+            })
+        }
+        HistoryAction::ObjectRemoved {
+            object,
+            block,
+            position,
+            item,
+            slot_index,
+        } => {
+            world.get_entity(block).ok()?;
+            if is_block_occupied(world, block, object, position) {
+                return None;
+            }
+            let slot_index = find_item_slot(world, &item, slot_index)?;
+
+            set_slot_item(world, slot_index, None);
+            let entity = spawn_placed_object(world, object, block, position);
+
+            Some(HistoryAction::ObjectPlaced {
+                object,
+                entity,
+                block,
+                position,
+                item,
+                slot_index,
+            })
+        }
+        HistoryAction::RockHit { rock, from, to } => {
+            let mut mineable = world.get_mut::<Mineable>(rock)?;
+            mineable.hits_remaining = from;
+            Some(HistoryAction::RockHit {
+                rock,
+                from: to,
+                to: from,
+            })
+        }
+        HistoryAction::RockBroken {
+            position,
+            rotation,
+            ores,
+            respawn_timer,
+        } => {
+            // 鉱石を拾った後や岩が再出現した後は戻せない
+            let ores_remain = ores
+                .iter()
+                .all(|&ore| world.get::<DroppedItem>(ore).is_some());
+            if !ores_remain || world.get::<RockRespawnTimer>(respawn_timer).is_none() {
+                return None;
+            }
+
+            for ore in ores {
+                world.despawn(ore);
+            }
+            world.despawn(respawn_timer);
+
+            let asset_server = world.resource::<AssetServer>().clone();
+            let rock = spawn_rock(&mut world.commands(), &asset_server, position, rotation);
+            world.flush();
+
+            // 壊す直前の状態（あと1回で壊れる）に戻す
+            if let Some(mut mineable) = world.get_mut::<Mineable>(rock) {
+                mineable.hits_remaining = 1;
+            }
+
+            Some(HistoryAction::RockRestored { rock })
+        }
+        HistoryAction::RockRestored { rock } => {
+            let transform = *world.get::<Transform>(rock)?;
+            let (ores, respawn_timer) =
+                world.resource_scope(|world, field_config: Mut<FieldConfig>| {
+                    world.resource_scope(|world, assets: Mut<DroppedItemAssets>| {
+                        break_rock(
+                            &mut world.commands(),
+                            &assets,
+                            &field_config,
+                            rock,
+                            transform.translation,
+                        )
+                    })
+                });
+            world.flush();
+
+            Some(HistoryAction::RockBroken {
+                position: transform.translation,
+                rotation: transform.rotation,
+                ores,
+                respawn_timer,
+            })
+        }
+    }
+}
+
+/// キツネの移動・設置・格納を取り消し、逆向きの操作の移動元と移動先を返す
+///
+/// フィールドにいるキツネは歩き回るため、逆向きの操作の移動元には
+/// 記録した位置ではなく取り消す直前の位置を使う。
+/// 歩いている途中なら移動先を消し、追従が移動先を設定し直さないようカメラへの追従もやめる。
+fn revert_fox_move(
+    world: &mut World,
+    fox: Entity,
+    from: FoxLocation,
+    to: FoxLocation,
+) -> Option<(FoxLocation, FoxLocation)> {
+    let current_position = world.get::<Transform>(fox)?.translation;
+
+    let current = match to {
+        FoxLocation::Slot(slot_index) => {
+            let in_slot =
+                slot_item(world, slot_index).is_some_and(|item| matches!(item, ItemType::Fox));
+            if !in_slot {
+                return None;
+            }
+            to
+        }
+        FoxLocation::Field(_) => FoxLocation::Field(current_position),
+    };
+
+    let restored = match from {
+        FoxLocation::Slot(slot_index) => FoxLocation::Slot(find_empty_slot(world, slot_index)?),
+        FoxLocation::Field(_) => from,
+    };
+
+    if let FoxLocation::Slot(slot_index) = current {
+        set_slot_item(world, slot_index, None);
+    }
+    match restored {
+        FoxLocation::Slot(slot_index) => {
+            set_slot_item(world, slot_index, Some(ItemType::Fox));
+            world.entity_mut(fox).insert(Visibility::Hidden);
+        }
+        FoxLocation::Field(position) => {
+            if let Some(mut transform) = world.get_mut::<Transform>(fox) {
+                transform.translation = position;
+            }
+            world.entity_mut(fox).insert(Visibility::Visible);
+        }
+    }
+    world
+        .entity_mut(fox)
+        .remove::<(FollowTarget, FoxPathTarget)>();
+
+    Some((current, restored))
+}
+
+/// 設置物をブロックの上に生成し直す
+///
+/// `position`はXZだけを使い、高さはブロックの中心に合わせる
+/// （取り除いた時に記録した位置は設置物自体の高さのため）。
+fn spawn_placed_object(
+    world: &mut World,
+    object: PlacedObject,
+    block: Entity,
+    position: Vec3,
+) -> Entity {
+    let position = world
+        .get::<Transform>(block)
+        .map_or(position, |block_transform| {
+            position.with_y(block_transform.translation.y)
+        });
+    let entity = match object {
+        PlacedObject::Plant { growth } => {
+            world.resource_scope(|world, assets: Mut<PlantAssets>| {
+                spawn_plant(&mut world.commands(), &assets, block, position, growth)
+            })
+        }
+        PlacedObject::Fence { side } => {
+            let tile = world.resource::<FieldConfig>().grid_coords(position);
+            world.resource_scope(|world, assets: Mut<FenceAssets>| {
+                world.resource_scope(|world, mut fenced_edges: Mut<FencedEdges>| {
+                    spawn_fence(
+                        &mut world.commands(),
+                        &assets,
+                        &mut fenced_edges,
+                        block,
+                        position,
+                        tile,
+                        side,
+                    )
+                })
+            })
+        }
+        PlacedObject::Torch => world.resource_scope(|world, assets: Mut<TorchAssets>| {
+            spawn_torch(&mut world.commands(), &assets, block, position)
+        }),
+    };
+    world.flush();
+    entity
+}
+
+/// 設置物を置く場所が埋まっているか
+///
+/// 柵は同じ辺に柵があるかだけを、植物と松明はブロックの上に植物か松明があるかを見る。
+fn is_block_occupied(
+    world: &mut World,
+    block: Entity,
+    object: PlacedObject,
+    position: Vec3,
+) -> bool {
+    if let PlacedObject::Fence { side } = object {
+        let tile = world.resource::<FieldConfig>().grid_coords(position);
+        return world.resource::<FencedEdges>().contains(tile, side);
+    }
+    let mut plant_query = world.query::<&Plant>();
+    let mut torch_query = world.query::<&Torch>();
+    plant_query.iter(world).any(|plant| plant.block == block)
+        || torch_query.iter(world).any(|torch| torch.block == block)
+}
+
+/// 指定したスロットのアイテムを取得する
+fn slot_item(world: &mut World, slot_index: usize) -> Option<ItemType> {
+    let mut slot_query = world.query::<&ItemSlot>();
+    slot_query
+        .iter(world)
+        .find(|slot| slot.slot_index == slot_index)
+        .and_then(|slot| slot.item.clone())
+}
+
+/// 空いているスロットを探す（`preferred`が空いていればそれを優先する）
+fn find_empty_slot(world: &mut World, preferred: usize) -> Option<usize> {
+    let mut slot_query = world.query::<&ItemSlot>();
+    let mut empty: Vec<usize> = slot_query
+        .iter(world)
+        .filter(|slot| slot.item.is_none())
+        .map(|slot| slot.slot_index)
+        .collect();
+    empty.sort();
+
+    empty
+        .contains(&preferred)
+        .then_some(preferred)
+        .or_else(|| empty.first().copied())
+}
+
+/// `item`と同じ種類のアイテムが入っているスロットを探す（`preferred`を優先する）
+fn find_item_slot(world: &mut World, item: &ItemType, preferred: usize) -> Option<usize> {
+    let mut slot_query = world.query::<&ItemSlot>();
+    let mut matching: Vec<usize> = slot_query
+        .iter(world)
+        .filter(|slot| {
+            slot.item
+                .as_ref()
+                .is_some_and(|i| std::mem::discriminant(i) == std::mem::discriminant(item))
+        })
+        .map(|slot| slot.slot_index)
+        .collect();
+    matching.sort();
+
+    matching
+        .contains(&preferred)
+        .then_some(preferred)
+        .or_else(|| matching.first().copied())
+}
+
+/// 指定したスロットの中身を書き換える
+fn set_slot_item(world: &mut World, slot_index: usize, item: Option<ItemType>) {
+    let mut slot_query = world.query::<&mut ItemSlot>();
+    if let Some(mut slot) = slot_query
+        .iter_mut(world)
+        .find(|slot| slot.slot_index == slot_index)
+    {
+        slot.item = item;
+    }
+}
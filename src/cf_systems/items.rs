@@ -0,0 +1,344 @@
+use bevy::prelude::*;
+
+use crate::cf_systems::audio::{SoundEffects, play_sound};
+use crate::cf_systems::camera::main_camera;
+use crate::components::*;
+use crate::constants::*;
+use crate::events::InventoryFull;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+use crate::traits::camera_relative_movement;
+
+/// ドロップアイテムの描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct DroppedItemAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for DroppedItemAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(Cuboid::new(
+            DROPPED_ITEM_SIZE,
+            DROPPED_ITEM_SIZE,
+            DROPPED_ITEM_SIZE,
+        ));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgb(
+                    DROPPED_ITEM_COLOR.0,
+                    DROPPED_ITEM_COLOR.1,
+                    DROPPED_ITEM_COLOR.2,
+                ),
+                ..default()
+            });
+        Self { mesh, material }
+    }
+}
+
+/// アイテムをワールドにドロップする
+///
+/// `position` のXZに、そのマスのブロック上面の高さで回転するアイテムを生成する。
+pub fn spawn_dropped_item(
+    commands: &mut Commands,
+    assets: &DroppedItemAssets,
+    field_config: &FieldConfig,
+    item: ItemType,
+    position: Vec3,
+) -> Entity {
+    let ground = BLOCK_HALF_SIZE + field_config.elevation_at(position);
+    commands
+        .spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_xyz(position.x, ground + DROPPED_ITEM_SIZE, position.z),
+            Pickable::IGNORE,
+            DroppedItem {
+                item,
+                pickup_armed: false,
+            },
+        ))
+        .id()
+}
+
+/// 空いている最初のアイテムスロットにアイテムを格納する
+///
+/// # Returns
+/// * `Some(usize)` - 格納したスロットのインデックス
+/// * `None` - 空きスロットがない場合
+pub fn store_item_in_empty_slot(
+    item_slot_query: &mut Query<&mut ItemSlot>,
+    item: ItemType,
+) -> Option<usize> {
+    let mut slots: Vec<_> = item_slot_query.iter_mut().collect();
+    slots.sort_by_key(|slot| slot.slot_index);
+
+    let mut slot = slots.into_iter().find(|slot| slot.item.is_none())?;
+    slot.item = Some(item);
+    Some(slot.slot_index)
+}
+
+/// ドロップアイテムを空きスロットに格納してワールドから削除する
+///
+/// 鉱石の場合は所持資源の鉱石数も増やす。空きスロットがなければ何もせず`false`を返す。
+fn collect_dropped_item(
+    commands: &mut Commands,
+    entity: Entity,
+    dropped_item: &DroppedItem,
+    item_slot_query: &mut Query<&mut ItemSlot>,
+    resources: &mut Resources,
+) -> bool {
+    if store_item_in_empty_slot(item_slot_query, dropped_item.item.clone()).is_none() {
+        return false;
+    }
+    commands.entity(entity).despawn();
+
+    if matches!(dropped_item.item, ItemType::Ore) {
+        resources.ore += 1;
+    }
+    true
+}
+
+/// カメラの注視点（視線とブロック上面の交点）を計算する
+pub fn camera_focus_point(camera_transform: &Transform) -> Option<Vec3> {
+    let forward = camera_transform.forward();
+    if forward.y >= 0.0 {
+        return None;
+    }
+    let t = (FOX_INITIAL_HEIGHT - camera_transform.translation.y) / forward.y;
+    Some(camera_transform.translation + *forward * t)
+}
+
+/// 選択中のアイテムをQキーでワールドにドロップするシステム
+///
+/// Possessionモード中はキツネの前方、それ以外はカメラの注視点に落とす。
+#[allow(clippy::too_many_arguments)]
+pub fn drop_selected_item(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    possession_mode: Res<PossessionMode>,
+    fox_query: Query<&GlobalTransform, With<Fox>>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    dropped_item_assets: Res<DroppedItemAssets>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    camera_mode: Res<CameraMode>,
+    field_config: Res<FieldConfig>,
+) {
+    // スペクテイターモード中のQキーは下降に使う
+    if camera_mode.is_spectator() || !keyboard_input.just_pressed(KeyCode::KeyQ) {
+        return;
+    }
+
+    let (Some(slot_idx), Some(item_type)) =
+        (selected_slot.slot_index, selected_slot.item_type.clone())
+    else {
+        return;
+    };
+
+    let Some(camera_transform) = main_camera(camera_query.single()) else {
+        return;
+    };
+
+    let drop_position = if let Some(fox_entity) = possession_mode.fox_entity
+        && let Ok(fox_transform) = fox_query.get(fox_entity)
+    {
+        let (forward_xz, _) = camera_relative_movement(camera_transform);
+        Some(fox_transform.translation() + forward_xz * DROPPED_ITEM_DROP_DISTANCE)
+    } else {
+        camera_focus_point(camera_transform)
+    };
+
+    let Some(drop_position) = drop_position else {
+        return;
+    };
+
+    for mut slot in item_slot_query.iter_mut() {
+        if slot.slot_index == slot_idx {
+            slot.item = None;
+            break;
+        }
+    }
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.tf(TextKey::ItemDropped, &[&locale.item_name(&item_type)]);
+    }
+
+    spawn_dropped_item(
+        &mut commands,
+        &dropped_item_assets,
+        &field_config,
+        item_type,
+        drop_position,
+    );
+
+    selected_slot.slot_index = None;
+    selected_slot.item_type = None;
+}
+
+/// ドロップアイテムを回転させるシステム
+pub fn spin_dropped_items(
+    mut dropped_query: Query<&mut Transform, With<DroppedItem>>,
+    time: Res<Time>,
+) {
+    for mut transform in dropped_query.iter_mut() {
+        transform.rotate_y(DROPPED_ITEM_SPIN_SPEED * time.delta_secs());
+    }
+}
+
+/// キツネ（またはカメラの注視点）に近づいたドロップアイテムを拾うシステム
+///
+/// Possessionモード中は操作中のキツネ、それ以外はカメラの注視点を基準に
+/// 半径内のアイテムを空きスロットへ格納し、ワールドから削除する。
+/// 落とした直後のアイテムは、基準点が一度半径の外に出るまで拾わない。
+/// 鉱石を拾った場合は所持資源の鉱石数も増やす。
+#[allow(clippy::too_many_arguments)]
+pub fn pickup_dropped_items(
+    mut commands: Commands,
+    mut dropped_query: Query<(Entity, &GlobalTransform, &mut DroppedItem)>,
+    possession_mode: Res<PossessionMode>,
+    fox_query: Query<&GlobalTransform, With<Fox>>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    mut resources: ResMut<Resources>,
+) {
+    let collector_position = if let Some(fox_entity) = possession_mode.fox_entity {
+        fox_query.get(fox_entity).ok().map(|t| t.translation())
+    } else {
+        main_camera(camera_query.single()).and_then(camera_focus_point)
+    };
+
+    let Some(collector_position) = collector_position else {
+        return;
+    };
+
+    for (entity, transform, mut dropped_item) in dropped_query.iter_mut() {
+        let offset = transform.translation() - collector_position;
+        let distance_xz = Vec2::new(offset.x, offset.z).length();
+        if distance_xz > DROPPED_ITEM_PICKUP_RADIUS {
+            dropped_item.pickup_armed = true;
+            continue;
+        }
+
+        if !dropped_item.pickup_armed {
+            continue;
+        }
+
+        if collect_dropped_item(
+            &mut commands,
+            entity,
+            &dropped_item,
+            &mut item_slot_query,
+            &mut resources,
+        ) && let Ok(mut feedback_text) = feedback_text_query.single_mut()
+        {
+            feedback_text.0 = locale.tf(
+                TextKey::ItemPickedUp,
+                &[&locale.item_name(&dropped_item.item)],
+            );
+        }
+    }
+}
+
+/// [`InventoryFull`]を受け取り、スロットがいっぱいであることを赤いメッセージとエラー音で知らせるシステム
+///
+/// 成功時のメッセージと見分けられるよう赤で表示し、
+/// 他のシステムがフィードバックメッセージを書き換えたら元の色に戻す。
+pub fn show_inventory_full(
+    mut inventory_full: MessageReader<InventoryFull>,
+    mut feedback_query: Query<(&mut Text, &mut TextColor), With<ClickFeedbackText>>,
+    mut commands: Commands,
+    sounds: Res<SoundEffects>,
+    locale: Res<Locale>,
+) {
+    let full = inventory_full.read().count() > 0;
+    let Ok((mut feedback_text, mut text_color)) = feedback_query.single_mut() else {
+        return;
+    };
+
+    if full {
+        feedback_text.0 = locale.t(TextKey::SlotsFull).to_string();
+        text_color.0 = Color::srgb(
+            FEEDBACK_ERROR_COLOR.0,
+            FEEDBACK_ERROR_COLOR.1,
+            FEEDBACK_ERROR_COLOR.2,
+        );
+        play_sound(&mut commands, &sounds.error, ERROR_SOUND_VOLUME);
+    } else if feedback_text.is_changed() && text_color.0 != Color::WHITE {
+        text_color.0 = Color::WHITE;
+    }
+}
+
+/// フィールドにいるキツネの近くのドロップアイテムを引き寄せて拾うシステム
+///
+/// [`AUTO_PICKUP_RADIUS`]内のアイテムをキツネに向かって動かし、
+/// [`AUTO_PICKUP_CONTACT_DISTANCE`]まで近づいたら空きスロットに格納して音を鳴らす。
+/// 空きスロットがない場合はアイテムを引き寄せず、ワールドに残したままにする。
+/// 落とした直後のアイテム（`pickup_armed`でないもの）は対象にしない。
+/// 鉱石を拾った場合は所持資源の鉱石数も増やす。
+#[allow(clippy::too_many_arguments)]
+pub fn auto_pickup(
+    mut commands: Commands,
+    mut dropped_query: Query<(Entity, &mut Transform, &DroppedItem), Without<Fox>>,
+    fox_query: Query<(&GlobalTransform, &Visibility), With<Fox>>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    mut resources: ResMut<Resources>,
+    sounds: Res<SoundEffects>,
+    time: Res<Time>,
+) {
+    if item_slot_query.iter().all(|slot| slot.item.is_some()) {
+        return;
+    }
+
+    let fox_positions: Vec<Vec3> = fox_query
+        .iter()
+        .filter(|(_, visibility)| **visibility != Visibility::Hidden)
+        .map(|(transform, _)| transform.translation())
+        .collect();
+
+    for (entity, mut transform, dropped_item) in dropped_query.iter_mut() {
+        if !dropped_item.pickup_armed {
+            continue;
+        }
+
+        let nearest = fox_positions
+            .iter()
+            .map(|&fox| (fox - transform.translation).with_y(0.0))
+            .filter(|offset| offset.length() <= AUTO_PICKUP_RADIUS)
+            .min_by(|a, b| a.length().total_cmp(&b.length()));
+        let Some(to_fox) = nearest else {
+            continue;
+        };
+
+        if to_fox.length() > AUTO_PICKUP_CONTACT_DISTANCE {
+            let step = (AUTO_PICKUP_PULL_SPEED * time.delta_secs()).min(to_fox.length());
+            transform.translation += to_fox.normalize() * step;
+            continue;
+        }
+
+        if !collect_dropped_item(
+            &mut commands,
+            entity,
+            dropped_item,
+            &mut item_slot_query,
+            &mut resources,
+        ) {
+            break;
+        }
+
+        play_sound(&mut commands, &sounds.pickup, PICKUP_SOUND_VOLUME);
+        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+            feedback_text.0 = locale.tf(
+                TextKey::FoxPickedUpItem,
+                &[&locale.item_name(&dropped_item.item)],
+            );
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! キツネの行動範囲
+//!
+//! キツネには[`Leash`]を付け、フィールドの外へ出ていかないようにする。
+//! 範囲は付けた時のフィールドの大きさに合わせて決め、その後は広げられた範囲を保つ。
+//! デバッグコンソールでフィールドを作り直した時だけ、フィールドに合わせて付け直す。
+//! デバッグコンソールを開いている間は範囲の境界を円で表示する。
+
+use bevy::prelude::*;
+
+use crate::components::{Fox, Leash};
+use crate::constants::*;
+use crate::resources::{DebugConsoleState, FieldConfig};
+
+/// まだ[`Leash`]が付いていないキツネに、フィールドに合わせた範囲を付けるシステム
+///
+/// 付けた後の範囲は書き換えない。他のシステムが広げた範囲をそのまま残すため。
+pub fn update_fox_leash(
+    mut commands: Commands,
+    fox_query: Query<Entity, (With<Fox>, Without<Leash>)>,
+    field_config: Res<FieldConfig>,
+) {
+    for fox_entity in fox_query.iter() {
+        commands
+            .entity(fox_entity)
+            .insert(Leash::for_field(&field_config));
+    }
+}
+
+/// デバッグコンソールを開いている間、キツネの行動範囲の境界を描画するシステム
+pub fn draw_leash_gizmos(
+    mut gizmos: Gizmos,
+    console_state: Res<DebugConsoleState>,
+    leash_query: Query<&Leash>,
+) {
+    if !console_state.is_open {
+        return;
+    }
+
+    let color = Color::srgb(
+        LEASH_GIZMO_COLOR.0,
+        LEASH_GIZMO_COLOR.1,
+        LEASH_GIZMO_COLOR.2,
+    );
+    for leash in leash_query.iter() {
+        // 円はXY平面上に描かれるので、X軸周りに倒して地面に沿わせる
+        let isometry = Isometry3d::new(
+            leash.center.with_y(BLOCK_HALF_SIZE + LEASH_GIZMO_LIFT),
+            Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+        );
+        gizmos.circle(isometry, leash.radius, color);
+    }
+}
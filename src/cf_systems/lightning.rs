@@ -0,0 +1,69 @@
+//! 嵐の稲光と雷鳴
+//!
+//! 雨が強い間はランダムな間隔で稲光が起き、太陽光を一瞬明るくする。
+//! 雷鳴は稲光と同じフレームではなく、距離を表すランダムな遅れの後に
+//! [`ThunderQueue`]から鳴らし、音量は稲光の明るさに比例させる。
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::cf_systems::audio::{SoundEffects, play_sound};
+use crate::components::SunLight;
+use crate::constants::*;
+use crate::resources::{GameRng, Lightning, PendingThunder, ThunderQueue, WeatherState};
+
+/// 稲光を起こし、光っている間は太陽光を明るくするシステム
+///
+/// 雨の強さが[`LIGHTNING_MIN_RAIN_INTENSITY`]以上の間だけ次の稲光までの時間を減らす。
+/// 光った時に明るさと雷鳴の遅れを抽選し、雷鳴を[`ThunderQueue`]に積む。
+/// 稲光が消えたフレームで、太陽光を天候の明るさに戻す。
+pub fn update_lightning(
+    mut lightning: ResMut<Lightning>,
+    mut thunder_queue: ResMut<ThunderQueue>,
+    weather: Res<WeatherState>,
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    mut sun_query: Query<&mut DirectionalLight, With<SunLight>>,
+) {
+    let was_flashing = lightning.flash > 0.0;
+    lightning.flash = (lightning.flash - time.delta_secs() / LIGHTNING_FLASH_FADE_SECS).max(0.0);
+
+    if weather.intensity >= LIGHTNING_MIN_RAIN_INTENSITY {
+        lightning.time_until_flash -= time.delta_secs();
+        if lightning.time_until_flash <= 0.0 {
+            let brightness = rng.0.random_range(LIGHTNING_BRIGHTNESS_MIN..=1.0);
+            lightning.flash = brightness;
+            lightning.time_until_flash = rng
+                .0
+                .random_range(LIGHTNING_INTERVAL_MIN..LIGHTNING_INTERVAL_MAX);
+            thunder_queue.claps.push(PendingThunder {
+                remaining: rng.0.random_range(THUNDER_DELAY_MIN..THUNDER_DELAY_MAX),
+                volume: THUNDER_SOUND_VOLUME * brightness,
+            });
+        }
+    }
+
+    if (was_flashing || lightning.flash > 0.0)
+        && let Ok(mut sun_light) = sun_query.single_mut()
+    {
+        sun_light.illuminance =
+            weather.sun_illuminance() + lightning.flash * LIGHTNING_FLASH_ILLUMINANCE;
+    }
+}
+
+/// 遅れの経過した雷鳴を鳴らすシステム
+pub fn play_thunder(
+    mut commands: Commands,
+    mut thunder_queue: ResMut<ThunderQueue>,
+    sounds: Res<SoundEffects>,
+    time: Res<Time>,
+) {
+    thunder_queue.claps.retain_mut(|clap| {
+        clap.remaining -= time.delta_secs();
+        if clap.remaining > 0.0 {
+            return true;
+        }
+        play_sound(&mut commands, &sounds.thunder, clap.volume);
+        false
+    });
+}
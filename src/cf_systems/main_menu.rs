@@ -0,0 +1,140 @@
+//! メインメニュー
+//!
+//! 起動時は[`GameState::MainMenu`]から始まり、スタートボタンで[`GameState::InGame`]に移る。
+//! ゲーム中は設定メニューの「メインメニューへ」ボタンで戻れる。
+//! メニューを表示している間はワールドの時間を止め、ゲーム中のシステムも動かさない。
+
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+
+/// メインメニューを表示するシステム
+///
+/// ゲーム本編のカメラがまだなければ、メニュー表示用のカメラも生成する。
+/// 本編のワールドが既にある場合（ゲーム中から戻った場合）は、スタートの代わりに「つづける」と表示する。
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    main_camera_query: Query<(), With<MainCamera>>,
+    locale: Res<Locale>,
+) {
+    let in_progress = !main_camera_query.is_empty();
+    if !in_progress {
+        commands.spawn((Camera2d, MainMenuCamera));
+    }
+
+    let start_label = if in_progress {
+        TextKey::MainMenuContinue
+    } else {
+        TextKey::MainMenuStart
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.12, 0.1)),
+            GlobalZIndex(i32::MAX),
+            MainMenu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(locale.t(TextKey::MainMenuTitle)),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            spawn_button!(parent, {
+                size: (200.0, 50.0),
+                text: locale.t(start_label),
+                font_size: 22.0,
+                bg_color: (0.2, 0.6, 0.2),
+                border_color: (0.3, 0.7, 0.3),
+                component: MainMenuButton::Start,
+            });
+
+            spawn_button!(parent, {
+                size: (200.0, 50.0),
+                text: locale.t(TextKey::MainMenuQuit),
+                font_size: 22.0,
+                bg_color: (0.5, 0.2, 0.2),
+                border_color: (0.6, 0.3, 0.3),
+                component: MainMenuButton::Quit,
+            });
+        });
+}
+
+/// メインメニューとメニュー表示用のカメラを削除するシステム
+pub fn despawn_main_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<MainMenu>>,
+    camera_query: Query<Entity, With<MainMenuCamera>>,
+) {
+    for entity in menu_query.iter().chain(camera_query.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// メインメニューのボタンのクリックを処理するシステム
+pub fn handle_main_menu_buttons(
+    interaction_query: Query<(&Interaction, &MainMenuButton), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button {
+            MainMenuButton::Start => next_state.set(GameState::InGame),
+            MainMenuButton::Quit => {
+                app_exit.write(AppExit::Success);
+            }
+        }
+    }
+}
+
+/// 設定メニューの「メインメニューへ」ボタンで、設定メニューを閉じてメインメニューに戻るシステム
+pub fn handle_return_to_menu_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ReturnToMenuButton>)>,
+    mut commands: Commands,
+    mut settings_state: ResMut<SettingsMenuState>,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !interaction_query
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+
+    for entity in settings_menu_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    settings_state.is_open = false;
+    next_state.set(GameState::MainMenu);
+}
+
+/// メインメニューを開いている間、ワールドの時間を止めるシステム
+pub fn pause_world(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+/// ゲームに戻った時に、止めていたワールドの時間を再開するシステム
+pub fn resume_world(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
@@ -0,0 +1,150 @@
+//! ミニマップ
+//!
+//! 画面右下にフィールド全体を上から見た図を出し、キツネ・岩・ドロップアイテムを点で表示する。
+//! 点のノードは毎フレーム作り直さず、前のフレームのものを位置と色だけ変えて使い回す。
+//! ミニマップをクリックすると、自由カメラの注視点をクリックした場所へ移す。
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use crate::cf_systems::camera::main_camera;
+use crate::cf_systems::items::camera_focus_point;
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::{FieldConfig, PossessionMode};
+
+/// ミニマップに打つ1つの点
+struct MinimapPoint {
+    position: Vec3,
+    size: f32,
+    color: (f32, f32, f32),
+}
+
+/// ワールド座標をミニマップ上の位置（左上からのピクセル）に変換する
+///
+/// ワールドのXを横、Zを縦に取り、フィールド全体がミニマップに収まるようにする。
+fn minimap_position(field_rect: Rect, position: Vec3) -> Vec2 {
+    let normalized = (Vec2::new(position.x, position.z) - field_rect.min) / field_rect.size();
+    normalized.clamp(Vec2::ZERO, Vec2::ONE) * MINIMAP_SIZE
+}
+
+/// キツネ・岩・ドロップアイテムの位置をミニマップの点に反映するシステム
+///
+/// 憑依中のキツネは別の色で表示し、格納中（非表示）のキツネは表示しない。
+/// 点が足りなければ子ノードを追加し、余った点は非表示にして次のフレームで使い回す。
+#[allow(clippy::too_many_arguments)]
+pub fn update_minimap(
+    mut commands: Commands,
+    minimap_query: Query<Entity, With<Minimap>>,
+    mut marker_query: Query<(&mut Node, &mut BackgroundColor), With<MinimapMarker>>,
+    fox_query: Query<(Entity, &GlobalTransform, &Visibility), With<Fox>>,
+    rock_query: Query<&GlobalTransform, With<Rock>>,
+    item_query: Query<&GlobalTransform, With<DroppedItem>>,
+    possession_mode: Res<PossessionMode>,
+    field_config: Res<FieldConfig>,
+) {
+    let Ok(minimap) = minimap_query.single() else {
+        return;
+    };
+
+    let rocks = rock_query.iter().map(|transform| MinimapPoint {
+        position: transform.translation(),
+        size: MINIMAP_ROCK_MARKER_SIZE,
+        color: MINIMAP_ROCK_COLOR,
+    });
+    let items = item_query.iter().map(|transform| MinimapPoint {
+        position: transform.translation(),
+        size: MINIMAP_ITEM_MARKER_SIZE,
+        color: MINIMAP_ITEM_COLOR,
+    });
+    // キツネが他の点に隠れないよう最後に追加する（後の子ノードほど手前に描かれる）
+    let foxes = fox_query
+        .iter()
+        .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+        .map(|(fox_entity, transform, _)| {
+            let possessed =
+                possession_mode.is_active && possession_mode.fox_entity == Some(fox_entity);
+            MinimapPoint {
+                position: transform.translation(),
+                size: MINIMAP_FOX_MARKER_SIZE,
+                color: if possessed {
+                    MINIMAP_POSSESSED_FOX_COLOR
+                } else {
+                    MINIMAP_FOX_COLOR
+                },
+            }
+        });
+
+    let field_rect = field_config.field_rect();
+    let mut markers = marker_query.iter_mut();
+    for point in rocks.chain(items).chain(foxes) {
+        let center = minimap_position(field_rect, point.position);
+        let node = Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(center.x - point.size / 2.0),
+            top: Val::Px(center.y - point.size / 2.0),
+            width: Val::Px(point.size),
+            height: Val::Px(point.size),
+            border_radius: BorderRadius::MAX,
+            ..default()
+        };
+        let color = BackgroundColor(Color::srgb(point.color.0, point.color.1, point.color.2));
+
+        match markers.next() {
+            Some((mut marker_node, mut marker_color)) => {
+                marker_node.set_if_neq(node);
+                marker_color.set_if_neq(color);
+            }
+            None => {
+                commands
+                    .entity(minimap)
+                    .with_child((node, color, Pickable::IGNORE, MinimapMarker));
+            }
+        }
+    }
+
+    // 使わなかった点は消さずに隠しておく
+    for (mut marker_node, _) in markers {
+        if marker_node.display != Display::None {
+            marker_node.display = Display::None;
+        }
+    }
+}
+
+/// ミニマップをクリックした場所へ自由カメラの注視点を移すシステム
+///
+/// カメラの向きと高さは変えずに水平方向だけ動かす。
+/// 憑依中はカメラがキツネを追うため何もしない。
+#[allow(clippy::type_complexity)]
+pub fn handle_minimap_click(
+    interaction_query: Query<
+        (&Interaction, &RelativeCursorPosition),
+        (Changed<Interaction>, With<Minimap>),
+    >,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    possession_mode: Res<PossessionMode>,
+    field_config: Res<FieldConfig>,
+) {
+    if possession_mode.is_active {
+        return;
+    }
+
+    for (interaction, cursor) in interaction_query.iter() {
+        // `normalized`はノードの中心が原点で、-0.5〜0.5の範囲になる
+        let Some(normalized) = cursor
+            .normalized
+            .filter(|_| *interaction == Interaction::Pressed)
+        else {
+            continue;
+        };
+        let Some(mut transform) = main_camera(camera_query.single_mut()) else {
+            return;
+        };
+
+        let field_rect = field_config.field_rect();
+        let target = field_rect.center() + normalized * field_rect.size();
+        let focus = camera_focus_point(&transform).unwrap_or(transform.translation);
+        transform.translation.x += target.x - focus.x;
+        transform.translation.z += target.y - focus.z;
+    }
+}
@@ -0,0 +1,335 @@
+use bevy::prelude::*;
+
+use crate::cf_mesh::field::create_cube_mesh;
+use crate::cf_systems::items::{DroppedItemAssets, spawn_dropped_item};
+use crate::cf_systems::setup::{
+    insert_grass_block_visual, random_rock_position, random_rock_rotation, spawn_rock,
+};
+use crate::cf_tool;
+use crate::components::*;
+use crate::constants::*;
+use crate::events::{MineableBlockClicked, RockClicked, RockMined};
+use crate::locale::{Locale, TextKey};
+use crate::resources::*;
+
+/// 選択中のツルハシで1回叩き、耐久度を1減らす
+///
+/// 耐久度が0になったツルハシは壊れ、スロットを空にして選択も解除する。
+///
+/// # Returns
+/// * `Some(u32)` - 叩いた後の残りの耐久度（0なら壊れた）
+/// * `None` - ツルハシを選択していない
+fn swing_pickaxe(
+    selected_slot: &mut SelectedItemSlot,
+    item_slot_query: &mut Query<&mut ItemSlot>,
+) -> Option<u32> {
+    let Some(ItemType::Pickaxe { durability }) = selected_slot.item_type else {
+        return None;
+    };
+    let slot_index = selected_slot.slot_index?;
+    let durability = durability.saturating_sub(1);
+
+    let item = (durability > 0).then_some(ItemType::Pickaxe { durability });
+    if let Some(mut slot) = item_slot_query
+        .iter_mut()
+        .find(|slot| slot.slot_index == slot_index)
+    {
+        slot.item = item.clone();
+    }
+    if item.is_some() {
+        selected_slot.item_type = item;
+    } else {
+        *selected_slot = SelectedItemSlot::default();
+    }
+
+    Some(durability)
+}
+
+/// 採掘の結果にツルハシの耐久度（壊れた場合はその旨）を付け加える
+fn with_pickaxe_status(mut message: String, durability: u32, locale: Locale) -> String {
+    message.push(' ');
+    if durability == 0 {
+        message.push_str(locale.t(TextKey::PickaxeBroken));
+    } else {
+        message.push_str(&locale.tf(TextKey::PickaxeDurability, &[&durability]));
+    }
+    message
+}
+
+/// 岩をクリックして採掘するシステム
+///
+/// [`RockClicked`] を受け取り、クリックされた岩の残り打撃回数を減らす。
+/// 0になった岩は削除し、その場所に鉱石をドロップする。
+/// どちらの場合も元に戻せるよう操作履歴に記録する。
+/// 採掘にはツルハシの選択が必要で、1回叩くごとに耐久度が1減る。
+///
+/// 移動モード中は処理しない。
+#[allow(clippy::too_many_arguments)]
+pub fn mine_rock_on_click(
+    mut rock_clicked: MessageReader<RockClicked>,
+    mut rock_query: Query<(&GlobalTransform, &mut Mineable), With<Rock>>,
+    move_mode: Res<FoxMoveMode>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut commands: Commands,
+    dropped_item_assets: Res<DroppedItemAssets>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    mut history: ResMut<ActionHistory>,
+    mut rock_mined: MessageWriter<RockMined>,
+    field_config: Res<FieldConfig>,
+) {
+    let Some(RockClicked(rock_entity)) = rock_clicked.read().last() else {
+        return;
+    };
+    let rock_entity = *rock_entity;
+
+    if move_mode.is_active {
+        return;
+    }
+
+    let Ok((rock_transform, mut mineable)) = rock_query.get_mut(rock_entity) else {
+        return;
+    };
+    let Ok(mut feedback_text) = feedback_text_query.single_mut() else {
+        return;
+    };
+    let Some(durability) = swing_pickaxe(&mut selected_slot, &mut item_slot_query) else {
+        feedback_text.0 = locale.t(TextKey::PickaxeRequired).to_string();
+        return;
+    };
+    let rock_position = rock_transform.translation();
+    let rock_rotation = rock_transform.rotation();
+
+    let hits_before = mineable.hits_remaining;
+    mineable.hits_remaining = mineable.hits_remaining.saturating_sub(1);
+
+    if mineable.hits_remaining == 0 {
+        let (ores, respawn_timer) = break_rock(
+            &mut commands,
+            &dropped_item_assets,
+            &field_config,
+            rock_entity,
+            rock_position,
+        );
+        history.push(HistoryAction::RockBroken {
+            position: rock_position,
+            rotation: rock_rotation,
+            ores,
+            respawn_timer,
+        });
+        rock_mined.write(RockMined);
+
+        feedback_text.0 = with_pickaxe_status(
+            locale.t(TextKey::RockMined).to_string(),
+            durability,
+            *locale,
+        );
+    } else {
+        history.push(HistoryAction::RockHit {
+            rock: rock_entity,
+            from: hits_before,
+            to: mineable.hits_remaining,
+        });
+
+        feedback_text.0 = with_pickaxe_status(
+            locale.tf(TextKey::RockMining, &[&mineable.hits_remaining]),
+            durability,
+            *locale,
+        );
+    }
+}
+
+/// 石のブロックの描画に使う共有メッシュとマテリアル
+#[derive(Resource, Clone)]
+pub struct StoneBlockAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for StoneBlockAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(create_cube_mesh(BLOCK_HALF_SIZE));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgb(
+                    STONE_BLOCK_COLOR.0,
+                    STONE_BLOCK_COLOR.1,
+                    STONE_BLOCK_COLOR.2,
+                ),
+                perceptual_roughness: 0.9,
+                ..default()
+            });
+        Self { mesh, material }
+    }
+}
+
+/// 石のブロックをクリックして採掘するシステム
+///
+/// [`MineableBlockClicked`]を受け取り、クリックされたブロックの残り打撃回数を減らす。
+/// 0になったブロックは草のブロックに変え、その上に鉱石をドロップする。
+/// 選択可能エリアの中のブロックは、草になった時点で選択可能にする。
+/// 岩と違い、ブロックの採掘は操作履歴に記録しない。
+/// 採掘にはツルハシの選択が必要で、1回叩くごとに耐久度が1減る。
+///
+/// 移動モード中は処理しない。
+#[allow(clippy::too_many_arguments)]
+pub fn mine_block_on_click(
+    mut block_clicked: MessageReader<MineableBlockClicked>,
+    mut block_query: Query<(&Transform, &mut Mineable), With<Block>>,
+    move_mode: Res<FoxMoveMode>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut item_slot_query: Query<&mut ItemSlot>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    dropped_item_assets: Res<DroppedItemAssets>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+    mut rock_mined: MessageWriter<RockMined>,
+    field_config: Res<FieldConfig>,
+    build_area: Res<BuildArea>,
+) {
+    let Some(MineableBlockClicked(block_entity)) = block_clicked.read().last() else {
+        return;
+    };
+    let block_entity = *block_entity;
+
+    if move_mode.is_active {
+        return;
+    }
+
+    let Ok((block_transform, mut mineable)) = block_query.get_mut(block_entity) else {
+        return;
+    };
+    let Ok(mut feedback_text) = feedback_text_query.single_mut() else {
+        return;
+    };
+    let Some(durability) = swing_pickaxe(&mut selected_slot, &mut item_slot_query) else {
+        feedback_text.0 = locale.t(TextKey::PickaxeRequired).to_string();
+        return;
+    };
+    mineable.hits_remaining = mineable.hits_remaining.saturating_sub(1);
+
+    if mineable.hits_remaining > 0 {
+        feedback_text.0 = with_pickaxe_status(
+            locale.tf(TextKey::StoneBlockMining, &[&mineable.hits_remaining]),
+            durability,
+            *locale,
+        );
+        return;
+    }
+
+    // 石のメッシュを外し、草のブロックの見た目に差し替える
+    // 拡大率が変わるため、タイマーの色の板は外して付け直させる
+    let position = block_transform.translation;
+    let mut entity_commands = commands.entity(block_entity);
+    entity_commands
+        .despawn_related::<Children>()
+        .remove::<(
+            Mesh3d,
+            MeshMaterial3d<StandardMaterial>,
+            Mineable,
+            cf_tool::timer::Timer,
+        )>()
+        .insert((
+            Transform::from_translation(position).with_scale(Vec3::splat(GRASS_BLOCK_SCALE)),
+            BlockType::Grass,
+        ));
+    insert_grass_block_visual(&mut entity_commands, &asset_server, &field_config);
+    let tile = field_config.grid_coords(position);
+    if field_config.tile_selectable(tile.x, tile.y, build_area.expansion) {
+        entity_commands.insert(Selectable);
+    }
+
+    for i in 0..STONE_BLOCK_ORE_YIELD {
+        let angle = i as f32 / STONE_BLOCK_ORE_YIELD as f32 * std::f32::consts::TAU;
+        let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * DROPPED_ITEM_SIZE;
+        spawn_dropped_item(
+            &mut commands,
+            &dropped_item_assets,
+            &field_config,
+            ItemType::Ore,
+            position + offset,
+        );
+    }
+    rock_mined.write(RockMined);
+
+    feedback_text.0 = with_pickaxe_status(
+        locale.t(TextKey::StoneBlockMined).to_string(),
+        durability,
+        *locale,
+    );
+}
+
+/// 岩を削除し、周囲に鉱石をドロップして再出現タイマーを開始する
+///
+/// # Returns
+/// * ドロップした鉱石と再出現タイマーのエンティティ（元に戻す操作で使う）
+pub fn break_rock(
+    commands: &mut Commands,
+    dropped_item_assets: &DroppedItemAssets,
+    field_config: &FieldConfig,
+    rock_entity: Entity,
+    rock_position: Vec3,
+) -> (Vec<Entity>, Entity) {
+    commands.entity(rock_entity).despawn();
+    let respawn_timer = commands
+        .spawn(RockRespawnTimer {
+            remaining: ROCK_RESPAWN_DELAY_SECS,
+        })
+        .id();
+
+    // 岩の周囲に鉱石を散らばらせる
+    let ores = (0..ROCK_ORE_YIELD)
+        .map(|i| {
+            let angle = i as f32 / ROCK_ORE_YIELD as f32 * std::f32::consts::TAU;
+            let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * DROPPED_ITEM_SIZE * 2.0;
+            spawn_dropped_item(
+                commands,
+                dropped_item_assets,
+                field_config,
+                ItemType::Ore,
+                rock_position + offset,
+            )
+        })
+        .collect();
+
+    (ores, respawn_timer)
+}
+
+/// 採掘された岩を一定時間後に新しいランダムな位置へ再出現させるシステム
+///
+/// 配置位置は初期配置と同じく、（拡張後の）選択可能エリアや既存の岩と重ならない場所から選ぶ。
+/// 置ける場所が見つからない場合は次のフレームで再試行する。
+#[allow(clippy::too_many_arguments)]
+pub fn respawn_rocks(
+    mut commands: Commands,
+    mut timer_query: Query<(Entity, &mut RockRespawnTimer)>,
+    rock_query: Query<&GlobalTransform, With<Rock>>,
+    asset_server: Res<AssetServer>,
+    field_config: Res<FieldConfig>,
+    build_area: Res<BuildArea>,
+    mut rng: ResMut<GameRng>,
+    time: Res<Time>,
+) {
+    let mut existing: Vec<Vec3> = rock_query.iter().map(|t| t.translation()).collect();
+
+    for (timer_entity, mut timer) in timer_query.iter_mut() {
+        timer.remaining -= time.delta_secs();
+        if timer.remaining > 0.0 {
+            continue;
+        }
+
+        if let Some(position) =
+            random_rock_position(&field_config, build_area.expansion, &existing, &mut rng)
+        {
+            let rotation = random_rock_rotation(&mut rng);
+            spawn_rock(&mut commands, &asset_server, position, rotation);
+            commands.entity(timer_entity).despawn();
+            existing.push(position);
+        }
+    }
+}
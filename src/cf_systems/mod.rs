@@ -1,13 +1,79 @@
+pub mod achievements;
 pub mod animation;
+pub mod audio;
+pub mod block_inspect;
+pub mod block_menu;
+pub mod block_timer;
+pub mod build_area;
 pub mod camera;
+pub mod camera_recording;
+pub mod console;
+pub mod dust;
+pub mod farming;
+pub mod fence;
+pub mod fox_growth;
+pub mod fox_mood;
+pub mod fox_path;
+pub mod fox_selection;
 pub mod game_logic;
+pub mod graphics;
+pub mod history;
+pub mod items;
+pub mod leash;
+pub mod lightning;
+pub mod main_menu;
+pub mod minimap;
+pub mod mining;
+pub mod picking;
+pub mod placement_preview;
+pub mod puddle;
+pub mod season;
 pub mod setup;
+pub mod sky;
+pub mod text_input;
+pub mod torch;
 pub mod ui;
+pub mod water;
 pub mod weather;
+pub mod wetness;
+pub mod window;
 
+pub use achievements::*;
 pub use animation::*;
+pub use audio::*;
+pub use block_inspect::*;
+pub use block_menu::*;
+pub use block_timer::*;
+pub use build_area::*;
 pub use camera::*;
+pub use camera_recording::*;
+pub use console::*;
+pub use dust::*;
+pub use farming::*;
+pub use fence::*;
+pub use fox_growth::*;
+pub use fox_mood::*;
+pub use fox_path::*;
+pub use fox_selection::*;
 pub use game_logic::*;
+pub use graphics::*;
+pub use history::*;
+pub use items::*;
+pub use leash::*;
+pub use lightning::*;
+pub use main_menu::*;
+pub use minimap::*;
+pub use mining::*;
+pub use picking::*;
+pub use placement_preview::*;
+pub use puddle::*;
+pub use season::*;
 pub use setup::*;
+pub use sky::*;
+pub use text_input::*;
+pub use torch::*;
 pub use ui::*;
+pub use water::*;
 pub use weather::*;
+pub use wetness::*;
+pub use window::*;
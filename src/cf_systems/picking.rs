@@ -0,0 +1,197 @@
+//! Bevyのメッシュピッキングによるホバー・クリック判定
+//!
+//! ブロック・キツネ・岩にはポインターイベントのオブザーバーを付けて生成し、
+//! クリックされた対象に応じたメッセージ（[`BlockClicked`]など）を送信する。
+//! ピッキングはカーソルに最も近いメッシュだけを対象にするため、
+//! 岩の陰になったブロックやUIの下にあるものは反応しない。
+//! 植物や雨粒など反応させたくないメッシュは[`Pickable::IGNORE`]で除外する。
+
+use bevy::picking::hover::HoverMap;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::resources::*;
+
+/// ブロックにカーソルが乗った時に、ホバー中のブロックとして記録するオブザーバー
+pub fn on_block_over(over: On<Pointer<Over>>, mut hovered_block: ResMut<HoveredBlock>) {
+    hovered_block.entity = Some(over.event_target());
+}
+
+/// ブロックからカーソルが外れた時に、ホバー中のブロックの記録を消すオブザーバー
+pub fn on_block_out(out: On<Pointer<Out>>, mut hovered_block: ResMut<HoveredBlock>) {
+    if hovered_block.entity == Some(out.event_target()) {
+        hovered_block.entity = None;
+    }
+}
+
+/// ブロックが左クリックされた時のオブザーバー
+///
+/// 採掘できるブロックなら[`MineableBlockClicked`]を、選択可能なブロックなら[`BlockClicked`]を、
+/// それ以外なら[`EmptySpaceClicked`]を送信する。
+/// カメラを回すドラッグの終わりに離した左ボタンはクリックとして扱わない。
+/// 右クリックの場合は、選択可能か採掘できるブロックなら[`BlockRightClicked`]を送信する。
+#[allow(clippy::too_many_arguments)]
+pub fn on_block_click(
+    click: On<Pointer<Click>>,
+    selectable_query: Query<(), With<Selectable>>,
+    mineable_query: Query<(), With<Mineable>>,
+    camera_mode: Res<CameraMode>,
+    drag_state: Res<MouseDragState>,
+    mut block_clicked: MessageWriter<BlockClicked>,
+    mut mineable_clicked: MessageWriter<MineableBlockClicked>,
+    mut empty_clicked: MessageWriter<EmptySpaceClicked>,
+    mut block_right_clicked: MessageWriter<BlockRightClicked>,
+) {
+    if camera_mode.is_spectator() {
+        return;
+    }
+
+    let entity = click.event_target();
+    if click.button == PointerButton::Secondary {
+        if selectable_query.contains(entity) || mineable_query.contains(entity) {
+            block_right_clicked.write(BlockRightClicked(entity));
+        }
+        return;
+    }
+    if click.button != PointerButton::Primary || drag_state.moved_since_press {
+        return;
+    }
+
+    if mineable_query.contains(entity) {
+        mineable_clicked.write(MineableBlockClicked(entity));
+        return;
+    }
+
+    match click.hit.position {
+        Some(hit_point) if selectable_query.contains(entity) => {
+            block_clicked.write(BlockClicked { entity, hit_point });
+        }
+        _ => {
+            empty_clicked.write(EmptySpaceClicked);
+        }
+    }
+}
+
+/// キツネが左クリックされた時に[`FoxClicked`]を送信するオブザーバー
+pub fn on_fox_click(
+    click: On<Pointer<Click>>,
+    camera_mode: Res<CameraMode>,
+    drag_state: Res<MouseDragState>,
+    mut fox_clicked: MessageWriter<FoxClicked>,
+) {
+    if click.button != PointerButton::Primary
+        || camera_mode.is_spectator()
+        || drag_state.moved_since_press
+    {
+        return;
+    }
+
+    fox_clicked.write(FoxClicked(click.event_target()));
+}
+
+/// 岩が左クリックされた時に[`RockClicked`]を送信するオブザーバー
+pub fn on_rock_click(
+    click: On<Pointer<Click>>,
+    camera_mode: Res<CameraMode>,
+    drag_state: Res<MouseDragState>,
+    mut rock_clicked: MessageWriter<RockClicked>,
+) {
+    if click.button != PointerButton::Primary
+        || camera_mode.is_spectator()
+        || drag_state.moved_since_press
+    {
+        return;
+    }
+
+    rock_clicked.write(RockClicked(click.event_target()));
+}
+
+/// キツネのメッシュをピッキングの対象にするかを切り替えるシステム
+///
+/// 移動モード中やアイテム選択中はキツネをクリックの対象にせず、
+/// カーソルの下にいても奥のブロックをクリックできるようにする。
+/// キツネのメッシュはシーンの読み込み後に生成されるため、毎フレーム確認する。
+pub fn update_fox_pickable(
+    mut commands: Commands,
+    fox_query: Query<Entity, With<Fox>>,
+    children_query: Query<&Children>,
+    mesh_query: Query<Option<&Pickable>, With<Mesh3d>>,
+    move_mode: Res<FoxMoveMode>,
+    selected_slot: Res<SelectedItemSlot>,
+) {
+    let pickable = if !move_mode.is_active && selected_slot.item_type.is_none() {
+        Pickable::default()
+    } else {
+        Pickable::IGNORE
+    };
+
+    for fox_entity in fox_query.iter() {
+        for descendant in children_query.iter_descendants(fox_entity) {
+            if let Ok(current) = mesh_query.get(descendant)
+                && current != Some(&pickable)
+            {
+                commands.entity(descendant).insert(pickable.clone());
+            }
+        }
+    }
+}
+
+/// 左ボタンを押してからカーソルが動いた距離を記録するシステム
+///
+/// ピッキングのクリックはドラッグでカメラを回した後でもボタンを離すと発生するため、
+/// [`CLICK_DRAG_THRESHOLD`]より動いたかを[`MouseDragState::moved_since_press`]に残し、
+/// クリックの判定側で無視できるようにする。
+pub fn track_click_drag(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    mut drag_state: ResMut<MouseDragState>,
+) {
+    let Some(cursor_position) = window_query
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+
+    if mouse_input.just_pressed(MouseButton::Left) {
+        drag_state.press_position = Some(cursor_position);
+        drag_state.moved_since_press = false;
+    } else if mouse_input.pressed(MouseButton::Left)
+        && drag_state
+            .press_position
+            .is_some_and(|press| press.distance(cursor_position) > CLICK_DRAG_THRESHOLD)
+    {
+        drag_state.moved_since_press = true;
+    }
+}
+
+/// 何もない場所（空など）で左クリックした時に[`EmptySpaceClicked`]を送信するシステム
+///
+/// ピッキングのクリックはボタンを離した時に発生するため、こちらも離した時に判定する。
+/// UIの上でクリックした場合はUIがホバーされているので送信せず、
+/// カメラを回すドラッグの終わりに離した場合も送信しない。
+pub fn emit_empty_space_clicks(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    hover_map: Res<HoverMap>,
+    camera_mode: Res<CameraMode>,
+    drag_state: Res<MouseDragState>,
+    mut empty_clicked: MessageWriter<EmptySpaceClicked>,
+) {
+    if camera_mode.is_spectator()
+        || !mouse_input.just_released(MouseButton::Left)
+        || drag_state.moved_since_press
+    {
+        return;
+    }
+
+    let hovering_nothing = hover_map
+        .get(&PointerId::Mouse)
+        .is_none_or(|hovered| hovered.is_empty());
+    if hovering_nothing {
+        empty_clicked.write(EmptySpaceClicked);
+    }
+}
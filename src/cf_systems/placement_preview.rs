@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+use crate::cf_systems::camera::main_camera;
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+
+/// 設置プレビューの描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct PlacementPreviewAssets {
+    pub mesh: Handle<Mesh>,
+    pub valid_material: Handle<StandardMaterial>,
+    pub invalid_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for PlacementPreviewAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(Cuboid::new(
+            PLACEMENT_PREVIEW_SIZE,
+            PLACEMENT_PREVIEW_SIZE,
+            PLACEMENT_PREVIEW_SIZE,
+        ));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let mut ghost_material = |color: (f32, f32, f32, f32)| {
+            materials.add(StandardMaterial {
+                base_color: Color::srgba(color.0, color.1, color.2, color.3),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            })
+        };
+        let valid_material = ghost_material(PLACEMENT_PREVIEW_VALID_COLOR);
+        let invalid_material = ghost_material(PLACEMENT_PREVIEW_INVALID_COLOR);
+        Self {
+            mesh,
+            valid_material,
+            invalid_material,
+        }
+    }
+}
+
+/// 設置できるアイテムを選択中に、設置先のプレビューを表示するシステム
+///
+/// カーソルの視線とブロック上面（持ち上げたマスはその上面）の交点に半透明のゴーストを置き、
+/// 交点のマスが選択可能なブロックなら緑、それ以外なら赤で表示する。
+/// 選択可能なブロック上では[`PlacementGrid`]のスナップ位置に合わせる（柵は最も近い辺に置く）。
+/// アイテムを選択していない場合やカーソルが画面外の場合は削除する。
+#[allow(clippy::too_many_arguments)]
+pub fn update_placement_preview(
+    mut commands: Commands,
+    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    block_query: Query<(Entity, &GlobalTransform), With<Block>>,
+    selectable_query: Query<Entity, With<Selectable>>,
+    mut preview_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut MeshMaterial3d<StandardMaterial>,
+        ),
+        With<PlacementPreview>,
+    >,
+    selected_slot: Res<SelectedItemSlot>,
+    field_config: Res<FieldConfig>,
+    placement_grid: Res<PlacementGrid>,
+    preview_assets: Res<PlacementPreviewAssets>,
+) {
+    let placeable = matches!(
+        selected_slot.item_type,
+        Some(ItemType::Fox | ItemType::Seed | ItemType::Fence | ItemType::Torch)
+    );
+
+    let hit_point = placeable
+        .then(|| {
+            let window = window_query.single().ok()?;
+            let cursor_position = window.cursor_position()?;
+            let (camera, camera_transform) = main_camera(camera_query.single())?;
+            let ray = camera
+                .viewport_to_world(camera_transform, cursor_position)
+                .ok()?;
+            field_config.ray_block_top(ray)
+        })
+        .flatten();
+
+    let Some(hit_point) = hit_point else {
+        for (entity, _, _) in preview_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    // 交点のマスにある選択可能なブロックを探す
+    let tile = field_config.grid_coords(hit_point);
+    let target_block = block_query.iter().find(|(entity, transform)| {
+        selectable_query.contains(*entity)
+            && field_config.grid_coords(transform.translation()) == tile
+    });
+
+    // 設置先の位置（Yはブロック上面の高さ）
+    let (position, material) = match target_block {
+        // 柵はクリック位置に最も近い辺に立つので、ゴーストもその辺に置く
+        Some((_, block_transform)) if matches!(selected_slot.item_type, Some(ItemType::Fence)) => {
+            let block_pos = block_transform.translation();
+            let offset = FenceSide::nearest(block_pos, hit_point).offset();
+            (
+                block_pos + Vec3::new(offset.x as f32, 1.0, offset.y as f32) * BLOCK_HALF_SIZE,
+                preview_assets.valid_material.clone(),
+            )
+        }
+        Some((_, block_transform)) => (
+            placement_grid.snap(block_transform.translation(), hit_point)
+                + Vec3::Y * BLOCK_HALF_SIZE,
+            preview_assets.valid_material.clone(),
+        ),
+        None => (hit_point, preview_assets.invalid_material.clone()),
+    };
+    let translation = position + Vec3::Y * (PLACEMENT_PREVIEW_SIZE / 2.0);
+
+    if let Ok((_, mut transform, mut preview_material)) = preview_query.single_mut() {
+        transform.translation = translation;
+        if preview_material.0 != material {
+            preview_material.0 = material;
+        }
+    } else {
+        commands.spawn((
+            Mesh3d(preview_assets.mesh.clone()),
+            MeshMaterial3d(material),
+            Transform::from_translation(translation),
+            Pickable::IGNORE,
+            PlacementPreview,
+        ));
+    }
+}
@@ -0,0 +1,157 @@
+//! 水たまり
+//!
+//! 雨が降ると各ブロックの上面に薄い半透明の水たまりが広がり、晴れると蒸発して縮む。
+//! 水のたまりやすさはマスごとに違い（[`Puddle::capacity`]）、
+//! 水たまりは[`PUDDLE_MAX_SIZE`]より大きくならないのでマスからあふれない。
+//! 憑依中のキツネが水たまりを走ると水しぶきが上がる。
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::cf_systems::weather::{RainSplashAssets, spawn_rain_splash};
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+
+/// 水たまりの描画に使う共有メッシュとマテリアル
+///
+/// メッシュは一辺1の板で、水の量に合わせてXZ方向に拡大する。
+#[derive(Resource)]
+pub struct PuddleAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for PuddleAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Plane3d::new(Vec3::Y, Vec2::splat(0.5)));
+        let (r, g, b, a) = PUDDLE_COLOR;
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgba(r, g, b, a),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 0.05,
+                reflectance: 0.8,
+                ..default()
+            });
+        Self { mesh, material }
+    }
+}
+
+/// 新しく生成されたブロックの上面に、空の水たまりを用意するシステム
+///
+/// 水のたまりやすさはブロックごとにランダムに決める。水のブロックには用意しない。
+pub fn spawn_puddles(
+    mut commands: Commands,
+    block_query: Query<(Entity, &Transform, &BlockType), Added<Block>>,
+    puddle_assets: Res<PuddleAssets>,
+    mut rng: ResMut<GameRng>,
+) {
+    for (block, block_transform, block_type) in block_query.iter() {
+        if *block_type == BlockType::Water {
+            continue;
+        }
+        let position = block_transform.translation + Vec3::Y * (BLOCK_HALF_SIZE + PUDDLE_HEIGHT);
+        commands.spawn((
+            Mesh3d(puddle_assets.mesh.clone()),
+            MeshMaterial3d(puddle_assets.material.clone()),
+            Transform::from_translation(position).with_scale(Vec3::new(0.0, 1.0, 0.0)),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            Puddle {
+                block,
+                level: 0.0,
+                capacity: rng.0.random_range(PUDDLE_CAPACITY_MIN..=1.0),
+            },
+        ));
+    }
+}
+
+/// 雨の強さに応じて水たまりを広げ、晴れている間は蒸発させて縮めるシステム
+///
+/// ブロックがなくなった水たまりは削除する。
+pub fn update_puddles(
+    mut commands: Commands,
+    mut puddle_query: Query<(Entity, &mut Puddle, &mut Transform, &mut Visibility)>,
+    block_query: Query<(), With<Block>>,
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    time: Res<Time>,
+) {
+    let delta = if weather.intensity > 0.0 && !calendar.season.is_snowy() {
+        weather.intensity * time.delta_secs() / PUDDLE_FILL_SECS
+    } else {
+        -time.delta_secs() / PUDDLE_EVAPORATE_SECS
+    };
+
+    for (entity, mut puddle, mut transform, mut visibility) in puddle_query.iter_mut() {
+        if !block_query.contains(puddle.block) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let level = (puddle.level + delta).clamp(0.0, 1.0);
+        if puddle.level == level {
+            continue;
+        }
+        puddle.level = level;
+
+        let size = puddle.size();
+        transform.scale = Vec3::new(size, 1.0, size);
+        visibility.set_if_neq(if size > 0.0 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
+/// 憑依中のキツネが水たまりを走っている間、足元に水しぶきを上げるシステム
+#[allow(clippy::too_many_arguments)]
+pub fn splash_through_puddles(
+    mut commands: Commands,
+    possession_mode: Res<PossessionMode>,
+    fox_query: Query<(&Transform, &FoxPhysics), With<Fox>>,
+    puddle_query: Query<(&Puddle, &Transform), Without<Fox>>,
+    field_config: Res<FieldConfig>,
+    splash_assets: Res<RainSplashAssets>,
+    time: Res<Time>,
+    mut splash_timer: Local<f32>,
+) {
+    *splash_timer -= time.delta_secs();
+
+    let Some(fox_entity) = possession_mode
+        .fox_entity
+        .filter(|_| possession_mode.is_active)
+    else {
+        return;
+    };
+    let Ok((fox_transform, physics)) = fox_query.get(fox_entity) else {
+        return;
+    };
+    if physics.velocity.length() < PUDDLE_SPLASH_MIN_SPEED || *splash_timer > 0.0 {
+        return;
+    }
+
+    let tile = field_config.grid_coords(fox_transform.translation);
+    let Some((_, puddle_transform)) = puddle_query.iter().find(|(puddle, transform)| {
+        puddle.size() >= PUDDLE_SPLASH_MIN_SIZE
+            && field_config.grid_coords(transform.translation) == tile
+    }) else {
+        return;
+    };
+
+    *splash_timer = PUDDLE_SPLASH_INTERVAL;
+    let feet = fox_transform
+        .translation
+        .with_y(puddle_transform.translation.y);
+    let start_angle = time.elapsed_secs() * 7.0;
+    for i in 0..PUDDLE_SPLASH_COUNT {
+        let angle = start_angle + i as f32 / PUDDLE_SPLASH_COUNT as f32 * std::f32::consts::TAU;
+        let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * FOX_HALF_SIZE * 0.5;
+        spawn_rain_splash(&mut commands, &splash_assets, feet + offset);
+    }
+}
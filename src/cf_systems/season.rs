@@ -0,0 +1,75 @@
+//! 季節
+//!
+//! ゲーム内の日付を[`Calendar`]で進め、[`SEASON_LENGTH_DAYS`]日ごとに季節が変わる。
+//! 季節は天候の抽選（春は雨が多く、夏は少ない）、空と草のブロックの色味、
+//! 植物の成長速度に影響し、冬は雨の代わりに雪が降る。
+
+use bevy::prelude::*;
+
+use crate::cf_systems::wetness::BlockWetMaterials;
+use crate::constants::*;
+use crate::resources::{Calendar, Season};
+
+/// 季節の色味を掛ける共有マテリアル
+///
+/// 草のブロックはシーンでも共有メッシュでも同じマテリアルのアセットを使うため、
+/// そのマテリアルの色を書き換えるだけで全ての草のブロックの色が変わる。
+#[derive(Resource)]
+pub struct SeasonMaterials {
+    grass: Handle<StandardMaterial>,
+    /// 色味を掛ける前の元の色（マテリアルの読み込み後に記録する）
+    grass_base_color: Option<Color>,
+    /// 色味を適用済みの季節
+    applied: Option<Season>,
+}
+
+impl FromWorld for SeasonMaterials {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            grass: world.resource::<AssetServer>().load(GRASS_BLOCK_MATERIAL),
+            grass_base_color: None,
+            applied: None,
+        }
+    }
+}
+
+/// ゲーム内の日付を進めるシステム
+pub fn advance_calendar(mut calendar: ResMut<Calendar>, time: Res<Time>) {
+    if calendar.advance(time.delta_secs()) {
+        println!("季節が変わりました: {:?}", calendar.season);
+    }
+}
+
+/// 季節が変わった時に、草のブロックの共有マテリアルに季節の色味を掛けるシステム
+///
+/// マテリアルの読み込みが終わるまでは何もしない。
+/// 濡れたマテリアルも作り直し、雨の間も季節の色味が保たれるようにする。
+pub fn apply_season_tint(
+    calendar: Res<Calendar>,
+    mut season_materials: ResMut<SeasonMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    wet_materials: Res<BlockWetMaterials>,
+) {
+    if season_materials.applied == Some(calendar.season) {
+        return;
+    }
+
+    let grass = season_materials.grass.clone();
+    let Some(material) = materials.get_mut(&grass) else {
+        return;
+    };
+    let base = season_materials
+        .grass_base_color
+        .get_or_insert(material.base_color)
+        .to_srgba();
+    let tint = calendar.season.field_tint();
+    material.base_color = Color::srgba(
+        base.red * tint.0,
+        base.green * tint.1,
+        base.blue * tint.2,
+        base.alpha,
+    );
+
+    wet_materials.refresh_wet(&grass, &mut materials);
+    season_materials.applied = Some(calendar.season);
+}
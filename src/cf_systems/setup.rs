@@ -1,36 +1,146 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use rand::Rng;
 
+use crate::cf_systems::graphics::sun_rotation;
+use crate::cf_systems::mining::StoneBlockAssets;
+use crate::cf_systems::picking::{
+    on_block_click, on_block_out, on_block_over, on_fox_click, on_rock_click,
+};
+use crate::cf_systems::sky::spawn_sky;
+use crate::cf_systems::water::WaterAssets;
 use crate::cf_tool;
 use crate::components::*;
 use crate::constants::*;
+use crate::resources::{
+    BuildArea, Calendar, CameraSettings, FieldConfig, GameRng, WaterTiles, WeatherState,
+};
 
 /// ゲームのセットアップシステム
 #[allow(unused_doc_comments)]
-pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+#[allow(clippy::too_many_arguments)]
+pub fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    field_config: Res<FieldConfig>,
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    settings: Res<CameraSettings>,
+    build_area: Res<BuildArea>,
+    mut rng: ResMut<GameRng>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    stone_assets: Res<StoneBlockAssets>,
+    (water_assets, mut water_tiles): (Res<WaterAssets>, ResMut<WaterTiles>),
+) {
     let fox_icon: Handle<Image> = asset_server.load("animated/Fox_img_512x512.png");
 
-    spawn_field(&mut commands, &asset_server);
+    spawn_field(
+        &mut commands,
+        &asset_server,
+        &stone_assets,
+        &water_assets,
+        &mut water_tiles,
+        &field_config,
+        &mut rng,
+    );
 
-    spawn_fox(&mut commands, &asset_server);
-    spawn_rock(&mut commands, &asset_server);
-    spawn_camera_and_light(&mut commands);
+    spawn_fox(
+        &mut commands,
+        &asset_server,
+        field_config.fox_spawn_position(),
+    );
+    spawn_rocks(
+        &mut commands,
+        &asset_server,
+        &field_config,
+        build_area.expansion,
+        &mut rng,
+    );
+    spawn_camera_and_light(&mut commands, &weather, &settings);
+    spawn_sky(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &weather,
+        &calendar,
+    );
     spawn_ui(&mut commands, fox_icon);
 }
 
-fn spawn_field(commands: &mut Commands, asset_server: &AssetServer) {
-    for x in 0..FIELD_SIZE {
-        for z in 0..FIELD_SIZE {
-            let x_pos = (x as f32 - FIELD_SIZE as f32 / 2.0) * BLOCK_SPACING;
-            let z_pos = (z as f32 - FIELD_SIZE as f32 / 2.0) * BLOCK_SPACING;
+/// フィールドのブロックを生成する
+///
+/// 初期の選択可能エリアの外のブロックは、[`STONE_BLOCK_CHANCE`]の確率で採掘できる石のブロックにする。
+/// [`FieldConfig::level`]がある場合は、乱数を使わずレベルファイルのマスの種類・選択可能か・高さに従う。
+/// 水のブロックはレベルファイルで指定した場合だけ置き、[`WaterTiles`]に登録する。
+pub fn spawn_field(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    stone_assets: &StoneBlockAssets,
+    water_assets: &WaterAssets,
+    water_tiles: &mut WaterTiles,
+    field_config: &FieldConfig,
+    rng: &mut GameRng,
+) {
+    for x in 0..field_config.size {
+        for z in 0..field_config.size {
+            let block_pos =
+                field_config.block_position(x, z) + Vec3::Y * field_config.tile_elevation(x, z);
 
-            let is_selectable = (SELECTABLE_AREA_START..=SELECTABLE_AREA_END).contains(&x)
-                && (SELECTABLE_AREA_START..=SELECTABLE_AREA_END).contains(&z);
+            let (is_selectable, kind) = match field_config
+                .level
+                .as_ref()
+                .and_then(|level| level.tile(x, z))
+            {
+                Some(tile) => (tile.selectable, tile.kind),
+                None => {
+                    let is_selectable = field_config.is_selectable(x, z, 0);
+                    let kind = if !is_selectable && rng.0.random_bool(STONE_BLOCK_CHANCE) {
+                        BlockType::Stone
+                    } else {
+                        BlockType::Grass
+                    };
+                    (is_selectable, kind)
+                }
+            };
 
-            let mut entity_commands = commands.spawn((
-                SceneRoot(asset_server.load("animated/GrassBlock.glb#Scene0")),
-                Transform::from_xyz(x_pos, 0.0, z_pos).with_scale(Vec3::splat(17.0)),
-                Block,
-            ));
+            let mut entity_commands = match kind {
+                BlockType::Stone => commands.spawn((
+                    Mesh3d(stone_assets.mesh.clone()),
+                    MeshMaterial3d(stone_assets.material.clone()),
+                    Transform::from_translation(block_pos),
+                    Block,
+                    BlockType::Stone,
+                    Mineable {
+                        hits_remaining: STONE_BLOCK_MINE_HITS,
+                    },
+                )),
+                BlockType::Water => {
+                    water_tiles.tiles.insert(IVec2::new(x, z));
+                    commands.spawn((
+                        Mesh3d(water_assets.block_mesh.clone()),
+                        MeshMaterial3d(water_assets.block_material.clone()),
+                        Transform::from_translation(block_pos),
+                        Block,
+                        BlockType::Water,
+                    ))
+                }
+                BlockType::Grass => {
+                    let mut entity_commands = commands.spawn((
+                        Transform::from_translation(block_pos)
+                            .with_scale(Vec3::splat(GRASS_BLOCK_SCALE)),
+                        Block,
+                        BlockType::Grass,
+                    ));
+                    insert_grass_block_visual(&mut entity_commands, asset_server, field_config);
+                    entity_commands
+                }
+            };
+            entity_commands
+                .observe(on_block_over)
+                .observe(on_block_out)
+                .observe(on_block_click);
 
             if is_selectable {
                 entity_commands.insert(Selectable);
@@ -39,26 +149,132 @@ fn spawn_field(commands: &mut Commands, asset_server: &AssetServer) {
     }
 }
 
-fn spawn_fox(commands: &mut Commands, asset_server: &AssetServer) {
-    commands.spawn((
-        SceneRoot(asset_server.load("animated/Fox.glb#Scene0")),
-        Transform::from_xyz(0.0, FOX_INITIAL_HEIGHT, 0.0).with_scale(Vec3::splat(FOX_SCALE)),
-        Fox,
-        cf_tool::timer::Timer {
-            time: 0.0,
-            name: "Fox".to_string(),
-        },
-    ));
+/// 草のブロックの見た目を追加する
+///
+/// [`FieldConfig::batched`]の場合はシーンを使わず、共有メッシュとマテリアルを
+/// ブロックのエンティティに直接付ける。
+pub fn insert_grass_block_visual(
+    entity_commands: &mut EntityCommands,
+    asset_server: &AssetServer,
+    field_config: &FieldConfig,
+) {
+    if field_config.batched {
+        entity_commands.insert((
+            Mesh3d(asset_server.load(GRASS_BLOCK_MESH)),
+            MeshMaterial3d::<StandardMaterial>(asset_server.load(GRASS_BLOCK_MATERIAL)),
+        ));
+    } else {
+        entity_commands.insert(SceneRoot(asset_server.load(GRASS_BLOCK_SCENE)));
+    }
 }
 
-fn spawn_rock(commands: &mut Commands, asset_server: &AssetServer) {
-    commands.spawn((
-        SceneRoot(asset_server.load("animated/rock.glb#Scene0")),
-        Transform::from_xyz(32.0, 8.0, -32.0).with_scale(Vec3::splat(18.0)),
-    ));
+pub fn spawn_fox(commands: &mut Commands, asset_server: &AssetServer, position: Vec3) {
+    commands
+        .spawn((
+            SceneRoot(asset_server.load("animated/Fox.glb#Scene0")),
+            Transform::from_translation(position).with_scale(Vec3::splat(FoxAge::scale(0))),
+            Fox,
+            FoxAge::default(),
+            FoxPhysics::default(),
+            FoxName("Fox".to_string()),
+            FoxMood::default(),
+            FoxStats::default(),
+            cf_tool::timer::Timer {
+                time: 0.0,
+                name: "Fox".to_string(),
+                threshold: None,
+            },
+        ))
+        .observe(on_fox_click);
+}
+
+/// （`expansion`周広げた）選択可能エリアの外側に岩をランダムに配置する
+pub fn spawn_rocks(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    field_config: &FieldConfig,
+    expansion: i32,
+    rng: &mut GameRng,
+) {
+    let mut placed: Vec<Vec3> = Vec::with_capacity(ROCK_COUNT);
+
+    for _ in 0..ROCK_COUNT {
+        let Some(position) = random_rock_position(field_config, expansion, &placed, rng) else {
+            eprintln!("岩を配置できる場所が見つかりませんでした");
+            break;
+        };
+        spawn_rock(commands, asset_server, position, random_rock_rotation(rng));
+        placed.push(position);
+    }
+}
+
+/// 岩の向きをY軸周りにランダムに決める
+pub fn random_rock_rotation(rng: &mut GameRng) -> Quat {
+    Quat::from_rotation_y(rng.0.random_range(0.0..std::f32::consts::TAU))
+}
+
+/// 岩を1つ生成する
+pub fn spawn_rock(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    position: Vec3,
+    rotation: Quat,
+) -> Entity {
+    commands
+        .spawn((
+            SceneRoot(asset_server.load("animated/rock.glb#Scene0")),
+            Transform::from_translation(position)
+                .with_rotation(rotation)
+                .with_scale(Vec3::splat(ROCK_SCALE)),
+            Rock,
+            Mineable {
+                hits_remaining: ROCK_MINE_HITS,
+            },
+        ))
+        .observe(on_rock_click)
+        .id()
+}
+
+/// 岩を置けるランダムな位置を探す
+///
+/// フィールド内で、`expansion`周分広げた選択可能エリア・キツネの初期位置・
+/// 既存の岩と重ならない位置を返す。見つからない場合は`None`。
+pub fn random_rock_position(
+    field_config: &FieldConfig,
+    expansion: i32,
+    existing: &[Vec3],
+    rng: &mut GameRng,
+) -> Option<Vec3> {
+    let field_rect = field_config.field_rect();
+    let forbidden_rect = field_config.selectable_rect(expansion).inflate(ROCK_RADIUS);
+    let fox_start = Vec2::ZERO;
+
+    for _ in 0..ROCK_PLACEMENT_ATTEMPTS {
+        let candidate = Vec2::new(
+            rng.0.random_range(field_rect.min.x..field_rect.max.x),
+            rng.0.random_range(field_rect.min.y..field_rect.max.y),
+        );
+
+        if forbidden_rect.contains(candidate)
+            || candidate.distance(fox_start) < ROCK_RADIUS + FOX_HALF_SIZE
+            || existing
+                .iter()
+                .any(|rock| candidate.distance(rock.xz()) < ROCK_MIN_SPACING)
+        {
+            continue;
+        }
+
+        return Some(Vec3::new(candidate.x, ROCK_HEIGHT, candidate.y));
+    }
+
+    None
 }
 
-fn spawn_camera_and_light(commands: &mut Commands) {
+fn spawn_camera_and_light(
+    commands: &mut Commands,
+    weather: &WeatherState,
+    settings: &CameraSettings,
+) {
     let camera_and_light_transform = Transform::from_xyz(
         CAMERA_INITIAL_POSITION.0,
         CAMERA_INITIAL_POSITION.1,
@@ -68,18 +284,19 @@ fn spawn_camera_and_light(commands: &mut Commands) {
 
     commands.spawn((Camera3d::default(), camera_and_light_transform, MainCamera));
 
+    // 日陰が暗くなりすぎないよう、設定の明るさで環境光を当てる
+    commands.insert_resource(GlobalAmbientLight {
+        brightness: settings.ambient_brightness,
+        ..default()
+    });
+
     commands.spawn((
         DirectionalLight {
-            illuminance: SUN_ILLUMINANCE_CLEAR,
+            illuminance: weather.sun_illuminance(),
             shadows_enabled: true,
             ..default()
         },
-        Transform::from_rotation(Quat::from_euler(
-            bevy::math::EulerRot::XYZ,
-            -std::f32::consts::FRAC_PI_4,
-            std::f32::consts::FRAC_PI_4,
-            0.0,
-        )),
+        Transform::from_rotation(sun_rotation(settings)),
         SunLight,
     ));
 }
@@ -104,7 +321,110 @@ fn spawn_ui(commands: &mut Commands, fox_icon: Handle<Image>) {
             left: Val::Px(10.0),
             ..default()
         },
-        ClickFeedbackText,
+        ClickFeedbackText::default(),
+    ));
+
+    commands.spawn((
+        Text::new(""),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        ResourcesText,
+    ));
+
+    // 天気アイコンと次の変化までのカウントダウン
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(40.0),
+            right: Val::Px(10.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(WEATHER_ICON_SIZE),
+                    height: Val::Px(WEATHER_ICON_SIZE),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                WeatherIcon,
+            ));
+            parent.spawn((Text::new(""), WeatherForecastText));
+        });
+
+    commands.spawn((
+        Text::new(""),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        PlaytimeText,
+    ));
+
+    // タイマー一覧パネル（行は update_timer_panel が子として追加する）
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(100.0),
+            right: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        Pickable::IGNORE,
+        cf_tool::timer::TimerPanel,
+    ));
+
+    // ブロックの情報パネル（Altを押しながらホバーした時だけ表示する）
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            top: Val::Px(100.0),
+            left: Val::Px(10.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        Pickable::IGNORE,
+        BlockInspectPanel,
+    ));
+
+    // ミニマップ（点は update_minimap が子として追加する）
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            right: Val::Px(10.0),
+            width: Val::Px(MINIMAP_SIZE),
+            height: Val::Px(MINIMAP_SIZE),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(
+            MINIMAP_BACKGROUND_COLOR.0,
+            MINIMAP_BACKGROUND_COLOR.1,
+            MINIMAP_BACKGROUND_COLOR.2,
+            MINIMAP_BACKGROUND_COLOR.3,
+        )),
+        Button,
+        RelativeCursorPosition::default(),
+        Minimap,
     ));
 
     spawn_item_area(commands, fox_icon);
@@ -166,7 +486,25 @@ pub fn spawn_item_area(commands: &mut Commands, fox_icon: Handle<Image>) {
                         )),
                         ItemSlot {
                             slot_index: i,
-                            item: None,
+                            // 最初のスロットから種、柵、松明、ツルハシの順に入れておく
+                            item: if i < STARTING_SEED_COUNT {
+                                Some(ItemType::Seed)
+                            } else if i < STARTING_SEED_COUNT + STARTING_FENCE_COUNT {
+                                Some(ItemType::Fence)
+                            } else if i < STARTING_SEED_COUNT
+                                + STARTING_FENCE_COUNT
+                                + STARTING_TORCH_COUNT
+                            {
+                                Some(ItemType::Torch)
+                            } else if i < STARTING_SEED_COUNT
+                                + STARTING_FENCE_COUNT
+                                + STARTING_TORCH_COUNT
+                                + STARTING_PICKAXE_COUNT
+                            {
+                                Some(ItemType::new_pickaxe())
+                            } else {
+                                None
+                            },
                         },
                         Button,
                     ))
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::Face;
+
+use crate::cf_systems::camera::main_camera;
+use crate::components::{MainCamera, SkyDome};
+use crate::constants::*;
+use crate::resources::{Calendar, WeatherState};
+
+/// 天候・季節・時刻に応じた空の色味（頂点カラーに掛け合わせる色）
+fn sky_tint(weather: &WeatherState, calendar: &Calendar) -> Color {
+    let weather_tint = if weather.is_raining {
+        SKY_RAIN_TINT
+    } else {
+        (1.0, 1.0, 1.0)
+    };
+    let season_tint = calendar.season.sky_tint();
+    let daylight_tint = calendar.daylight_tint();
+    Color::srgb(
+        weather_tint.0 * season_tint.0 * daylight_tint.0,
+        weather_tint.1 * season_tint.1 * daylight_tint.1,
+        weather_tint.2 * season_tint.2 * daylight_tint.2,
+    )
+}
+
+/// 地平線から天頂へのグラデーションを持つ空のドームを生成する
+///
+/// 内側から見えるよう裏面を描画する球体に、高さに応じた頂点カラーを設定する。
+/// ライティングの影響を受けないよう unlit のマテリアルを使う。
+pub fn spawn_sky(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    weather: &WeatherState,
+    calendar: &Calendar,
+) {
+    let horizon = LinearRgba::from(Color::srgb(
+        SKY_HORIZON_COLOR.0,
+        SKY_HORIZON_COLOR.1,
+        SKY_HORIZON_COLOR.2,
+    ));
+    let zenith = LinearRgba::from(Color::srgb(
+        SKY_ZENITH_COLOR.0,
+        SKY_ZENITH_COLOR.1,
+        SKY_ZENITH_COLOR.2,
+    ));
+
+    let mut mesh = Sphere::new(SKY_RADIUS).mesh().uv(32, 18);
+    let colors: Vec<[f32; 4]> = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|positions| positions.as_float3())
+        .unwrap_or_default()
+        .iter()
+        .map(|position| {
+            let height = (position[1] / SKY_RADIUS).clamp(0.0, 1.0);
+            horizon.mix(&zenith, height.sqrt()).to_f32_array()
+        })
+        .collect();
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+
+    commands.spawn((
+        Mesh3d(meshes.add(mesh)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: sky_tint(weather, calendar),
+            unlit: true,
+            cull_mode: Some(Face::Front),
+            fog_enabled: false,
+            ..default()
+        })),
+        Transform::default(),
+        Pickable::IGNORE,
+        SkyDome,
+    ));
+}
+
+/// 空のドームをカメラに追従させ、天候と時刻に合わせて色味を変えるシステム
+///
+/// 雨が降ると空が徐々に暗くなり、晴れると元の色に戻る。季節によっても色味を変え、
+/// 夕暮れには橙色に、夜には暗い藍色になる。
+/// 背景色（`ClearColor`）も地平線の色に合わせる。
+pub fn update_sky(
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    camera_query: Query<&Transform, (With<MainCamera>, Without<SkyDome>)>,
+    mut sky_query: Query<(&mut Transform, &MeshMaterial3d<StandardMaterial>), With<SkyDome>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut clear_color: ResMut<ClearColor>,
+    time: Res<Time>,
+) {
+    let Ok((mut sky_transform, sky_material)) = sky_query.single_mut() else {
+        return;
+    };
+
+    if let Some(camera_transform) = main_camera(camera_query.single()) {
+        sky_transform.translation = camera_transform.translation;
+    }
+
+    let Some(material) = materials.get_mut(&sky_material.0) else {
+        return;
+    };
+
+    let target = sky_tint(&weather, &calendar);
+    let t = (SKY_TRANSITION_SPEED * time.delta_secs()).min(1.0);
+    material.base_color = material.base_color.mix(&target, t);
+
+    let horizon = Color::srgb(
+        SKY_HORIZON_COLOR.0,
+        SKY_HORIZON_COLOR.1,
+        SKY_HORIZON_COLOR.2,
+    )
+    .to_linear();
+    let tint = material.base_color.to_linear();
+    clear_color.0 = LinearRgba::rgb(
+        horizon.red * tint.red,
+        horizon.green * tint.green,
+        horizon.blue * tint.blue,
+    )
+    .into();
+}
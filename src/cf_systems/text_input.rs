@@ -0,0 +1,236 @@
+//! 1行テキスト入力UI
+//!
+//! [`TextInput`]を持つボタンをクリックするとフォーカスされ、
+//! キーボードの文字入力を受け付ける。Enterで確定すると
+//! [`TextInputSubmitted`]を送信するので、用途ごとのシステムがそれを読む。
+//! フォーカス中はIMEを有効にし、日本語などの変換入力も受け付ける。
+
+use bevy::ecs::system::EntityCommands;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy::window::{Ime, PrimaryWindow};
+
+use crate::components::{TextInput, TextInputDisplay};
+use crate::constants::*;
+use crate::events::TextInputSubmitted;
+
+/// テキスト入力欄を生成する
+///
+/// 呼び出し側は返り値に用途を表すコンポーネントを追加して使う。
+pub fn spawn_text_input<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    initial_value: &str,
+    max_len: usize,
+    width: f32,
+) -> EntityCommands<'a> {
+    let mut entity_commands = parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(width),
+            height: Val::Px(TEXT_INPUT_HEIGHT),
+            align_items: AlignItems::Center,
+            padding: UiRect::horizontal(Val::Px(6.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+        BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
+        TextInput {
+            value: initial_value.chars().take(max_len).collect(),
+            max_len,
+            focused: false,
+            preedit: String::new(),
+        },
+    ));
+
+    entity_commands.with_children(|p| {
+        p.spawn((
+            Text::new(initial_value),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            TextInputDisplay,
+        ));
+    });
+
+    entity_commands
+}
+
+/// クリックされたテキスト入力欄にフォーカスを移すシステム
+///
+/// 入力欄以外がクリックされた場合はフォーカスを外す。
+pub fn focus_text_input(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut input_query: Query<(Entity, &Interaction, &mut TextInput)>,
+) {
+    if !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let clicked = input_query
+        .iter()
+        .find(|(_, interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(entity, _, _)| entity);
+
+    for (entity, _, mut input) in input_query.iter_mut() {
+        let focused = Some(entity) == clicked;
+        if input.focused != focused {
+            input.focused = focused;
+        }
+    }
+}
+
+/// フォーカス中のテキスト入力欄にキーボード入力を反映するシステム
+///
+/// 文字は`max_len`文字まで追加でき、Backspaceで1文字削除する。
+/// IMEで変換中の文字列は`preedit`に持ち、確定した時に`value`へ追加する。
+/// Enterで確定して[`TextInputSubmitted`]を送信し、Escでフォーカスを外す。
+/// 入力中はゲームのキー操作が反応しないよう、キー入力の状態をリセットする。
+pub fn text_input_keyboard(
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut ime_events: MessageReader<Ime>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut input_query: Query<(Entity, &mut TextInput)>,
+    mut submitted: MessageWriter<TextInputSubmitted>,
+) {
+    let Some((entity, mut input)) = input_query.iter_mut().find(|(_, input)| input.focused) else {
+        keyboard_events.clear();
+        ime_events.clear();
+        return;
+    };
+
+    for event in ime_events.read() {
+        match event {
+            Ime::Preedit { value, .. } => input.preedit = value.clone(),
+            Ime::Commit { value, .. } => {
+                input.preedit.clear();
+                push_chars(&mut input, value);
+            }
+            Ime::Enabled { .. } | Ime::Disabled { .. } => input.preedit.clear(),
+        }
+    }
+
+    // 変換中のEnterやBackspaceはIMEが使うので、入力欄では扱わない
+    if !input.preedit.is_empty() {
+        keyboard_events.clear();
+        keyboard_input.reset_all();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                submitted.write(TextInputSubmitted {
+                    input: entity,
+                    value: input.value.clone(),
+                });
+                input.focused = false;
+                break;
+            }
+            Key::Escape => {
+                input.focused = false;
+                break;
+            }
+            Key::Backspace => {
+                input.value.pop();
+            }
+            _ => {
+                let Some(text) = &event.text else {
+                    continue;
+                };
+                push_chars(&mut input, text);
+            }
+        }
+    }
+
+    keyboard_input.reset_all();
+}
+
+/// 制御文字を除いた文字を`max_len`文字まで入力欄に追加する
+fn push_chars(input: &mut TextInput, text: &str) {
+    for c in text.chars().filter(|c| !c.is_control()) {
+        if input.value.chars().count() >= input.max_len {
+            break;
+        }
+        input.value.push(c);
+    }
+}
+
+/// テキスト入力欄にフォーカスがある間だけIMEを有効にするシステム
+///
+/// 変換候補のウィンドウがフォーカス中の入力欄のすぐ下に出るよう、位置も合わせる。
+pub fn update_text_input_ime(
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mut input_query: Query<(&mut TextInput, &ComputedNode, &UiGlobalTransform)>,
+) {
+    let Ok(mut window) = window_query.single_mut() else {
+        return;
+    };
+
+    // フォーカス中の入力欄の左下（論理ピクセル）
+    let focused_corner =
+        input_query
+            .iter()
+            .find(|(input, _, _)| input.focused)
+            .map(|(_, node, transform)| {
+                (transform.translation + Vec2::new(-node.size.x, node.size.y) / 2.0)
+                    * node.inverse_scale_factor
+            });
+    let Some(ime_position) = focused_corner else {
+        if window.ime_enabled {
+            window.ime_enabled = false;
+        }
+        for (mut input, _, _) in input_query.iter_mut() {
+            if !input.preedit.is_empty() {
+                input.preedit.clear();
+            }
+        }
+        return;
+    };
+
+    if !window.ime_enabled {
+        window.ime_enabled = true;
+    }
+    if window.ime_position != ime_position {
+        window.ime_position = ime_position;
+    }
+}
+
+/// テキスト入力欄の表示（入力中の文字列・キャレット・枠の色）を更新するシステム
+pub fn update_text_input_display(
+    input_query: Query<(&TextInput, &Children, &mut BorderColor)>,
+    mut text_query: Query<&mut Text, With<TextInputDisplay>>,
+    time: Res<Time>,
+) {
+    let caret_visible =
+        ((time.elapsed_secs() / TEXT_INPUT_CARET_BLINK_INTERVAL) as u32).is_multiple_of(2);
+
+    for (input, children, mut border_color) in input_query {
+        let border = if input.focused {
+            Color::srgb(0.9, 0.8, 0.3)
+        } else {
+            Color::srgb(0.5, 0.5, 0.5)
+        };
+        *border_color = BorderColor::all(border);
+
+        let mut display = input.value.clone();
+        display.push_str(&input.preedit);
+        if input.focused && caret_visible {
+            display.push('|');
+        }
+
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child)
+                && text.0 != display
+            {
+                text.0 = display.clone();
+            }
+        }
+    }
+}
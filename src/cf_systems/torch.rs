@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::{FieldConfig, SavedTorch, TorchSave};
+use crate::traits::JsonSave;
+
+/// 松明の描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct TorchAssets {
+    pub stick_mesh: Handle<Mesh>,
+    pub flame_mesh: Handle<Mesh>,
+    pub stick_material: Handle<StandardMaterial>,
+    pub flame_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for TorchAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let stick_mesh = meshes.add(Cylinder::new(TORCH_STICK_RADIUS, TORCH_HEIGHT));
+        let flame_mesh = meshes.add(Sphere::new(TORCH_FLAME_RADIUS));
+
+        let light_color = Color::srgb(
+            TORCH_LIGHT_COLOR.0,
+            TORCH_LIGHT_COLOR.1,
+            TORCH_LIGHT_COLOR.2,
+        );
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let stick_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(FENCE_COLOR.0, FENCE_COLOR.1, FENCE_COLOR.2),
+            ..default()
+        });
+        let flame_material = materials.add(StandardMaterial {
+            base_color: light_color,
+            emissive: LinearRgba::from(light_color) * 10.0,
+            ..default()
+        });
+
+        Self {
+            stick_mesh,
+            flame_mesh,
+            stick_material,
+            flame_material,
+        }
+    }
+}
+
+/// ブロックの上に松明を設置する
+///
+/// 炎の位置に暖色の`PointLight`を子として持ち、`flicker_torches`で明るさを揺らす。
+pub fn spawn_torch(
+    commands: &mut Commands,
+    assets: &TorchAssets,
+    block_entity: Entity,
+    block_pos: Vec3,
+) -> Entity {
+    // 松明ごとに揺らぎのタイミングをずらす
+    let flicker_phase = (block_pos.x * 12.9898 + block_pos.z * 78.233).sin() * 43758.547;
+
+    commands
+        .spawn((
+            Mesh3d(assets.stick_mesh.clone()),
+            MeshMaterial3d(assets.stick_material.clone()),
+            Transform::from_xyz(
+                block_pos.x,
+                block_pos.y + BLOCK_HALF_SIZE + TORCH_HEIGHT / 2.0,
+                block_pos.z,
+            ),
+            Pickable::IGNORE,
+            Torch {
+                block: block_entity,
+                flicker_phase,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Mesh3d(assets.flame_mesh.clone()),
+                MeshMaterial3d(assets.flame_material.clone()),
+                Transform::from_xyz(0.0, TORCH_HEIGHT / 2.0, 0.0),
+                Pickable::IGNORE,
+                PointLight {
+                    color: Color::srgb(
+                        TORCH_LIGHT_COLOR.0,
+                        TORCH_LIGHT_COLOR.1,
+                        TORCH_LIGHT_COLOR.2,
+                    ),
+                    intensity: TORCH_LIGHT_INTENSITY,
+                    range: TORCH_LIGHT_RANGE,
+                    ..default()
+                },
+            ));
+        })
+        .id()
+}
+
+/// 松明の明るさを炎のように揺らすシステム
+///
+/// 周期の異なる正弦波を重ねて、規則的に見えない揺らぎを作る。
+pub fn flicker_torches(
+    torch_query: Query<(&Torch, &Children)>,
+    mut light_query: Query<&mut PointLight>,
+    time: Res<Time>,
+) {
+    let t = time.elapsed_secs() * TORCH_FLICKER_SPEED;
+
+    for (torch, children) in torch_query.iter() {
+        let phase = t + torch.flicker_phase;
+        let flicker = (phase.sin() + (phase * 2.3).sin() * 0.5 + (phase * 5.7).sin() * 0.25) / 1.75;
+
+        for child in children.iter() {
+            if let Ok(mut light) = light_query.get_mut(child) {
+                light.intensity = TORCH_LIGHT_INTENSITY * (1.0 + flicker * TORCH_FLICKER_AMOUNT);
+            }
+        }
+    }
+}
+
+/// 前回終了時に置いてあった松明をセーブファイルから置き直すシステム
+///
+/// フィールドのブロックが生成された後に一度だけ実行する。置かれていたマスにブロックがない松明は捨てる。
+pub fn restore_saved_torches(
+    mut commands: Commands,
+    assets: Res<TorchAssets>,
+    block_query: Query<(Entity, &Transform), With<Block>>,
+    field_config: Res<FieldConfig>,
+    mut restored: Local<bool>,
+) {
+    if *restored || block_query.is_empty() {
+        return;
+    }
+    *restored = true;
+
+    for torch in TorchSave::load_or_default().torches {
+        let tile = IVec2::from_array(torch.tile);
+        let Some((block, block_transform)) = block_query
+            .iter()
+            .find(|(_, transform)| field_config.grid_coords(transform.translation) == tile)
+        else {
+            continue;
+        };
+        let position =
+            block_transform.translation + Vec3::new(torch.offset[0], 0.0, torch.offset[1]);
+        spawn_torch(&mut commands, &assets, block, position);
+    }
+}
+
+/// 松明を設置・撤去したらセーブファイルに書き出すシステム
+pub fn save_torches(
+    torch_query: Query<(&Torch, &Transform)>,
+    added_query: Query<(), Added<Torch>>,
+    mut removed: RemovedComponents<Torch>,
+    block_query: Query<&Transform, With<Block>>,
+    field_config: Res<FieldConfig>,
+) {
+    let removed_any = removed.read().count() > 0;
+    if !removed_any && added_query.is_empty() {
+        return;
+    }
+
+    let torches = torch_query
+        .iter()
+        .filter_map(|(torch, transform)| {
+            let block_pos = block_query.get(torch.block).ok()?.translation;
+            let offset = transform.translation - block_pos;
+            Some(SavedTorch {
+                tile: field_config.grid_coords(block_pos).to_array(),
+                offset: [offset.x, offset.z],
+            })
+        })
+        .collect();
+    if let Err(e) = (TorchSave { torches }).save_to_file() {
+        eprintln!("Failed to save torches: {}", e);
+    }
+}
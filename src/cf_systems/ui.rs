@@ -1,10 +1,74 @@
+use bevy::asset::io::file::FileAssetReader;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
 
+use crate::cf_systems::graphics::shadow_map_size_step;
 use crate::components::*;
 use crate::constants::*;
+use crate::locale::{Locale, TextKey};
 use crate::resources::*;
+use crate::traits::{JsonSave, Storable};
+
+/// 日本語表示用フォントのハンドル
+#[derive(Resource)]
+pub struct JapaneseFont(pub Handle<Font>);
+
+impl FromWorld for JapaneseFont {
+    /// `assets`の[`JAPANESE_FONT_PATH`]を読み込む
+    ///
+    /// 有無はアセットサーバーと同じ場所（`cargo run`ではクレートの、それ以外では実行ファイルの
+    /// ディレクトリにある`assets`）で確かめるので、起動したディレクトリには左右されない。
+    /// 置かれていない場合は[`JAPANESE_SYSTEM_FONT_PATHS`]からOSの日本語フォントを探して使う。
+    /// どちらも見つからない場合は警告を出し、日本語は表示されないままになる。
+    fn from_world(world: &mut World) -> Self {
+        let assets_dir = FileAssetReader::new(AssetPlugin::default().file_path);
+        if assets_dir.root_path().join(JAPANESE_FONT_PATH).exists() {
+            return Self(world.resource::<AssetServer>().load(JAPANESE_FONT_PATH));
+        }
+
+        let system_font = JAPANESE_SYSTEM_FONT_PATHS.iter().find_map(|path| {
+            let bytes = std::fs::read(path).ok()?;
+            Font::try_from_bytes(bytes).ok()
+        });
+        match system_font {
+            Some(font) => Self(world.resource_mut::<Assets<Font>>().add(font)),
+            None => {
+                warn!(
+                    "日本語フォントが見つかりません。{} に日本語フォントを配置してください",
+                    assets_dir.root_path().join(JAPANESE_FONT_PATH).display()
+                );
+                Self(Handle::default())
+            }
+        }
+    }
+}
+
+/// 日本語フォントが使えるようになった時に、デフォルトフォントとして登録するシステム
+///
+/// Bevy組み込みのデフォルトフォントは日本語のグリフを含まないため、
+/// `TextFont`でフォントを指定していないテキストの漢字・かなが表示されない。
+/// デフォルトフォントのハンドルを差し替えることで、すべてのテキストに適用する。
+/// `assets`からの読み込みを待つ場合と、OSのフォントを直接追加した場合の両方に対応する。
+pub fn apply_japanese_default_font(
+    japanese_font: Res<JapaneseFont>,
+    mut fonts: ResMut<Assets<Font>>,
+    mut applied: Local<bool>,
+) {
+    if *applied || japanese_font.0.id() == AssetId::default() {
+        return;
+    }
+
+    if let Some(font) = fonts.get(&japanese_font.0).cloned() {
+        let _ = fonts.insert(AssetId::default(), font);
+        *applied = true;
+    }
+}
 
 /// ESCキーで設定メニューを切り替えるシステム
+///
+/// キツネやブロックのアクションメニューが開いている場合は、設定メニューより先にそちらを閉じる。
+/// 設定メニューが閉じていてアイテムを選択中の場合は、選択を解除するだけにする。
+#[allow(clippy::too_many_arguments)]
 pub fn toggle_settings_menu(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut settings_state: ResMut<SettingsMenuState>,
@@ -12,6 +76,12 @@ pub fn toggle_settings_menu(
     mut commands: Commands,
     settings_menu_query: Query<Entity, With<SettingsMenu>>,
     current_settings: Res<CameraSettings>,
+    action_menu_query: Query<Entity, With<FoxActionMenu>>,
+    block_menu_query: Query<Entity, With<BlockActionMenu>>,
+    locale: Res<Locale>,
+    settings_tab: Res<SettingsTab>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Escape) {
         // Possessionモード中の場合は、Possessionモードを終了
@@ -21,10 +91,23 @@ pub fn toggle_settings_menu(
             return;
         }
 
+        // アクションメニューが開いている場合は、それを閉じるだけにする
+        if !action_menu_query.is_empty() || !block_menu_query.is_empty() {
+            for entity in action_menu_query.iter().chain(block_menu_query.iter()) {
+                commands.entity(entity).despawn();
+            }
+            return;
+        }
+
+        if !settings_state.is_open && selected_slot.slot_index.is_some() {
+            deselect_item(&mut selected_slot, &mut feedback_text_query, *locale);
+            return;
+        }
+
         settings_state.is_open = !settings_state.is_open;
 
         if settings_state.is_open {
-            spawn_settings_menu(&mut commands, &current_settings);
+            spawn_settings_menu(&mut commands, &current_settings, *settings_tab, *locale);
         } else {
             for entity in settings_menu_query.iter() {
                 commands.entity(entity).despawn();
@@ -34,37 +117,121 @@ pub fn toggle_settings_menu(
 }
 
 /// 設定ボタンのクリックを処理するシステム
+///
+/// 保存していない変更がある状態で読み込もうとした場合は、確認ダイアログを出して
+/// 「はい」が押された時だけ読み込む。
 pub fn handle_setting_buttons(
     interaction_query: Query<(&Interaction, &SettingButton), Changed<Interaction>>,
     mut settings: ResMut<CameraSettings>,
+    mut locale: ResMut<Locale>,
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+    dialog_query: Query<Entity, With<LoadSettingsDialog>>,
 ) {
     for (interaction, button_type) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
             match button_type {
                 SettingButton::MouseSensitivityUp => {
-                    settings.mouse_sensitivity = (settings.mouse_sensitivity + 0.001).min(0.02);
+                    settings.mouse_sensitivity =
+                        (settings.mouse_sensitivity + 0.001).min(MOUSE_SENSITIVITY_MAX);
                 }
                 SettingButton::MouseSensitivityDown => {
-                    settings.mouse_sensitivity = (settings.mouse_sensitivity - 0.001).max(0.001);
+                    settings.mouse_sensitivity =
+                        (settings.mouse_sensitivity - 0.001).max(MOUSE_SENSITIVITY_MIN);
+                }
+                SettingButton::PossessionSensitivityUp => {
+                    settings.possession_sensitivity =
+                        (settings.possession_sensitivity + 0.001).min(MOUSE_SENSITIVITY_MAX);
+                }
+                SettingButton::PossessionSensitivityDown => {
+                    settings.possession_sensitivity =
+                        (settings.possession_sensitivity - 0.001).max(MOUSE_SENSITIVITY_MIN);
+                }
+                SettingButton::DashFovKickUp => {
+                    settings.dash_fov_kick =
+                        (settings.dash_fov_kick + 2.0).min(DASH_FOV_KICK_MAX_DEGREES);
+                }
+                SettingButton::DashFovKickDown => {
+                    settings.dash_fov_kick = (settings.dash_fov_kick - 2.0).max(0.0);
                 }
                 SettingButton::KeyboardSensitivityUp => {
-                    settings.keyboard_sensitivity = (settings.keyboard_sensitivity + 0.01).min(0.1);
+                    settings.keyboard_sensitivity =
+                        (settings.keyboard_sensitivity + 0.6).min(KEYBOARD_SENSITIVITY_MAX);
                 }
                 SettingButton::KeyboardSensitivityDown => {
                     settings.keyboard_sensitivity =
-                        (settings.keyboard_sensitivity - 0.01).max(0.01);
+                        (settings.keyboard_sensitivity - 0.6).max(KEYBOARD_SENSITIVITY_MIN);
                 }
                 SettingButton::MovementSpeedUp => {
-                    settings.movement_speed = (settings.movement_speed + 5.0).min(100.0);
+                    settings.movement_speed =
+                        (settings.movement_speed + 300.0).min(MOVEMENT_SPEED_MAX);
                 }
                 SettingButton::MovementSpeedDown => {
-                    settings.movement_speed = (settings.movement_speed - 5.0).max(5.0);
+                    settings.movement_speed =
+                        (settings.movement_speed - 300.0).max(MOVEMENT_SPEED_MIN);
                 }
                 SettingButton::ZoomSpeedUp => {
-                    settings.zoom_speed = (settings.zoom_speed + 10.0).min(200.0);
+                    settings.zoom_speed = (settings.zoom_speed + 10.0).min(ZOOM_SPEED_MAX);
                 }
                 SettingButton::ZoomSpeedDown => {
-                    settings.zoom_speed = (settings.zoom_speed - 10.0).max(10.0);
+                    settings.zoom_speed = (settings.zoom_speed - 10.0).max(ZOOM_SPEED_MIN);
+                }
+                SettingButton::GamepadDeadzoneUp => {
+                    settings.gamepad_deadzone =
+                        (settings.gamepad_deadzone + 0.05).min(GAMEPAD_DEADZONE_MAX);
+                }
+                SettingButton::GamepadDeadzoneDown => {
+                    settings.gamepad_deadzone = (settings.gamepad_deadzone - 0.05).max(0.0);
+                }
+                SettingButton::DashTimeoutUp => {
+                    settings.dash_timeout =
+                        (settings.dash_timeout + DASH_TIMEOUT_STEP).min(DASH_TIMEOUT_MAX);
+                }
+                SettingButton::DashTimeoutDown => {
+                    settings.dash_timeout =
+                        (settings.dash_timeout - DASH_TIMEOUT_STEP).max(DASH_TIMEOUT_MIN);
+                }
+                SettingButton::ToggleShadows => {
+                    settings.shadows_enabled = !settings.shadows_enabled;
+                }
+                SettingButton::ShadowResolutionUp => {
+                    settings.shadow_map_size = shadow_map_size_step(settings.shadow_map_size, 1);
+                }
+                SettingButton::ShadowResolutionDown => {
+                    settings.shadow_map_size = shadow_map_size_step(settings.shadow_map_size, -1);
+                }
+                SettingButton::AmbientBrightnessUp => {
+                    settings.ambient_brightness = (settings.ambient_brightness
+                        + AMBIENT_BRIGHTNESS_STEP)
+                        .min(AMBIENT_BRIGHTNESS_MAX);
+                }
+                SettingButton::AmbientBrightnessDown => {
+                    settings.ambient_brightness =
+                        (settings.ambient_brightness - AMBIENT_BRIGHTNESS_STEP).max(0.0);
+                }
+                SettingButton::ToggleManualSun => {
+                    settings.manual_sun = !settings.manual_sun;
+                }
+                // 方位と高さを変えたら手動の向きに切り替える
+                SettingButton::SunAzimuthUp => {
+                    settings.sun_azimuth =
+                        (settings.sun_azimuth + SUN_AZIMUTH_STEP_DEGREES).rem_euclid(360.0);
+                    settings.manual_sun = true;
+                }
+                SettingButton::SunAzimuthDown => {
+                    settings.sun_azimuth =
+                        (settings.sun_azimuth - SUN_AZIMUTH_STEP_DEGREES).rem_euclid(360.0);
+                    settings.manual_sun = true;
+                }
+                SettingButton::SunElevationUp => {
+                    settings.sun_elevation = (settings.sun_elevation + SUN_ELEVATION_STEP_DEGREES)
+                        .min(SUN_ELEVATION_MAX_DEGREES);
+                    settings.manual_sun = true;
+                }
+                SettingButton::SunElevationDown => {
+                    settings.sun_elevation = (settings.sun_elevation - SUN_ELEVATION_STEP_DEGREES)
+                        .max(SUN_ELEVATION_MIN_DEGREES);
+                    settings.manual_sun = true;
                 }
                 SettingButton::SaveSettings => {
                     if let Err(e) = settings.save_to_file() {
@@ -73,56 +240,408 @@ pub fn handle_setting_buttons(
                         println!("Settings saved successfully!");
                     }
                 }
-                SettingButton::LoadSettings => {
+                SettingButton::LoadSettings => match CameraSettings::load_from_file() {
+                    Ok(loaded_settings) if settings.has_unsaved_changes(&loaded_settings) => {
+                        if dialog_query.is_empty()
+                            && let Ok(menu_entity) = settings_menu_query.single()
+                        {
+                            spawn_load_settings_dialog(&mut commands, menu_entity, *locale);
+                        }
+                    }
+                    Ok(loaded_settings) => {
+                        *settings = loaded_settings;
+                        println!("Settings loaded successfully!");
+                    }
+                    Err(_) => eprintln!("Failed to load settings"),
+                },
+                SettingButton::ConfirmLoadSettings => {
                     if let Ok(loaded_settings) = CameraSettings::load_from_file() {
                         *settings = loaded_settings;
                         println!("Settings loaded successfully!");
                     } else {
                         eprintln!("Failed to load settings");
                     }
+                    for entity in dialog_query.iter() {
+                        commands.entity(entity).despawn();
+                    }
+                }
+                SettingButton::CancelLoadSettings => {
+                    for entity in dialog_query.iter() {
+                        commands.entity(entity).despawn();
+                    }
+                }
+                SettingButton::ToggleLanguage => {
+                    locale.toggle();
                 }
             }
         }
     }
 }
 
+/// 設定メニューのタブボタンのクリックを処理するシステム
+pub fn handle_settings_tab_buttons(
+    interaction_query: Query<(&Interaction, &SettingsTabButton), Changed<Interaction>>,
+    mut settings_tab: ResMut<SettingsTab>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed && *settings_tab != button.0 {
+            *settings_tab = button.0;
+        }
+    }
+}
+
+/// 選択中のタブに合わせてセクションの表示とタブボタンの色を切り替えるシステム
+///
+/// 切り替えたタブは先頭から表示するよう、スクロール位置も戻す。
+pub fn update_settings_tab_display(
+    settings_tab: Res<SettingsTab>,
+    mut section_query: Query<(&mut Node, &SettingsSection)>,
+    mut tab_button_query: Query<(&mut BackgroundColor, &SettingsTabButton)>,
+    mut scroll_query: Query<&mut ScrollPosition, With<SettingsScrollArea>>,
+) {
+    if !settings_tab.is_changed() {
+        return;
+    }
+
+    for mut scroll in scroll_query.iter_mut() {
+        scroll.0 = Vec2::ZERO;
+    }
+
+    for (mut node, section) in section_query.iter_mut() {
+        node.display = settings_section_display(section.0, *settings_tab);
+    }
+
+    for (mut background, button) in tab_button_query.iter_mut() {
+        let color = settings_tab_color(button.0, *settings_tab);
+        background.0 = Color::srgb(color.0, color.1, color.2);
+    }
+}
+
+/// カーソルが設定メニューの内容の上にある時、マウスホイールでスクロールするシステム
+///
+/// スクロール量は内容の高さと表示領域の高さの差までに制限する。
+pub fn scroll_settings_menu(
+    mut wheel_events: MessageReader<MouseWheel>,
+    mut scroll_query: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            &bevy::ui::RelativeCursorPosition,
+        ),
+        With<SettingsScrollArea>,
+    >,
+) {
+    let delta: f32 = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * SETTINGS_SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+
+    for (mut scroll, node, cursor) in scroll_query.iter_mut() {
+        if !cursor.cursor_over() {
+            continue;
+        }
+
+        let max_offset = (node.content_size.y - node.size.y).max(0.0) * node.inverse_scale_factor;
+        scroll.0.y = (scroll.0.y - delta).clamp(0.0, max_offset);
+    }
+}
+
 /// 設定値テキストを更新するシステム
 pub fn update_setting_value_texts(
     mut text_query: Query<(&mut Text, &SettingValueText)>,
     settings: Res<CameraSettings>,
+    locale: Res<Locale>,
 ) {
-    if !settings.is_changed() {
+    if !settings.is_changed() && !locale.is_changed() {
         return;
     }
 
     for (mut text, value_type) in text_query.iter_mut() {
-        match value_type {
-            SettingValueText::MouseSensitivity => {
-                text.0 = format!("Mouse Sensitivity: {:.3}", settings.mouse_sensitivity);
-            }
-            SettingValueText::KeyboardSensitivity => {
-                text.0 = format!("Keyboard Sensitivity: {:.2}", settings.keyboard_sensitivity);
-            }
-            SettingValueText::MovementSpeed => {
-                text.0 = format!("Movement Speed: {:.1}", settings.movement_speed);
-            }
-            SettingValueText::ZoomSpeed => {
-                text.0 = format!("Zoom Speed: {:.1}", settings.zoom_speed);
-            }
+        text.0 = setting_value_label(value_type, &settings, *locale);
+    }
+}
+
+/// 所持資源テキストを更新するシステム
+pub fn update_resources_text(
+    mut text_query: Query<&mut Text, With<ResourcesText>>,
+    resources: Res<Resources>,
+    locale: Res<Locale>,
+) {
+    if !resources.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    for mut text in text_query.iter_mut() {
+        text.0 = locale.tf(TextKey::ResourcesLabel, &[&resources.ore, &resources.coins]);
+    }
+}
+
+/// フィードバックメッセージを最後の[`FEEDBACK_FADE_SECS`]秒でフェードアウトさせるシステム
+///
+/// メッセージが書き換わったら経過時間を戻して不透明にし、
+/// [`FEEDBACK_DISPLAY_SECS`]秒経つまでに文字色のアルファを0まで下げる。
+/// 色そのもの（エラー時の赤など）は変えず、アルファだけを書き換える。
+pub fn fade_feedback_text(
+    mut feedback_query: Query<(Ref<Text>, &mut TextColor, &mut ClickFeedbackText)>,
+    time: Res<Time>,
+) {
+    let Ok((feedback_text, mut text_color, mut feedback)) = feedback_query.single_mut() else {
+        return;
+    };
+
+    if feedback_text.is_changed() {
+        feedback.shown_secs = 0.0;
+    } else {
+        feedback.shown_secs += time.delta_secs();
+    }
+
+    let alpha =
+        ((FEEDBACK_DISPLAY_SECS - feedback.shown_secs) / FEEDBACK_FADE_SECS).clamp(0.0, 1.0);
+    if text_color.0.alpha() != alpha {
+        text_color.0.set_alpha(alpha);
+    }
+}
+
+/// 秒数を`mm:ss`形式の時計表記にする
+pub fn format_clock(secs: f32) -> String {
+    let total = secs.max(0.0).ceil() as u32;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
+
+/// 天気アイコンと次の天候変化までのカウントダウンを更新するシステム
+///
+/// 次が雨の時に晴れの残り時間が[`WEATHER_FORECAST_CLOUD_SECS`]を切るとくもりのアイコンにして、
+/// まもなく雨が降ることを知らせる。冬は雨の代わりに雪と表示する。
+/// カウントダウンの前に今の季節と何日目かを表示する。
+pub fn update_weather_display(
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    locale: Res<Locale>,
+    mut icon_query: Query<(&mut BackgroundColor, &mut Node), With<WeatherIcon>>,
+    mut text_query: Query<&mut Text, With<WeatherForecastText>>,
+) {
+    let (name, color, radius) = if weather.is_raining && calendar.season.is_snowy() {
+        (TextKey::WeatherSnowy, WEATHER_ICON_SNOW_COLOR, 4.0)
+    } else if weather.is_raining {
+        (TextKey::WeatherRainy, WEATHER_ICON_RAIN_COLOR, 4.0)
+    } else if weather.next_is_raining && weather.time_until_change <= WEATHER_FORECAST_CLOUD_SECS {
+        (
+            TextKey::WeatherCloudy,
+            WEATHER_ICON_CLOUD_COLOR,
+            WEATHER_ICON_SIZE / 3.0,
+        )
+    } else {
+        (
+            TextKey::WeatherSunny,
+            WEATHER_ICON_SUN_COLOR,
+            WEATHER_ICON_SIZE / 2.0,
+        )
+    };
+
+    for (mut background, mut node) in icon_query.iter_mut() {
+        let color = Color::srgb(color.0, color.1, color.2);
+        if background.0 != color {
+            background.0 = color;
+            node.border_radius = BorderRadius::all(Val::Px(radius));
+        }
+    }
+
+    let season = match calendar.season {
+        Season::Spring => TextKey::SeasonSpring,
+        Season::Summer => TextKey::SeasonSummer,
+        Season::Autumn => TextKey::SeasonAutumn,
+        Season::Winter => TextKey::SeasonWinter,
+    };
+    let label = format!(
+        "{}  {}",
+        locale.tf(
+            TextKey::SeasonDay,
+            &[&locale.t(season), &calendar.day_of_season()]
+        ),
+        locale.tf(
+            TextKey::WeatherForecast,
+            &[&locale.t(name), &format_clock(weather.time_until_change)],
+        )
+    );
+    for mut text in text_query.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+/// 累計プレイ時間のテキストを更新するシステム
+pub fn update_playtime_text(
+    playtime: Res<Playtime>,
+    locale: Res<Locale>,
+    mut text_query: Query<&mut Text, With<PlaytimeText>>,
+) {
+    let label = locale.tf(
+        TextKey::PlaytimeLabel,
+        &[&format_clock(playtime.total_secs as f32)],
+    );
+    for mut text in text_query.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
         }
     }
 }
 
+/// 累計プレイ時間を一定間隔とアプリ終了時にセーブファイルに書き出すシステム
+pub fn save_playtime(
+    playtime: Res<Playtime>,
+    mut app_exit: MessageReader<AppExit>,
+    time: Res<Time>,
+    mut since_last_save: Local<f32>,
+) {
+    *since_last_save += time.delta_secs();
+    let exiting = app_exit.read().count() > 0;
+    if !exiting && *since_last_save < PLAYTIME_SAVE_INTERVAL_SECS {
+        return;
+    }
+    *since_last_save = 0.0;
+
+    if let Err(e) = playtime.save_to_file() {
+        eprintln!("Failed to save playtime: {}", e);
+    }
+}
+
+/// 所持資源が変わったらセーブファイルに書き出すシステム
+pub fn save_resources(resources: Res<Resources>) {
+    if !resources.is_changed() || resources.is_added() {
+        return;
+    }
+
+    if let Err(e) = resources.save_to_file() {
+        eprintln!("Failed to save resources: {}", e);
+    }
+}
+
+/// 設定値テキストの表示文字列を生成する
+fn setting_value_label(
+    value_type: &SettingValueText,
+    settings: &CameraSettings,
+    locale: Locale,
+) -> String {
+    match value_type {
+        SettingValueText::MouseSensitivity => locale.tf(
+            TextKey::MouseSensitivity,
+            &[&format!("{:.3}", settings.mouse_sensitivity)],
+        ),
+        SettingValueText::PossessionSensitivity => locale.tf(
+            TextKey::PossessionSensitivity,
+            &[&format!("{:.3}", settings.possession_sensitivity)],
+        ),
+        SettingValueText::DashFovKick => locale.tf(
+            TextKey::DashFovKick,
+            &[&format!("{:.0}", settings.dash_fov_kick)],
+        ),
+        SettingValueText::KeyboardSensitivity => locale.tf(
+            TextKey::KeyboardSensitivity,
+            &[&format!("{:.1}", settings.keyboard_sensitivity)],
+        ),
+        SettingValueText::MovementSpeed => locale.tf(
+            TextKey::MovementSpeed,
+            &[&format!("{:.0}", settings.movement_speed)],
+        ),
+        SettingValueText::ZoomSpeed => locale.tf(
+            TextKey::ZoomSpeed,
+            &[&format!("{:.1}", settings.zoom_speed)],
+        ),
+        SettingValueText::GamepadDeadzone => locale.tf(
+            TextKey::GamepadDeadzone,
+            &[&format!("{:.2}", settings.gamepad_deadzone)],
+        ),
+        SettingValueText::DashTimeout => locale.tf(
+            TextKey::DashTimeout,
+            &[&format!("{:.2}", settings.dash_timeout)],
+        ),
+        SettingValueText::Shadows => {
+            let state = if settings.shadows_enabled {
+                TextKey::SettingOn
+            } else {
+                TextKey::SettingOff
+            };
+            locale.tf(TextKey::ShadowsLabel, &[&locale.t(state)])
+        }
+        SettingValueText::ShadowResolution => {
+            locale.tf(TextKey::ShadowResolution, &[&settings.shadow_map_size])
+        }
+        SettingValueText::AmbientBrightness => locale.tf(
+            TextKey::AmbientBrightness,
+            &[&format!("{:.0}", settings.ambient_brightness)],
+        ),
+        SettingValueText::ManualSun => {
+            let state = if settings.manual_sun {
+                TextKey::SettingOn
+            } else {
+                TextKey::SettingOff
+            };
+            locale.tf(TextKey::ManualSun, &[&locale.t(state)])
+        }
+        SettingValueText::SunAzimuth => locale.tf(
+            TextKey::SunAzimuth,
+            &[&format!("{:.0}", settings.sun_azimuth)],
+        ),
+        SettingValueText::SunElevation => locale.tf(
+            TextKey::SunElevation,
+            &[&format!("{:.0}", settings.sun_elevation)],
+        ),
+    }
+}
+
+/// 言語が切り替わった時に表示中のUIを新しい言語で作り直すシステム
+///
+/// 設定メニューは開いていれば再生成し、キツネのアクションメニューは閉じる。
+/// 直前のフィードバックメッセージは古い言語のままになるため消去する。
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_localized_ui(
+    locale: Res<Locale>,
+    settings_state: Res<SettingsMenuState>,
+    current_settings: Res<CameraSettings>,
+    settings_tab: Res<SettingsTab>,
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+    action_menu_query: Query<Entity, With<FoxActionMenu>>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+) {
+    if !locale.is_changed() || locale.is_added() {
+        return;
+    }
+
+    for entity in settings_menu_query.iter().chain(action_menu_query.iter()) {
+        commands.entity(entity).despawn();
+    }
+
+    if settings_state.is_open {
+        spawn_settings_menu(&mut commands, &current_settings, *settings_tab, *locale);
+    }
+
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0.clear();
+    }
+}
+
 /// アイテムスロットの表示を更新するシステム
 pub fn update_item_slot_display(
     slot_query: Query<(&ItemSlot, &Children), Changed<ItemSlot>>,
-    mut icon_query: Query<&mut Visibility, With<ItemSlotIcon>>,
+    mut icon_query: Query<(&mut Visibility, &mut ImageNode), With<ItemSlotIcon>>,
+    asset_server: Res<AssetServer>,
 ) {
     for (slot, children) in slot_query.iter() {
         for child in children {
-            if let Ok(mut visibility) = icon_query.get_mut(*child) {
+            if let Ok((mut visibility, mut image)) = icon_query.get_mut(*child) {
                 *visibility = match &slot.item {
-                    Some(ItemType::Fox) => Visibility::Visible,
+                    Some(item) => {
+                        image.image = asset_server.load(item.icon_path());
+                        Visibility::Visible
+                    }
                     None => Visibility::Hidden,
                 };
                 break;
@@ -158,28 +677,68 @@ pub fn update_item_slot_highlight(
     }
 }
 
+/// 右クリックでアイテムの選択を解除するシステム
+pub fn deselect_item_on_right_click(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut selected_slot: ResMut<SelectedItemSlot>,
+    mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
+) {
+    if mouse_input.just_pressed(MouseButton::Right) && selected_slot.slot_index.is_some() {
+        deselect_item(&mut selected_slot, &mut feedback_text_query, *locale);
+    }
+}
+
+/// アイテムの選択を解除し、フィードバックテキストで知らせる
+fn deselect_item(
+    selected_slot: &mut SelectedItemSlot,
+    feedback_text_query: &mut Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Locale,
+) {
+    *selected_slot = SelectedItemSlot::default();
+    if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+        feedback_text.0 = locale.t(TextKey::ItemDeselected).to_string();
+    }
+}
+
 /// アイテムスロットのクリックを処理するシステム
+///
+/// 空のスロットをクリックした場合は、キツネの格納先として選択する。
 pub fn handle_item_slot_click(
     interaction_query: Query<(&Interaction, &ItemSlot), (Changed<Interaction>, With<Button>)>,
     mut selected_slot: ResMut<SelectedItemSlot>,
     mut feedback_text_query: Query<&mut Text, With<ClickFeedbackText>>,
+    locale: Res<Locale>,
 ) {
     for (interaction, slot) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed
-            && let Some(item_type) = &slot.item
-        {
-            selected_slot.slot_index = Some(slot.slot_index);
-            selected_slot.item_type = Some(item_type.clone());
-
-            if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
-                feedback_text.0 = format!("アイテムを選択しました: {:?}", item_type);
-            }
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        selected_slot.slot_index = Some(slot.slot_index);
+        selected_slot.item_type = slot.item.clone();
+
+        if let Ok(mut feedback_text) = feedback_text_query.single_mut() {
+            feedback_text.0 = match &slot.item {
+                Some(item_type) => {
+                    locale.tf(TextKey::ItemSelected, &[&locale.item_name(item_type)])
+                }
+                None => locale.tf(TextKey::EmptySlotSelected, &[&(slot.slot_index + 1)]),
+            };
         }
     }
 }
 
 /// 設定メニューUIをスポーンする関数（マクロを使用してリファクタリング）
-fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
+///
+/// 上部のタブボタンで[`SettingsTab`]を切り替え、選択中のタブのセクションだけを表示する。
+/// 保存/読み込みボタンと言語切り替えはどのタブでも表示する。
+fn spawn_settings_menu(
+    commands: &mut Commands,
+    settings: &CameraSettings,
+    tab: SettingsTab,
+    locale: Locale,
+) {
     commands
         .spawn((
             Node {
@@ -198,7 +757,7 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
                 .spawn((
                     Node {
                         width: Val::Px(600.0),
-                        height: Val::Px(400.0),
+                        height: Val::Px(540.0),
                         flex_direction: FlexDirection::Column,
                         padding: UiRect::all(Val::Px(20.0)),
                         row_gap: Val::Px(15.0),
@@ -209,51 +768,218 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
                 .with_children(|parent| {
                     // タイトル
                     parent.spawn((
-                        Text::new("Settings"),
+                        Text::new(locale.t(TextKey::SettingsTitle)),
                         TextFont { font_size: 32.0, ..default() },
                         TextColor(Color::WHITE),
                     ));
 
-                    // カメラ設定セクション
+                    // タブボタン
+                    parent.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(8.0),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    }).with_children(|tabs| {
+                        for button_tab in SettingsTab::ALL {
+                            let color = settings_tab_color(button_tab, tab);
+                            spawn_button!(tabs, {
+                                size: (128.0, 34.0),
+                                text: locale.t(settings_tab_label(button_tab)),
+                                font_size: 16.0,
+                                bg_color: (color.0, color.1, color.2),
+                                border_color: (0.5, 0.5, 0.5),
+                                component: SettingsTabButton(button_tab),
+                            });
+                        }
+                    });
+
+                    // タブごとのセクション（ホイールでスクロールできる）
                     parent.spawn((
-                        Text::new("Camera Settings"),
-                        TextFont { font_size: 24.0, ..default() },
-                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
-                    ));
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            flex_grow: 1.0,
+                            min_height: Val::Px(0.0),
+                            overflow: Overflow::scroll_y(),
+                            ..default()
+                        },
+                        ScrollPosition::default(),
+                        bevy::ui::RelativeCursorPosition::default(),
+                        SettingsScrollArea,
+                    )).with_children(|parent| {
+                        // カメラ設定セクション
+                        parent.spawn(settings_section(SettingsTab::Camera, tab)).with_children(|parent| {
+                            parent.spawn((
+                                Text::new(locale.t(TextKey::CameraSettingsHeader)),
+                                TextFont { font_size: 24.0, ..default() },
+                                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                            ));
 
-                    // 設定行をマクロで生成
-                    spawn_setting_row!(parent, {
-                        label: format!("Mouse Sensitivity: {:.3}", settings.mouse_sensitivity),
-                        value_type: SettingValueText::MouseSensitivity,
-                        down_button: SettingButton::MouseSensitivityDown,
-                        up_button: SettingButton::MouseSensitivityUp,
-                    });
+                            // 設定行をマクロで生成
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::MouseSensitivity, settings, locale),
+                                value_type: SettingValueText::MouseSensitivity,
+                                down_button: SettingButton::MouseSensitivityDown,
+                                up_button: SettingButton::MouseSensitivityUp,
+                            });
 
-                    spawn_setting_row!(parent, {
-                        label: format!("Keyboard Sensitivity: {:.2}", settings.keyboard_sensitivity),
-                        value_type: SettingValueText::KeyboardSensitivity,
-                        down_button: SettingButton::KeyboardSensitivityDown,
-                        up_button: SettingButton::KeyboardSensitivityUp,
-                    });
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::PossessionSensitivity, settings, locale),
+                                value_type: SettingValueText::PossessionSensitivity,
+                                down_button: SettingButton::PossessionSensitivityDown,
+                                up_button: SettingButton::PossessionSensitivityUp,
+                            });
 
-                    spawn_setting_row!(parent, {
-                        label: format!("Movement Speed: {:.1}", settings.movement_speed),
-                        value_type: SettingValueText::MovementSpeed,
-                        down_button: SettingButton::MovementSpeedDown,
-                        up_button: SettingButton::MovementSpeedUp,
-                    });
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::DashFovKick, settings, locale),
+                                value_type: SettingValueText::DashFovKick,
+                                down_button: SettingButton::DashFovKickDown,
+                                up_button: SettingButton::DashFovKickUp,
+                            });
 
-                    spawn_setting_row!(parent, {
-                        label: format!("Zoom Speed: {:.1}", settings.zoom_speed),
-                        value_type: SettingValueText::ZoomSpeed,
-                        down_button: SettingButton::ZoomSpeedDown,
-                        up_button: SettingButton::ZoomSpeedUp,
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::KeyboardSensitivity, settings, locale),
+                                value_type: SettingValueText::KeyboardSensitivity,
+                                down_button: SettingButton::KeyboardSensitivityDown,
+                                up_button: SettingButton::KeyboardSensitivityUp,
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::MovementSpeed, settings, locale),
+                                value_type: SettingValueText::MovementSpeed,
+                                down_button: SettingButton::MovementSpeedDown,
+                                up_button: SettingButton::MovementSpeedUp,
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::ZoomSpeed, settings, locale),
+                                value_type: SettingValueText::ZoomSpeed,
+                                down_button: SettingButton::ZoomSpeedDown,
+                                up_button: SettingButton::ZoomSpeedUp,
+                            });
+                        });
+
+                        // 操作設定セクション
+                        parent.spawn(settings_section(SettingsTab::Controls, tab)).with_children(|parent| {
+                            parent.spawn((
+                                Text::new(locale.t(TextKey::ControlsHeader)),
+                                TextFont { font_size: 24.0, ..default() },
+                                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                            ));
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::GamepadDeadzone, settings, locale),
+                                value_type: SettingValueText::GamepadDeadzone,
+                                down_button: SettingButton::GamepadDeadzoneDown,
+                                up_button: SettingButton::GamepadDeadzoneUp,
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::DashTimeout, settings, locale),
+                                value_type: SettingValueText::DashTimeout,
+                                down_button: SettingButton::DashTimeoutDown,
+                                up_button: SettingButton::DashTimeoutUp,
+                            });
+
+                            parent.spawn((
+                                Text::new(locale.t(TextKey::ControlsHelp)),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+
+                        // サウンド設定セクション（項目は今後追加する）
+                        parent.spawn(settings_section(SettingsTab::Audio, tab)).with_children(|parent| {
+                            parent.spawn((
+                                Text::new(locale.t(TextKey::NoSettingsInTab)),
+                                TextFont { font_size: 18.0, ..default() },
+                                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                            ));
+                        });
+
+                        // グラフィック設定セクション
+                        parent.spawn(settings_section(SettingsTab::Graphics, tab)).with_children(|parent| {
+                            parent.spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::SpaceBetween,
+                                align_items: AlignItems::Center,
+                                width: Val::Percent(100.0),
+                                ..default()
+                            }).with_children(|row| {
+                                row.spawn((
+                                    Text::new(setting_value_label(&SettingValueText::Shadows, settings, locale)),
+                                    TextFont { font_size: 20.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                    SettingValueText::Shadows,
+                                ));
+
+                                spawn_button!(row, {
+                                    size: (100.0, 30.0),
+                                    text: locale.t(TextKey::ToggleButton),
+                                    font_size: 16.0,
+                                    bg_color: (0.4, 0.4, 0.4),
+                                    border_color: (0.5, 0.5, 0.5),
+                                    component: SettingButton::ToggleShadows,
+                                });
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::ShadowResolution, settings, locale),
+                                value_type: SettingValueText::ShadowResolution,
+                                down_button: SettingButton::ShadowResolutionDown,
+                                up_button: SettingButton::ShadowResolutionUp,
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::AmbientBrightness, settings, locale),
+                                value_type: SettingValueText::AmbientBrightness,
+                                down_button: SettingButton::AmbientBrightnessDown,
+                                up_button: SettingButton::AmbientBrightnessUp,
+                            });
+
+                            parent.spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::SpaceBetween,
+                                align_items: AlignItems::Center,
+                                width: Val::Percent(100.0),
+                                ..default()
+                            }).with_children(|row| {
+                                row.spawn((
+                                    Text::new(setting_value_label(&SettingValueText::ManualSun, settings, locale)),
+                                    TextFont { font_size: 20.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                    SettingValueText::ManualSun,
+                                ));
+
+                                spawn_button!(row, {
+                                    size: (100.0, 30.0),
+                                    text: locale.t(TextKey::ToggleButton),
+                                    font_size: 16.0,
+                                    bg_color: (0.4, 0.4, 0.4),
+                                    border_color: (0.5, 0.5, 0.5),
+                                    component: SettingButton::ToggleManualSun,
+                                });
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::SunAzimuth, settings, locale),
+                                value_type: SettingValueText::SunAzimuth,
+                                down_button: SettingButton::SunAzimuthDown,
+                                up_button: SettingButton::SunAzimuthUp,
+                            });
+
+                            spawn_setting_row!(parent, {
+                                label: setting_value_label(&SettingValueText::SunElevation, settings, locale),
+                                value_type: SettingValueText::SunElevation,
+                                down_button: SettingButton::SunElevationDown,
+                                up_button: SettingButton::SunElevationUp,
+                            });
+                        });
                     });
 
                     // スペーサー
                     parent.spawn(Node { height: Val::Px(20.0), ..default() });
 
-                    // 保存/読み込みボタン
+                    // 保存/読み込み・メインメニューへ戻るボタン
                     parent.spawn(Node {
                         flex_direction: FlexDirection::Row,
                         justify_content: JustifyContent::Center,
@@ -263,7 +989,7 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
                     }).with_children(|buttons| {
                         spawn_button!(buttons, {
                             size: (120.0, 40.0),
-                            text: "Save Settings",
+                            text: locale.t(TextKey::SaveSettings),
                             font_size: 18.0,
                             bg_color: (0.2, 0.6, 0.2),
                             border_color: (0.3, 0.7, 0.3),
@@ -272,27 +998,46 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
 
                         spawn_button!(buttons, {
                             size: (120.0, 40.0),
-                            text: "Load Settings",
+                            text: locale.t(TextKey::LoadSettings),
                             font_size: 18.0,
                             bg_color: (0.2, 0.4, 0.7),
                             border_color: (0.3, 0.5, 0.8),
                             component: SettingButton::LoadSettings,
                         });
-                    });
 
-                    parent.spawn(Node { height: Val::Px(10.0), ..default() });
+                        spawn_button!(buttons, {
+                            size: (160.0, 40.0),
+                            text: locale.t(TextKey::ReturnToMenu),
+                            font_size: 18.0,
+                            bg_color: (0.5, 0.3, 0.2),
+                            border_color: (0.6, 0.4, 0.3),
+                            component: ReturnToMenuButton,
+                        });
+                    });
 
-                    parent.spawn((
-                        Text::new("Controls"),
-                        TextFont { font_size: 24.0, ..default() },
-                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
-                    ));
+                    // 言語切り替え
+                    parent.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        width: Val::Percent(100.0),
+                        ..default()
+                    }).with_children(|row| {
+                        row.spawn((
+                            Text::new(locale.t(TextKey::LanguageLabel)),
+                            TextFont { font_size: 20.0, ..default() },
+                            TextColor(Color::WHITE),
+                        ));
 
-                    parent.spawn((
-                        Text::new("WASD - Move\nArrows - Rotate\nMouse Drag - Rotate\nWheel - Zoom\nESC - Toggle"),
-                        TextFont { font_size: 16.0, ..default() },
-                        TextColor(Color::WHITE),
-                    ));
+                        spawn_button!(row, {
+                            size: (100.0, 30.0),
+                            text: locale.t(TextKey::LanguageButton),
+                            font_size: 16.0,
+                            bg_color: (0.4, 0.4, 0.4),
+                            border_color: (0.5, 0.5, 0.5),
+                            component: SettingButton::ToggleLanguage,
+                        });
+                    });
 
                     parent
                         .spawn(Node {
@@ -304,7 +1049,7 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
                         })
                         .with_children(|close| {
                             close.spawn((
-                                Text::new("Press ESC to close"),
+                                Text::new(locale.t(TextKey::PressEscToClose)),
                                 TextFont { font_size: 16.0, ..default() },
                                 TextColor(Color::srgb(0.6, 0.6, 0.6)),
                             ));
@@ -312,3 +1057,117 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &CameraSettings) {
                 });
         });
 }
+
+/// 設定メニューのタブ1つ分のセクションのノード
+///
+/// 選択中のタブでなければ`Display::None`で隠す。
+fn settings_section(section_tab: SettingsTab, current: SettingsTab) -> impl Bundle {
+    (
+        Node {
+            display: settings_section_display(section_tab, current),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(15.0),
+            width: Val::Percent(100.0),
+            ..default()
+        },
+        SettingsSection(section_tab),
+    )
+}
+
+fn settings_section_display(section_tab: SettingsTab, current: SettingsTab) -> Display {
+    if section_tab == current {
+        Display::Flex
+    } else {
+        Display::None
+    }
+}
+
+/// タブボタンの背景色（選択中のタブは強調する）
+fn settings_tab_color(button_tab: SettingsTab, current: SettingsTab) -> (f32, f32, f32) {
+    if button_tab == current {
+        SETTINGS_TAB_ACTIVE_COLOR
+    } else {
+        SETTINGS_TAB_INACTIVE_COLOR
+    }
+}
+
+/// タブボタンの表示名
+fn settings_tab_label(tab: SettingsTab) -> TextKey {
+    match tab {
+        SettingsTab::Camera => TextKey::SettingsTabCamera,
+        SettingsTab::Controls => TextKey::SettingsTabControls,
+        SettingsTab::Audio => TextKey::SettingsTabAudio,
+        SettingsTab::Graphics => TextKey::SettingsTabGraphics,
+    }
+}
+
+/// 設定の読み込みを確認するダイアログを設定メニューの上に表示する
+fn spawn_load_settings_dialog(commands: &mut Commands, menu_entity: Entity, locale: Locale) {
+    commands.entity(menu_entity).with_children(|parent| {
+        parent
+            .spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                LoadSettingsDialog,
+            ))
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(400.0),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(20.0)),
+                            row_gap: Val::Px(15.0),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                        BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(locale.t(TextKey::ConfirmLoadSettings)),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+
+                        parent
+                            .spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                column_gap: Val::Px(10.0),
+                                ..default()
+                            })
+                            .with_children(|buttons| {
+                                spawn_button!(buttons, {
+                                    size: (120.0, 40.0),
+                                    text: locale.t(TextKey::Yes),
+                                    font_size: 18.0,
+                                    bg_color: (0.2, 0.6, 0.2),
+                                    border_color: (0.3, 0.7, 0.3),
+                                    component: SettingButton::ConfirmLoadSettings,
+                                });
+
+                                spawn_button!(buttons, {
+                                    size: (120.0, 40.0),
+                                    text: locale.t(TextKey::No),
+                                    font_size: 18.0,
+                                    bg_color: (0.4, 0.4, 0.4),
+                                    border_color: (0.5, 0.5, 0.5),
+                                    component: SettingButton::CancelLoadSettings,
+                                });
+                            });
+                    });
+            });
+    });
+}
@@ -0,0 +1,109 @@
+//! 水のブロック
+//!
+//! レベルファイルで`kind: Water`を指定したマスは水のブロックになる。
+//! 憑依中のキツネは水の上では遅くなり、歩くと水面に広がって消える波紋が出る。
+//! 波紋は共有のメッシュと、薄くなる段階ごとの共有マテリアルを使う。
+
+use bevy::prelude::*;
+
+use crate::cf_mesh::field::create_cube_mesh;
+use crate::components::Ripple;
+use crate::constants::*;
+
+/// 水のブロックと波紋の描画に使う共有メッシュとマテリアル
+#[derive(Resource, Clone)]
+pub struct WaterAssets {
+    pub block_mesh: Handle<Mesh>,
+    pub block_material: Handle<StandardMaterial>,
+    pub ripple_mesh: Handle<Mesh>,
+    /// 波紋の濃い順のマテリアル（[`RIPPLE_FADE_STEPS`]段階）
+    pub ripple_materials: Vec<Handle<StandardMaterial>>,
+}
+
+impl FromWorld for WaterAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let block_mesh = meshes.add(create_cube_mesh(BLOCK_HALF_SIZE));
+        let ripple_mesh = meshes.add(Annulus::new(RIPPLE_RADIUS - RIPPLE_WIDTH, RIPPLE_RADIUS));
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let (r, g, b, a) = WATER_BLOCK_COLOR;
+        let block_material = materials.add(StandardMaterial {
+            base_color: Color::srgba(r, g, b, a),
+            alpha_mode: AlphaMode::Blend,
+            perceptual_roughness: 0.1,
+            reflectance: 0.6,
+            ..default()
+        });
+        let (r, g, b, a) = RIPPLE_COLOR;
+        let ripple_materials = (0..RIPPLE_FADE_STEPS)
+            .map(|step| {
+                let alpha = a * (1.0 - step as f32 / RIPPLE_FADE_STEPS as f32);
+                materials.add(StandardMaterial {
+                    base_color: Color::srgba(r, g, b, alpha),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                })
+            })
+            .collect();
+
+        Self {
+            block_mesh,
+            block_material,
+            ripple_mesh,
+            ripple_materials,
+        }
+    }
+}
+
+impl WaterAssets {
+    /// 波紋の経過時間に対応する薄さの段階のマテリアルを返す
+    fn ripple_material_for(&self, age: f32) -> &Handle<StandardMaterial> {
+        let t = (age / RIPPLE_LIFETIME).clamp(0.0, 1.0);
+        let step = ((t * self.ripple_materials.len() as f32) as usize)
+            .min(self.ripple_materials.len() - 1);
+        &self.ripple_materials[step]
+    }
+}
+
+/// 水面の`position`に波紋を1つ出す
+pub fn spawn_ripple(commands: &mut Commands, assets: &WaterAssets, position: Vec3) {
+    commands.spawn((
+        Mesh3d(assets.ripple_mesh.clone()),
+        MeshMaterial3d(assets.ripple_material_for(0.0).clone()),
+        // 波紋のメッシュはXY平面にあるため、水面に寝かせる
+        Transform::from_translation(position)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        Pickable::IGNORE,
+        Ripple { age: 0.0 },
+    ));
+}
+
+/// 波紋を広げながら薄くし、寿命が来たら消すシステム
+pub fn update_ripples(
+    mut commands: Commands,
+    mut ripple_query: Query<(
+        Entity,
+        &mut Transform,
+        &mut MeshMaterial3d<StandardMaterial>,
+        &mut Ripple,
+    )>,
+    assets: Res<WaterAssets>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut material, mut ripple) in ripple_query.iter_mut() {
+        ripple.age += time.delta_secs();
+        if ripple.age >= RIPPLE_LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let t = ripple.age / RIPPLE_LIFETIME;
+        transform.scale = Vec3::splat(1.0 + t * (RIPPLE_MAX_SCALE - 1.0));
+        let target = assets.ripple_material_for(ripple.age);
+        if material.0 != *target {
+            material.0 = target.clone();
+        }
+    }
+}
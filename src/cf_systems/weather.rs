@@ -1,36 +1,35 @@
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::components::{RainDrop, SunLight};
+use crate::cf_systems::camera::main_camera;
+use crate::cf_systems::items::camera_focus_point;
+use crate::components::{MainCamera, RainDrop, RainSplash, Rock, SunLight};
 use crate::constants::*;
-use crate::resources::WeatherState;
+use crate::resources::{
+    Calendar, FieldConfig, GameRng, RainArea, RainConfig, RainStressMode, WeatherConfig,
+    WeatherSave, WeatherState,
+};
+use crate::traits::JsonSave;
 
 /// 天候状態を更新するシステム
+///
+/// 切り替わる時は抽選済みの次の天候にして、その次の天候と続く時間を
+/// [`WeatherConfig`]の重みと時間の範囲で抽選する。雨の重みは今の季節に合わせて変える。
+/// 雨の強さは天候の切り替わりに合わせて徐々に変え、太陽光の明るさもそれに合わせる。
 pub fn update_weather(
     mut weather: ResMut<WeatherState>,
+    weather_config: Res<WeatherConfig>,
     time: Res<Time>,
     mut sun_query: Query<&mut DirectionalLight, With<SunLight>>,
+    mut rng: ResMut<GameRng>,
+    calendar: Res<Calendar>,
 ) {
-    let mut rng = rand::rng();
-
     weather.time_until_change -= time.delta_secs();
 
     if weather.time_until_change <= 0.0 {
-        weather.is_raining = !weather.is_raining;
-
-        weather.time_until_change = if weather.is_raining {
-            rng.random_range(WEATHER_RAIN_DURATION_MIN..WEATHER_RAIN_DURATION_MAX)
-        } else {
-            rng.random_range(WEATHER_CLEAR_DURATION_MIN..WEATHER_CLEAR_DURATION_MAX)
-        };
-
-        if let Ok(mut sun_light) = sun_query.single_mut() {
-            sun_light.illuminance = if weather.is_raining {
-                SUN_ILLUMINANCE_RAIN
-            } else {
-                SUN_ILLUMINANCE_CLEAR
-            };
-        }
+        weather.is_raining = weather.next_is_raining;
+        weather.next_is_raining = weather_config.sample_is_raining(calendar.season, &mut rng.0);
+        weather.time_until_change = weather_config.sample_duration(weather.is_raining, &mut rng.0);
 
         println!(
             "天候変化: {} (次の変化まで: {:.1}秒)",
@@ -38,61 +37,439 @@ pub fn update_weather(
             weather.time_until_change
         );
     }
+
+    let target = if weather.is_raining { 1.0 } else { 0.0 };
+    if weather.intensity != target {
+        let step = time.delta_secs() / WEATHER_RAIN_RAMP_SECS;
+        weather.intensity = if weather.intensity < target {
+            (weather.intensity + step).min(target)
+        } else {
+            (weather.intensity - step).max(target)
+        };
+
+        if let Ok(mut sun_light) = sun_query.single_mut() {
+            sun_light.illuminance = weather.sun_illuminance();
+        }
+    }
 }
 
 /// 雨粒を生成するシステム
+///
+/// 生成レートは雨の強さに比例させ、1フレームに満たない端数は次のフレームに持ち越す。
+/// ストレステストモード中は天候に関係なく、倍率を掛けたレートで生成する。
+/// 雨粒は共有のメッシュとマテリアル（[`RainDropAssets`]）を使う。
+/// 冬は雨粒の代わりに、ゆっくり落ちる雪片を降らせる。
+/// 降らせる範囲は[`RainArea`]に従う（[`rain_area_rect`]）。
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_rain(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    rain_assets: Res<RainDropAssets>,
     time: Res<Time>,
     weather: Res<WeatherState>,
+    field_config: Res<FieldConfig>,
+    rain_area: Res<RainArea>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    mut rng: ResMut<GameRng>,
+    stress_mode: Res<RainStressMode>,
+    calendar: Res<Calendar>,
+    mut pending_drops: Local<f32>,
 ) {
-    if !weather.is_raining {
+    let intensity = if stress_mode.enabled {
+        1.0
+    } else {
+        weather.intensity
+    };
+    if intensity <= 0.0 {
+        *pending_drops = 0.0;
         return;
     }
 
-    let mut rng = rand::rng();
-    let drops_to_spawn = (RAIN_SPAWN_RATE * time.delta_secs()) as i32;
-    let field_size = FIELD_SIZE as f32 * BLOCK_SIZE;
+    *pending_drops += stress_mode.spawn_rate() * intensity * time.delta_secs();
+    let drops_to_spawn = *pending_drops as i32;
+    *pending_drops -= drops_to_spawn as f32;
+    let spawn_rect = rain_area_rect(
+        *rain_area,
+        &field_config,
+        main_camera(camera_query.single()),
+    );
+    let is_snow = calendar.season.is_snowy();
+    let (mesh, material, fall_velocity, lifetime) = if is_snow {
+        (
+            &rain_assets.snow_mesh,
+            &rain_assets.snow_material,
+            SNOW_FALL_VELOCITY,
+            SNOW_LIFETIME,
+        )
+    } else {
+        (
+            &rain_assets.mesh,
+            &rain_assets.material,
+            RAIN_FALL_VELOCITY,
+            RAIN_LIFETIME,
+        )
+    };
 
     for _ in 0..drops_to_spawn {
-        let x = rng.random_range(-field_size / 2.0..field_size / 2.0);
-        let z = rng.random_range(-field_size / 2.0..field_size / 2.0);
+        let x = rng.0.random_range(spawn_rect.min.x..spawn_rect.max.x);
+        let z = rng.0.random_range(spawn_rect.min.y..spawn_rect.max.y);
+
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(x, RAIN_SPAWN_HEIGHT, z),
+            Pickable::IGNORE,
+            RainDrop {
+                velocity: Vec3::new(0.0, fall_velocity, 0.0),
+                lifetime,
+                is_snow,
+            },
+        ));
+    }
+}
+
+/// 雨粒を降らせる範囲（XZ平面）を計算する
+///
+/// カメラ追従の場合はカメラの注視点（画面外ならカメラの真下）を中心にする。
+/// カメラがなければフィールドの範囲を使う。
+fn rain_area_rect(
+    rain_area: RainArea,
+    field_config: &FieldConfig,
+    camera: Option<&Transform>,
+) -> Rect {
+    match (rain_area, camera) {
+        (RainArea::Camera, Some(camera_transform)) => {
+            let center =
+                camera_focus_point(camera_transform).unwrap_or(camera_transform.translation);
+            Rect::from_center_half_size(center.xz(), Vec2::splat(RAIN_CAMERA_AREA_HALF_EXTENT))
+        }
+        _ => field_config.field_rect(),
+    }
+}
+
+/// 雨粒の描画に使う共有メッシュとマテリアル
+///
+/// マテリアルは全ての雨粒で1つなので、[`update_rain_color`]が
+/// 1回書き換えるだけで降っている雨粒全ての色が変わる。
+/// 雨粒の太さ・長さ・色は[`RainConfig`]に従い、設定が変わると[`apply_rain_config`]が作り直す。
+/// 冬に降らせる雪片のメッシュとマテリアルも持つ。
+#[derive(Resource)]
+pub struct RainDropAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+    pub snow_mesh: Handle<Mesh>,
+    pub snow_material: Handle<StandardMaterial>,
+}
 
-        let rain_material = materials.add(StandardMaterial {
-            base_color: Color::srgba(RAIN_COLOR.0, RAIN_COLOR.1, RAIN_COLOR.2, RAIN_COLOR.3),
+impl FromWorld for RainDropAssets {
+    fn from_world(world: &mut World) -> Self {
+        let config = world
+            .get_resource::<RainConfig>()
+            .cloned()
+            .unwrap_or_default();
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let mesh = meshes.add(rain_drop_mesh(&config));
+        let snow_mesh = meshes.add(Sphere::new(SNOW_FLAKE_RADIUS));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let material = materials.add(StandardMaterial {
+            base_color: rain_color(&config, 1.0),
             alpha_mode: AlphaMode::Blend,
             unlit: true,
             ..default()
         });
+        let snow_material = materials.add(StandardMaterial {
+            base_color: Color::srgba(SNOW_COLOR.0, SNOW_COLOR.1, SNOW_COLOR.2, SNOW_COLOR.3),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        Self {
+            mesh,
+            material,
+            snow_mesh,
+            snow_material,
+        }
+    }
+}
 
-        let rain_mesh = meshes.add(Capsule3d::new(RAIN_CAPSULE_RADIUS, RAIN_CAPSULE_HEIGHT));
+/// 雨粒の設定に合わせたカプセルのメッシュ
+fn rain_drop_mesh(config: &RainConfig) -> Mesh {
+    Capsule3d::new(config.capsule_radius, config.capsule_height).into()
+}
 
-        commands.spawn((
-            Mesh3d(rain_mesh),
-            MeshMaterial3d(rain_material),
-            Transform::from_xyz(x, RAIN_SPAWN_HEIGHT, z),
-            RainDrop {
-                velocity: Vec3::new(0.0, RAIN_FALL_VELOCITY, 0.0),
-                lifetime: RAIN_LIFETIME,
-            },
-        ));
+/// 雨の強さに応じた雨粒の色
+///
+/// 小雨（強さ0）の[`RainConfig::drizzle_color`]から本降り（強さ1）の[`RainConfig::color`]へ補間し、
+/// 弱い雨ほど薄く、強い雨ほど濃く青く見せる。
+fn rain_color(config: &RainConfig, intensity: f32) -> Color {
+    let drizzle = Vec4::new(
+        config.drizzle_color.0,
+        config.drizzle_color.1,
+        config.drizzle_color.2,
+        config.drizzle_color.3,
+    );
+    let heavy = Vec4::new(
+        config.color.0,
+        config.color.1,
+        config.color.2,
+        config.color.3,
+    );
+    let color = drizzle.lerp(heavy, intensity.clamp(0.0, 1.0));
+    Color::srgba(color.x, color.y, color.z, color.w)
+}
+
+/// 雨の強さに合わせて、共有の雨粒のマテリアルの色を変えるシステム
+///
+/// ストレステストモード中は本降りの色にする。
+/// 色が変わらないフレームはマテリアルに触れない。
+pub fn update_rain_color(
+    weather: Res<WeatherState>,
+    stress_mode: Res<RainStressMode>,
+    rain_config: Res<RainConfig>,
+    rain_assets: Res<RainDropAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let intensity = if stress_mode.enabled {
+        1.0
+    } else {
+        weather.intensity
+    };
+    let color = rain_color(&rain_config, intensity);
+
+    let unchanged = materials
+        .get(&rain_assets.material)
+        .is_none_or(|material| material.base_color == color);
+    if unchanged {
+        return;
+    }
+    if let Some(material) = materials.get_mut(&rain_assets.material) {
+        material.base_color = color;
     }
 }
 
+/// [`RainConfig`]が変わった時に、共有の雨粒のメッシュと水しぶきの色を作り直すシステム
+///
+/// 雨粒の色は[`update_rain_color`]が次のフレームで設定に合わせる。
+pub fn apply_rain_config(
+    rain_config: Res<RainConfig>,
+    rain_assets: Res<RainDropAssets>,
+    splash_assets: Res<RainSplashAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !rain_config.is_changed() || rain_config.is_added() {
+        return;
+    }
+
+    if let Some(mesh) = meshes.get_mut(&rain_assets.mesh) {
+        *mesh = rain_drop_mesh(&rain_config);
+    }
+    if let Some(material) = materials.get_mut(&splash_assets.material) {
+        let color = rain_config.color;
+        material.base_color = Color::srgba(color.0, color.1, color.2, color.3);
+    }
+}
+
+/// 水しぶきの描画に使う共有メッシュとマテリアル
+#[derive(Resource)]
+pub struct RainSplashAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for RainSplashAssets {
+    fn from_world(world: &mut World) -> Self {
+        let color = world
+            .get_resource::<RainConfig>()
+            .map_or(RAIN_COLOR, |config| config.color);
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Sphere::new(RAIN_SPLASH_RADIUS));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::srgba(color.0, color.1, color.2, color.3),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            });
+        Self { mesh, material }
+    }
+}
+
+/// 指定した位置に水しぶきを1つ出す
+pub fn spawn_rain_splash(
+    commands: &mut Commands,
+    splash_assets: &RainSplashAssets,
+    position: Vec3,
+) {
+    commands.spawn((
+        Mesh3d(splash_assets.mesh.clone()),
+        MeshMaterial3d(splash_assets.material.clone()),
+        Transform::from_translation(position),
+        Pickable::IGNORE,
+        RainSplash {
+            lifetime: RAIN_SPLASH_LIFETIME,
+        },
+    ));
+}
+
 /// 雨粒を更新するシステム
+///
+/// 雨粒の真下のマスと岩だけを調べ、ブロックの上面か岩の表面に達したら水しぶきを出して消す。
+/// 雨を降らせる範囲から[`RAIN_CULL_MARGIN`]以上外れた雨粒は、地面に届くのを待たずに消す。
+/// フィールドの外で地面に届いた雨粒と雪片は水しぶきを出さない。
+#[allow(clippy::too_many_arguments)]
 pub fn update_rain(
     mut commands: Commands,
-    mut rain_query: Query<(Entity, &mut Transform, &mut RainDrop)>,
+    mut rain_query: Query<(Entity, &mut Transform, &mut RainDrop), Without<MainCamera>>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    field_config: Res<FieldConfig>,
+    rain_area: Res<RainArea>,
+    splash_assets: Res<RainSplashAssets>,
+    rock_query: Query<&GlobalTransform, With<Rock>>,
     time: Res<Time>,
 ) {
+    let field_rect = field_config.field_rect();
+    let rocks: Vec<Vec3> = rock_query
+        .iter()
+        .map(|transform| transform.translation())
+        .collect();
+    let cull_rect = rain_area_rect(
+        *rain_area,
+        &field_config,
+        main_camera(camera_query.single()),
+    )
+    .inflate(RAIN_CULL_MARGIN);
+
     for (entity, mut transform, mut raindrop) in rain_query.iter_mut() {
         transform.translation += raindrop.velocity * time.delta_secs();
         raindrop.lifetime -= time.delta_secs();
 
-        if transform.translation.y < 0.0 || raindrop.lifetime <= 0.0 {
+        let position_xz = transform.translation.xz();
+        if !cull_rect.contains(position_xz) {
             commands.entity(entity).despawn();
+            continue;
         }
+
+        let surface_y = field_config.surface_height(transform.translation, &rocks);
+
+        if transform.translation.y <= surface_y {
+            commands.entity(entity).despawn();
+            if raindrop.is_snow || !field_rect.contains(position_xz) {
+                continue;
+            }
+            spawn_rain_splash(
+                &mut commands,
+                &splash_assets,
+                transform.translation.with_y(surface_y),
+            );
+        } else if raindrop.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// 水しぶきを横に広げながら消すシステム
+pub fn update_rain_splashes(
+    mut commands: Commands,
+    mut splash_query: Query<(Entity, &mut Transform, &mut RainSplash)>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut splash) in splash_query.iter_mut() {
+        splash.lifetime -= time.delta_secs();
+        if splash.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let progress = 1.0 - splash.lifetime / RAIN_SPLASH_LIFETIME;
+        let spread = 1.0 + progress * (RAIN_SPLASH_GROWTH - 1.0);
+        transform.scale = Vec3::new(spread, 1.0 - progress, spread);
+    }
+}
+
+/// F9キーで雨のストレステストモードを切り替えるシステム
+pub fn toggle_rain_stress_mode(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut stress_mode: ResMut<RainStressMode>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F9) {
+        stress_mode.toggle();
+        println!(
+            "雨のストレステストモード: {}",
+            if stress_mode.enabled { "ON" } else { "OFF" }
+        );
+    }
+}
+
+/// F8キーで雨を降らせる範囲をフィールド固定とカメラ追従で切り替えるシステム
+pub fn toggle_rain_area(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut rain_area: ResMut<RainArea>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F8) {
+        rain_area.toggle();
+        println!(
+            "雨を降らせる範囲: {}",
+            match *rain_area {
+                RainArea::Field => "フィールド",
+                RainArea::Camera => "カメラ追従",
+            }
+        );
+    }
+}
+
+/// ストレステストモード中、一定間隔で平均FPSと雨粒の数をログに出力するシステム
+pub fn report_rain_stress(
+    mut stress_mode: ResMut<RainStressMode>,
+    rain_query: Query<(), With<RainDrop>>,
+    time: Res<Time>,
+) {
+    if !stress_mode.enabled {
+        return;
+    }
+
+    stress_mode.frame_count += 1;
+    stress_mode.elapsed += time.delta_secs();
+    stress_mode.time_until_report -= time.delta_secs();
+
+    if stress_mode.time_until_report > 0.0 {
+        return;
+    }
+
+    let fps = stress_mode.frame_count as f32 / stress_mode.elapsed;
+    println!(
+        "雨ストレステスト: {:.1} FPS (平均フレーム時間 {:.2}ms), 雨粒数 {}",
+        fps,
+        1000.0 / fps,
+        rain_query.iter().count()
+    );
+
+    stress_mode.frame_count = 0;
+    stress_mode.elapsed = 0.0;
+    stress_mode.time_until_report = RAIN_STRESS_REPORT_INTERVAL;
+}
+
+/// 天候と日付を一定間隔とアプリ終了時にセーブファイルに書き出すシステム
+pub fn save_weather(
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    mut app_exit: MessageReader<AppExit>,
+    time: Res<Time>,
+    mut since_last_save: Local<f32>,
+) {
+    *since_last_save += time.delta_secs();
+    let exiting = app_exit.read().count() > 0;
+    if !exiting && *since_last_save < WEATHER_SAVE_INTERVAL_SECS {
+        return;
+    }
+    *since_last_save = 0.0;
+
+    let save = WeatherSave {
+        weather: Some(weather.clone()),
+        calendar: Some(calendar.clone()),
+    };
+    if let Err(e) = save.save_to_file() {
+        eprintln!("Failed to save weather: {}", e);
     }
 }
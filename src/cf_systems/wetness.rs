@@ -0,0 +1,223 @@
+//! 雨に濡れたブロックのつや
+//!
+//! 雨が続くと[`Wetness`]が上がり、しきい値を超えるとブロックのマテリアルを
+//! 粗さを下げた「濡れた」マテリアルに差し替える。乾くと元のマテリアルに戻す。
+//! 濡れたマテリアルは元のマテリアルごとに一度だけ作り、以降は差し替えるだけにする。
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::components::{Block, BlockTimerTint};
+use crate::constants::*;
+use crate::resources::{Calendar, WeatherState, Wetness};
+
+/// ブロックの乾いたマテリアルと濡れたマテリアルの対応表
+#[derive(Resource, Default)]
+pub struct BlockWetMaterials {
+    /// 乾いたマテリアルから濡れたマテリアルへの対応
+    wet_by_dry: HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>,
+    /// 濡れたマテリアルから乾いたマテリアルへの対応
+    dry_by_wet: HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>,
+}
+
+impl BlockWetMaterials {
+    /// 乾いたマテリアルに対応する濡れたマテリアルを取得する（初回のみ作成する）
+    fn wet_for(
+        &mut self,
+        dry: &Handle<StandardMaterial>,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Option<Handle<StandardMaterial>> {
+        if let Some(wet) = self.wet_by_dry.get(&dry.id()) {
+            return Some(wet.clone());
+        }
+
+        let wet_material = wet_variant(materials.get(dry)?);
+        let wet = materials.add(wet_material);
+        self.wet_by_dry.insert(dry.id(), wet.clone());
+        self.dry_by_wet.insert(wet.id(), dry.clone());
+        Some(wet)
+    }
+
+    /// 乾いたマテリアルを書き換えた後に、作成済みの濡れたマテリアルを作り直す
+    ///
+    /// 季節の色味のように元のマテリアルが変わっても、濡れた見た目がずれないようにする。
+    pub fn refresh_wet(
+        &self,
+        dry: &Handle<StandardMaterial>,
+        materials: &mut Assets<StandardMaterial>,
+    ) {
+        let Some(wet) = self.wet_by_dry.get(&dry.id()) else {
+            return;
+        };
+        let Some(wet_material) = materials.get(dry).map(wet_variant) else {
+            return;
+        };
+        if let Some(material) = materials.get_mut(wet) {
+            *material = wet_material;
+        }
+    }
+
+    /// ブロックのメッシュに付けるべきマテリアルを返す（差し替え不要なら`None`）
+    fn swap_target(
+        &mut self,
+        current: &Handle<StandardMaterial>,
+        wet: bool,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Option<Handle<StandardMaterial>> {
+        let is_wet = self.dry_by_wet.contains_key(&current.id());
+        match (wet, is_wet) {
+            (true, false) => self.wet_for(current, materials),
+            (false, true) => self.dry_by_wet.get(&current.id()).cloned(),
+            _ => None,
+        }
+    }
+}
+
+/// 乾いたマテリアルから、粗さを下げて色を暗くした濡れたマテリアルを作る
+fn wet_variant(dry: &StandardMaterial) -> StandardMaterial {
+    let mut wet_material = dry.clone();
+    wet_material.perceptual_roughness = WET_BLOCK_ROUGHNESS;
+    wet_material.reflectance = WET_BLOCK_REFLECTANCE;
+    let base = wet_material.base_color.to_srgba();
+    wet_material.base_color = Color::srgba(
+        base.red * WET_BLOCK_DARKEN,
+        base.green * WET_BLOCK_DARKEN,
+        base.blue * WET_BLOCK_DARKEN,
+        base.alpha,
+    );
+    wet_material
+}
+
+/// 天候に応じてブロックの濡れ具合を上げ下げするシステム
+///
+/// 雪はブロックを濡らさないため、冬は降っていても乾いていく。
+pub fn update_wetness(
+    weather: Res<WeatherState>,
+    calendar: Res<Calendar>,
+    mut wetness: ResMut<Wetness>,
+    time: Res<Time>,
+) {
+    let delta = if weather.is_raining && !calendar.season.is_snowy() {
+        time.delta_secs() / WETNESS_SOAK_SECS
+    } else {
+        -time.delta_secs() / WETNESS_DRY_SECS
+    };
+    let level = (wetness.level + delta).clamp(0.0, 1.0);
+    if wetness.level != level {
+        wetness.level = level;
+    }
+}
+
+/// 濡れ具合がしきい値をまたいだ時に、ブロックのマテリアルを差し替えるシステム
+///
+/// しきい値をまたいだ時は全ブロックを、それ以外はシーンの読み込みで
+/// 新しく現れたブロックのメッシュだけを対象にする。
+/// メッシュを直接持つブロック（石のブロックや[`FieldConfig::batched`]の草のブロック）は
+/// ブロック自体も対象にする。
+#[allow(clippy::too_many_arguments)]
+pub fn apply_block_wetness(
+    wetness: Res<Wetness>,
+    mut was_wet: Local<bool>,
+    block_query: Query<Entity, With<Block>>,
+    children_query: Query<&Children>,
+    parent_query: Query<&ChildOf>,
+    mut material_query: Query<
+        (Entity, &mut MeshMaterial3d<StandardMaterial>),
+        Without<BlockTimerTint>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut wet_materials: ResMut<BlockWetMaterials>,
+) {
+    let wet = wetness.level >= WETNESS_SHEEN_THRESHOLD;
+
+    let targets: Vec<Entity> = if wet != *was_wet {
+        *was_wet = wet;
+        block_query
+            .iter()
+            .flat_map(|block| std::iter::once(block).chain(children_query.iter_descendants(block)))
+            .collect()
+    } else {
+        // 同じマテリアルを書き換えるクエリと分けると競合するため、追加されたかは同じクエリで見る
+        material_query
+            .iter_mut()
+            .filter(|(entity, material)| {
+                material.is_added()
+                    && (block_query.contains(*entity)
+                        || parent_query
+                            .iter_ancestors(*entity)
+                            .any(|ancestor| block_query.contains(ancestor)))
+            })
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+
+    for entity in targets {
+        let Ok((_, mut material)) = material_query.get_mut(entity) else {
+            continue;
+        };
+        if let Some(target) = wet_materials.swap_target(&material.0, wet, &mut materials) {
+            material.0 = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 乾いたマテリアルを共有するブロックを並べ、マテリアルの差し替えだけを動かすアプリを作る
+    fn app_with_blocks(block_count: usize) -> (App, Handle<StandardMaterial>) {
+        let mut app = App::new();
+        app.init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<Wetness>()
+            .init_resource::<BlockWetMaterials>()
+            .add_systems(Update, apply_block_wetness);
+
+        let dry = app
+            .world_mut()
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial::default());
+        for _ in 0..block_count {
+            app.world_mut().spawn((Block, MeshMaterial3d(dry.clone())));
+        }
+        (app, dry)
+    }
+
+    fn material_count(app: &App) -> usize {
+        app.world().resource::<Assets<StandardMaterial>>().len()
+    }
+
+    #[test]
+    fn repeated_rain_reuses_one_wet_material() {
+        let (mut app, dry) = app_with_blocks(4);
+        app.update();
+        assert_eq!(material_count(&app), 1);
+
+        // 濡れる・乾くを何度繰り返しても、濡れたマテリアルは最初の1つを使い回す
+        for frame in 0..8 {
+            let level = if frame % 2 == 0 { 1.0 } else { 0.0 };
+            app.world_mut().resource_mut::<Wetness>().level = level;
+            app.update();
+            assert_eq!(material_count(&app), 2, "frame {frame}");
+        }
+
+        // 最後は乾いた状態なので、全ブロックが元のマテリアルに戻っている
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&MeshMaterial3d<StandardMaterial>, With<Block>>();
+        assert!(query.iter(app.world()).all(|material| material.0 == dry));
+    }
+
+    #[test]
+    fn staying_wet_does_not_add_materials() {
+        let (mut app, _) = app_with_blocks(4);
+        app.world_mut().resource_mut::<Wetness>().level = 1.0;
+        app.update();
+        let count = material_count(&app);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(material_count(&app), count);
+    }
+}
@@ -0,0 +1,88 @@
+//! ウィンドウサイズとフルスクリーン状態の保存
+//!
+//! 起動時は設定ファイルのサイズ・表示モードでウィンドウを開き、
+//! F11でフルスクリーンを切り替える。ウィンドウの状態は切り替え時と終了時に
+//! 設定ファイルへ書き戻す。
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode, WindowResized};
+
+use crate::resources::CameraSettings;
+
+/// 設定からプライマリウィンドウを作る
+pub fn primary_window(settings: &CameraSettings) -> Window {
+    Window {
+        resolution: (settings.window_width, settings.window_height).into(),
+        mode: window_mode(settings.fullscreen),
+        ..default()
+    }
+}
+
+/// フルスクリーンかどうかからウィンドウの表示モードを決める
+fn window_mode(fullscreen: bool) -> WindowMode {
+    if fullscreen {
+        WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+    } else {
+        WindowMode::Windowed
+    }
+}
+
+/// F11キーでフルスクリーンとウィンドウ表示を切り替えるシステム
+pub fn toggle_fullscreen(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mut settings: ResMut<CameraSettings>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F11) {
+        return;
+    }
+
+    let Ok(mut window) = window_query.single_mut() else {
+        return;
+    };
+
+    settings.fullscreen = !settings.fullscreen;
+    window.mode = window_mode(settings.fullscreen);
+
+    if let Err(e) = settings.save_window_to_file() {
+        eprintln!("Failed to save window settings: {}", e);
+    }
+}
+
+/// ウィンドウ表示中にサイズが変わったら設定に記録するシステム
+///
+/// フルスクリーン中のサイズは画面の大きさなので記録しない。
+pub fn track_window_size(
+    mut resized_events: MessageReader<WindowResized>,
+    window_query: Query<(), With<PrimaryWindow>>,
+    mut settings: ResMut<CameraSettings>,
+) {
+    let Some(resized) = resized_events
+        .read()
+        .filter(|event| window_query.contains(event.window))
+        .last()
+    else {
+        return;
+    };
+    if settings.fullscreen {
+        return;
+    }
+
+    let width = resized.width.round() as u32;
+    let height = resized.height.round() as u32;
+    if settings.window_width != width || settings.window_height != height {
+        settings.window_width = width;
+        settings.window_height = height;
+    }
+}
+
+/// アプリ終了時にウィンドウの状態を設定ファイルに書き戻すシステム
+pub fn save_window_settings(settings: Res<CameraSettings>, mut app_exit: MessageReader<AppExit>) {
+    if app_exit.read().count() == 0 {
+        return;
+    }
+
+    if let Err(e) = settings.save_window_to_file() {
+        eprintln!("Failed to save window settings: {}", e);
+    }
+}
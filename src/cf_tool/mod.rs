@@ -1 +1 @@
-pub mod timer;
\ No newline at end of file
+pub mod timer;
@@ -1,23 +1,48 @@
 use bevy::prelude::*;
 
+use crate::components::Block;
+use crate::constants::*;
+use crate::events::TimerThresholdReached;
+use crate::locale::{Locale, TextKey};
+use crate::resources::Playtime;
+
 // Generic component to track object's internal timer
 #[derive(Component)]
 pub struct Timer {
     pub time: f32,
     pub name: String,
+    // Seconds at which TimerThresholdReached is sent (None = never).
+    // Resetting `time` to 0 re-arms it.
+    pub threshold: Option<f32>,
 }
 
-// System to update all timers
-pub fn update_timers(time: Res<Time>, mut timer_query: Query<&mut Timer>) {
-    for mut timer in timer_query.iter_mut() {
+// System to update all timers (including the global playtime)
+// and notify timers that crossed their threshold this frame
+pub fn update_timers(
+    time: Res<Time>,
+    mut timer_query: Query<(Entity, &mut Timer)>,
+    mut playtime: ResMut<Playtime>,
+    mut threshold_reached: MessageWriter<TimerThresholdReached>,
+) {
+    for (entity, mut timer) in timer_query.iter_mut() {
+        let previous = timer.time;
         timer.time += time.delta_secs();
+
+        if let Some(threshold) = timer.threshold
+            && previous < threshold
+            && timer.time >= threshold
+        {
+            threshold_reached.write(TimerThresholdReached(entity));
+        }
     }
+    playtime.total_secs += time.delta_secs_f64();
 }
 
-// System to update timer UI
+// System to update timer UI (block timers are shown as tints instead)
 pub fn update_timer_ui(
-    timer_query: Query<&Timer>,
+    timer_query: Query<&Timer, Without<Block>>,
     mut text_query: Query<&mut Text, With<TimerText>>,
+    locale: Res<Locale>,
 ) {
     if let Ok(mut text) = text_query.single_mut() {
         let mut timer_text = String::new();
@@ -28,7 +53,7 @@ pub fn update_timer_ui(
             timer_text.push_str(&format!("{}: {:.1}s", timer.name, timer.time));
         }
         if timer_text.is_empty() {
-            timer_text = "No timers".to_string();
+            timer_text = locale.t(TextKey::NoTimers).to_string();
         }
         text.0 = timer_text;
     }
@@ -37,3 +62,86 @@ pub fn update_timer_ui(
 // Marker component for UI text
 #[derive(Component)]
 pub struct TimerText;
+
+// Marker component for the panel node that lists every timer
+#[derive(Component)]
+pub struct TimerPanel;
+
+// Marker component for one line of the timer panel (reused between frames)
+#[derive(Component)]
+pub struct TimerPanelLine;
+
+// Format elapsed seconds as "12.3s", or "m:ss.s" once it passes a minute
+pub fn format_timer_time(secs: f32) -> String {
+    if secs < 60.0 {
+        format!("{:.1}s", secs)
+    } else {
+        let minutes = (secs / 60.0).floor();
+        format!("{}:{:04.1}", minutes as u32, secs - minutes * 60.0)
+    }
+}
+
+// System to list every timer (including block timers) on its own line, sorted by name.
+// Lines past TIMER_PANEL_MAX_LINES are folded into a "+N more" line so the panel stays
+// readable with many block timers. Line nodes are reused, and unused ones are hidden.
+pub fn update_timer_panel(
+    mut commands: Commands,
+    timer_query: Query<&Timer>,
+    panel_query: Query<Entity, With<TimerPanel>>,
+    mut line_query: Query<(&mut Text, &mut Node), With<TimerPanelLine>>,
+    locale: Res<Locale>,
+) {
+    let Ok(panel) = panel_query.single() else {
+        return;
+    };
+
+    let mut timers: Vec<&Timer> = timer_query.iter().collect();
+    timers.sort_by(|a, b| a.name.cmp(&b.name).then(b.time.total_cmp(&a.time)));
+
+    let mut lines: Vec<String> = timers
+        .iter()
+        .take(TIMER_PANEL_MAX_LINES)
+        .map(|timer| format!("{}: {}", timer.name, format_timer_time(timer.time)))
+        .collect();
+    if timers.len() > TIMER_PANEL_MAX_LINES {
+        lines.push(locale.tf(
+            TextKey::MoreTimers,
+            &[&(timers.len() - TIMER_PANEL_MAX_LINES)],
+        ));
+    }
+    if lines.is_empty() {
+        lines.push(locale.t(TextKey::NoTimers).to_string());
+    }
+
+    let mut line_nodes = line_query.iter_mut();
+    for line in lines {
+        match line_nodes.next() {
+            Some((mut text, mut node)) => {
+                if text.0 != line {
+                    text.0 = line;
+                }
+                if node.display != Display::Flex {
+                    node.display = Display::Flex;
+                }
+            }
+            None => {
+                commands.entity(panel).with_child((
+                    Text::new(line),
+                    TextFont {
+                        font_size: TIMER_PANEL_FONT_SIZE,
+                        ..default()
+                    },
+                    Node::default(),
+                    TimerPanelLine,
+                ));
+            }
+        }
+    }
+
+    // Hide leftover lines instead of despawning them
+    for (_, mut node) in line_nodes {
+        if node.display != Display::None {
+            node.display = Display::None;
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! 起動時のコマンドライン引数を解析するモジュール
+//!
+//! 定数を書き換えて再コンパイルせずに試せるよう、以下のフラグを受け付ける：
+//! - `--rain`: 雨の状態で開始する
+//! - `--field-size <N>`: フィールドのサイズ（ブロック数）
+//! - `--seed <N>`: 乱数シード
+//! - `--rain-stress`: 雨のストレステストモードで開始する（F9キーで切り替え可能）
+//! - `--batch-field`: 草のブロックを共有メッシュでまとめて描画する（エンティティ数を減らす）
+//! - `--level <name>`: `assets/levels/<name>.ron`からフィールドの配置を読み込む（`--field-size`より優先）
+
+use std::iter::Peekable;
+
+use crate::constants::*;
+
+/// 起動時に指定された引数
+#[derive(Debug, Default)]
+pub struct LaunchArgs {
+    pub rain: bool,
+    pub field_size: Option<i32>,
+    pub seed: Option<u64>,
+    pub rain_stress: bool,
+    pub batch_field: bool,
+    pub level: Option<String>,
+}
+
+impl LaunchArgs {
+    /// `std::env::args`から引数を解析する
+    pub fn parse() -> Self {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    /// 任意の引数列から解析する
+    ///
+    /// 不正な値は警告を出してデフォルトにフォールバックする。
+    /// 値の代わりに`--`で始まる引数が続いた場合は値がないものとして扱い、
+    /// その引数は次のフラグとして解析する。
+    pub fn parse_from<I>(args: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut launch_args = Self::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--rain" => launch_args.rain = true,
+                "--rain-stress" => launch_args.rain_stress = true,
+                "--batch-field" => launch_args.batch_field = true,
+                "--field-size" => {
+                    launch_args.field_size = match next_value(&mut args).map(|v| v.parse::<i32>()) {
+                        Some(Ok(size)) if (FIELD_SIZE_MIN..=FIELD_SIZE_MAX).contains(&size) => {
+                            Some(size)
+                        }
+                        _ => {
+                            eprintln!(
+                                "--field-size には {}〜{} の整数を指定してください。デフォルト({})を使用します",
+                                FIELD_SIZE_MIN, FIELD_SIZE_MAX, FIELD_SIZE
+                            );
+                            None
+                        }
+                    };
+                }
+                "--level" => {
+                    launch_args.level = next_value(&mut args);
+                    if launch_args.level.is_none() {
+                        eprintln!(
+                            "--level にはレベル名を指定してください。フィールドを自動生成します"
+                        );
+                    }
+                }
+                "--seed" => {
+                    launch_args.seed = match next_value(&mut args).map(|v| v.parse::<u64>()) {
+                        Some(Ok(seed)) => Some(seed),
+                        _ => {
+                            eprintln!(
+                                "--seed には0以上の整数を指定してください。ランダムなシードを使用します"
+                            );
+                            None
+                        }
+                    };
+                }
+                unknown => {
+                    eprintln!("不明な引数を無視します: {}", unknown);
+                }
+            }
+        }
+
+        launch_args
+    }
+}
+
+/// 次の引数をフラグの値として取り出す
+///
+/// `--`で始まる引数は次のフラグなので、取り出さずに残しておく。
+fn next_value(args: &mut Peekable<impl Iterator<Item = String>>) -> Option<String> {
+    args.next_if(|value| !value.starts_with("--"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> LaunchArgs {
+        LaunchArgs::parse_from(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_args_use_defaults() {
+        let launch_args = parse(&[]);
+        assert!(!launch_args.rain);
+        assert_eq!(launch_args.field_size, None);
+        assert_eq!(launch_args.seed, None);
+        assert_eq!(launch_args.level, None);
+    }
+
+    #[test]
+    fn parses_flags_and_values() {
+        let launch_args = parse(&[
+            "--rain",
+            "--field-size",
+            "15",
+            "--seed",
+            "42",
+            "--level",
+            "example",
+            "--batch-field",
+        ]);
+        assert!(launch_args.rain);
+        assert!(launch_args.batch_field);
+        assert!(!launch_args.rain_stress);
+        assert_eq!(launch_args.field_size, Some(15));
+        assert_eq!(launch_args.seed, Some(42));
+        assert_eq!(launch_args.level.as_deref(), Some("example"));
+    }
+
+    #[test]
+    fn invalid_values_fall_back_to_defaults() {
+        let launch_args = parse(&["--field-size", "abc", "--seed", "-1"]);
+        assert_eq!(launch_args.field_size, None);
+        assert_eq!(launch_args.seed, None);
+
+        let launch_args = parse(&["--field-size", &(FIELD_SIZE_MAX + 1).to_string()]);
+        assert_eq!(launch_args.field_size, None);
+    }
+
+    #[test]
+    fn flag_in_place_of_a_value_is_not_consumed() {
+        let launch_args = parse(&["--level", "--rain", "--seed", "--field-size", "15"]);
+        assert_eq!(launch_args.level, None);
+        assert_eq!(launch_args.seed, None);
+        assert!(launch_args.rain);
+        assert_eq!(launch_args.field_size, Some(15));
+    }
+
+    #[test]
+    fn missing_value_at_the_end_falls_back() {
+        let launch_args = parse(&["--rain", "--level"]);
+        assert!(launch_args.rain);
+        assert_eq!(launch_args.level, None);
+    }
+
+    #[test]
+    fn unknown_args_are_ignored() {
+        let launch_args = parse(&["--unknown", "--rain"]);
+        assert!(launch_args.rain);
+    }
+}
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
+use crate::constants::PICKAXE_DURABILITY;
+use crate::resources::{FieldConfig, SettingsTab};
 use crate::traits::Storable;
 
 // ========================================
@@ -18,10 +21,26 @@ pub struct MainCamera;
 #[derive(Component)]
 pub struct SunLight;
 
+/// グラデーションの空のドームをマークするコンポーネント
+#[derive(Component)]
+pub struct SkyDome;
+
 /// ブロックをマークするコンポーネント
 #[derive(Component)]
 pub struct Block;
 
+/// ブロックの種類
+///
+/// 石のブロックは[`Mineable`]を持ち、採掘しきると草のブロックに変わる。
+/// 水のブロックはレベルファイルでのみ置け、憑依中のキツネが上を通ると遅くなる。
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum BlockType {
+    #[default]
+    Grass,
+    Stone,
+    Water,
+}
+
 /// 選択可能なブロックをマークするコンポーネント（初期の3x3エリア）
 #[derive(Component)]
 pub struct Selectable;
@@ -34,13 +53,223 @@ pub struct BlockHighlight;
 #[derive(Component)]
 pub struct BlockHighlighted;
 
+/// 岩をマークするコンポーネント
+#[derive(Component)]
+pub struct Rock;
+
 /// Foxエンティティをマークするコンポーネント
 #[derive(Component)]
 pub struct Fox;
 
-/// クリックフィードバックテキストのマーカーコンポーネント
+/// 選択中（憑依中・移動中・アクションメニューを開いている・まとめて選んだ）のキツネをマークするコンポーネント
+#[derive(Component)]
+pub struct FoxSelected;
+
+/// 選択中のキツネの足元に出す輪のコンポーネント（対象のキツネを持つ）
+#[derive(Component)]
+pub struct FoxSelectionRing(pub Entity);
+
+/// キツネが離れられる範囲（XZ平面上の円）
+///
+/// 憑依中の移動や自動で歩く時に、キツネの位置を円の内側に収める。
+/// `radius`は公開しているので、範囲を広げたい場合は書き換えればよい。
+#[derive(Component, Clone, Copy)]
+pub struct Leash {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Leash {
+    /// フィールド全体を覆う範囲（中心はフィールドの中心）
+    pub fn for_field(field_config: &FieldConfig) -> Self {
+        Self {
+            center: field_config.center(),
+            radius: field_config.world_extent() / 2.0,
+        }
+    }
+
+    /// 位置を範囲内に収める
+    ///
+    /// 範囲の外に出た分は中心へ向かって引き戻すので、境界に沿って滑るように動く。
+    pub fn clamp(&self, position: Vec3) -> Vec3 {
+        let offset = (position - self.center).with_y(0.0);
+        if offset.length() <= self.radius {
+            return position;
+        }
+        let clamped = self.center + offset.clamp_length_max(self.radius);
+        clamped.with_y(position.y)
+    }
+}
+
+/// キツネが歩いて向かっている移動先
+#[derive(Component)]
+pub struct FoxPathTarget {
+    pub target: Vec3,
+    /// 進めない状態が続いている秒数
+    pub stuck_secs: f32,
+}
+
+/// カメラの注視点について行くキツネのマーカーコンポーネント
+///
+/// アクションメニューの「ついて来て」で付け外しし、Xキーでも外せる。
+#[derive(Component)]
+pub struct FollowTarget;
+
+/// 雨の間、岩の陰で雨宿りしているキツネのマーカーコンポーネント
+#[derive(Component)]
+pub struct Sheltered;
+
+/// 歩いて向かう途中で諦めたキツネのコンポーネント
+///
+/// 届かない植物へ何度も向かい直さないよう、外れるまでは空腹でも食べ物を探しに行かない。
+#[derive(Component)]
+pub struct FoodSeekCooldown {
+    /// 再び食べ物を探すまでの残り時間（秒）
+    pub remaining_secs: f32,
+}
+
+/// 嵐におびえているキツネのコンポーネント
+///
+/// 強い雨の中で稲光が走ると付き、雨が弱まってから[`Self::calm_down_secs`]経つと外れる。
+/// おびえている間は速く走り、雨宿りを急ぐ。
+#[derive(Component)]
+pub struct Spooked {
+    /// 落ち着くまでの残り時間（秒）
+    pub calm_down_secs: f32,
+}
+
+/// キツネの機嫌
+#[derive(Component, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FoxMood {
+    #[default]
+    Happy,
+    Neutral,
+    Unhappy,
+    Scared,
+}
+
+/// 機嫌の色に染めるため、キツネごとに複製したマテリアルのメッシュにつけるコンポーネント
+///
+/// glTFのマテリアルはすべてのキツネで共有されているため、複製してから染める。
+/// 染める色は複製前の色に機嫌の色を掛け合わせて求める。
+#[derive(Component)]
+pub struct FoxMoodMaterial {
+    /// 複製する前のマテリアルの色
+    pub base_color: Color,
+}
+
+/// キツネの状態を表す数値
+#[derive(Component, Default, Clone, Copy, PartialEq, Debug)]
+pub struct FoxStats {
+    /// 空腹度（0.0で満腹、1.0で腹ぺこ）。時間とともに増える
+    pub hunger: f32,
+}
+
+/// キツネにつけた名前
+#[derive(Component)]
+pub struct FoxName(pub String);
+
+/// 憑依中のキツネの移動の慣性
+///
+/// 入力方向へ徐々に加速し、入力がなければ摩擦で徐々に止まる。
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct FoxPhysics {
+    /// 現在の速度（XZ平面、1秒あたりの移動量）
+    pub velocity: Vec3,
+}
+
+impl FoxPhysics {
+    /// 1フレーム分、速度を入力に近づける
+    ///
+    /// `input`は長さ1以下の移動方向で、`input * max_speed`に向けて`acceleration`で加速する。
+    /// 入力がない場合は[`FOX_FRICTION`](crate::constants::FOX_FRICTION)で減速する。
+    pub fn steer(&mut self, input: Vec3, max_speed: f32, acceleration: f32, delta_secs: f32) {
+        let (target, rate) = if input == Vec3::ZERO {
+            (Vec3::ZERO, crate::constants::FOX_FRICTION)
+        } else {
+            (input * max_speed, acceleration)
+        };
+        self.velocity = self.velocity.move_towards(target, rate * delta_secs);
+    }
+}
+
+/// キツネの年齢と成長段階
+///
+/// `stage`は[`FOX_GROWTH_STAGES`](crate::constants::FOX_GROWTH_STAGES)の添字で、最後の段階が大人。
+#[derive(Component, Default)]
+pub struct FoxAge {
+    /// フィールドで過ごした秒数（格納中は進まない）
+    pub seconds: f32,
+    pub stage: u8,
+}
+
+/// 1行テキスト入力欄
+///
+/// フォーカス中のみキーボード入力を受け付け、`max_len`文字までに制限する。
+#[derive(Component)]
+pub struct TextInput {
+    pub value: String,
+    pub max_len: usize,
+    pub focused: bool,
+    /// IMEで変換中の文字列（確定するまで`value`には入れない）
+    pub preedit: String,
+}
+
+/// テキスト入力欄の中身を表示するテキストのマーカーコンポーネント
+#[derive(Component)]
+pub struct TextInputDisplay;
+
+/// キツネの名前を入力するテキスト入力欄（対象のキツネを保持）
+#[derive(Component)]
+pub struct FoxNameInput(pub Entity);
+
+/// デバッグコンソールUIのマーカーコンポーネント
+#[derive(Component)]
+pub struct DebugConsole;
+
+/// デバッグコンソールのコマンド入力欄のマーカーコンポーネント
+#[derive(Component)]
+pub struct DebugConsoleInput;
+
+/// デバッグコンソールのログ表示テキストのマーカーコンポーネント
 #[derive(Component)]
-pub struct ClickFeedbackText;
+pub struct DebugConsoleLog;
+
+/// クリックフィードバックテキストのコンポーネント
+///
+/// `shown_secs`は今のメッセージを表示してからの経過時間で、フェードアウトに使う。
+#[derive(Component, Default)]
+pub struct ClickFeedbackText {
+    pub shown_secs: f32,
+}
+
+/// ブロックの情報パネルのマーカーコンポーネント
+#[derive(Component)]
+pub struct BlockInspectPanel;
+
+/// ミニマップの背景のマーカーコンポーネント
+#[derive(Component)]
+pub struct Minimap;
+
+/// ミニマップ上の点のマーカーコンポーネント（使い回すため、何を表すかは持たない）
+#[derive(Component)]
+pub struct MinimapMarker;
+
+/// 所持資源テキストのマーカーコンポーネント
+#[derive(Component)]
+pub struct ResourcesText;
+
+/// 天気アイコンのマーカーコンポーネント
+#[derive(Component)]
+pub struct WeatherIcon;
+
+/// 天気と次の変化までの時間を表示するテキストのマーカーコンポーネント
+#[derive(Component)]
+pub struct WeatherForecastText;
+
+/// 累計プレイ時間テキストのマーカーコンポーネント
+#[derive(Component)]
+pub struct PlaytimeText;
 
 /// アイテムエリアUIのマーカーコンポーネント
 #[derive(Component)]
@@ -50,14 +279,60 @@ pub struct ItemArea;
 #[derive(Component)]
 pub struct ItemSlotIcon;
 
-/// Foxアクションメニューのマーカーコンポーネント
+/// Foxアクションメニューのコンポーネント（メニューを開いたキツネを持つ）
 #[derive(Component)]
-pub struct FoxActionMenu;
+pub struct FoxActionMenu(pub Entity);
+
+/// ブロックのアクションメニューのコンポーネント
+///
+/// `inspecting`が`true`の間は、メニューを開いたブロックの情報パネルを表示し続ける。
+#[derive(Component)]
+pub struct BlockActionMenu {
+    pub block: Entity,
+    pub inspecting: bool,
+}
+
+/// メインメニューUIのマーカーコンポーネント
+#[derive(Component)]
+pub struct MainMenu;
+
+/// メインメニュー表示用のカメラのマーカーコンポーネント
+///
+/// ゲーム本編のカメラを生成する前だけ使う。
+#[derive(Component)]
+pub struct MainMenuCamera;
+
+/// メインメニューのボタン
+#[derive(Component)]
+pub enum MainMenuButton {
+    Start,
+    Quit,
+}
+
+/// 設定メニューからメインメニューに戻るボタンのマーカーコンポーネント
+#[derive(Component)]
+pub struct ReturnToMenuButton;
 
 /// 設定メニューUIのマーカーコンポーネント
 #[derive(Component)]
 pub struct SettingsMenu;
 
+/// 設定メニューのタブごとのセクションをマークするコンポーネント
+#[derive(Component)]
+pub struct SettingsSection(pub SettingsTab);
+
+/// 設定の読み込みを確認するダイアログのマーカーコンポーネント
+#[derive(Component)]
+pub struct LoadSettingsDialog;
+
+/// 設定メニューのスクロールできる内容部分をマークするコンポーネント
+#[derive(Component)]
+pub struct SettingsScrollArea;
+
+/// 設定メニューのタブボタンのコンポーネント
+#[derive(Component)]
+pub struct SettingsTabButton(pub SettingsTab);
+
 // ========================================
 // Data Components
 // ========================================
@@ -67,24 +342,214 @@ pub struct SettingsMenu;
 pub struct RainDrop {
     pub velocity: Vec3,
     pub lifetime: f32,
+    /// 冬に降る雪片か（着地しても水しぶきを出さない）
+    pub is_snow: bool,
+}
+
+/// 雨粒が着地した時の水しぶきのコンポーネント
+#[derive(Component)]
+pub struct RainSplash {
+    pub lifetime: f32,
+}
+
+/// ブロックの上面にたまる水たまりのコンポーネント
+#[derive(Component)]
+pub struct Puddle {
+    /// 水たまりができるブロック
+    pub block: Entity,
+    /// たまった水の量（0.0で空、1.0で最大）
+    pub level: f32,
+    /// マスごとの水のたまりやすさ（[`PUDDLE_CAPACITY_MIN`](crate::constants::PUDDLE_CAPACITY_MIN)〜1.0）
+    pub capacity: f32,
+}
+
+impl Puddle {
+    /// 水たまりの一辺の大きさ
+    pub fn size(&self) -> f32 {
+        self.level * self.capacity * crate::constants::PUDDLE_MAX_SIZE
+    }
+}
+
+/// ブロックのタイマーの経過を色で表示する板のコンポーネント
+///
+/// 板はブロックの子として上面に置かれ、`block`のタイマーに合わせて色が変わる。
+#[derive(Component)]
+pub struct BlockTimerTint {
+    pub block: Entity,
+}
+
+/// 水のブロックの上を歩くキツネの足元に広がる波紋のコンポーネント
+#[derive(Component)]
+pub struct Ripple {
+    /// 出てからの経過時間（秒）
+    pub age: f32,
+}
+
+/// キツネの足元から出る土ぼこりの粒のコンポーネント
+#[derive(Component)]
+pub struct Dust {
+    pub velocity: Vec3,
+    pub lifetime: f32,
+}
+
+/// ワールドに落ちているアイテムのコンポーネント
+#[derive(Component)]
+pub struct DroppedItem {
+    pub item: ItemType,
+    /// 拾う側が一度拾得半径の外に出たか（落とした直後に拾い直さないため）
+    pub pickup_armed: bool,
+}
+
+/// 採掘可能なオブジェクトのコンポーネント
+#[derive(Component)]
+pub struct Mineable {
+    /// 採掘しきるまでに必要な残りの打撃回数
+    pub hits_remaining: u32,
+}
+
+/// 採掘された岩の再出現までの時間を管理するコンポーネント
+#[derive(Component)]
+pub struct RockRespawnTimer {
+    /// 再出現までの残り時間（秒）
+    pub remaining: f32,
+}
+
+/// アイテム設置位置のプレビュー（ゴースト）のマーカーコンポーネント
+#[derive(Component)]
+pub struct PlacementPreview;
+
+/// ブロックに植えられた植物のコンポーネント
+#[derive(Component)]
+pub struct Plant {
+    /// 成長度（0.0〜1.0）
+    pub growth: f32,
+    /// 植えられているブロック
+    pub block: Entity,
+}
+
+impl Plant {
+    /// 収穫可能かどうか
+    pub fn is_mature(&self) -> bool {
+        self.growth >= 1.0
+    }
+}
+
+/// ブロックの辺の1つに立てた柵のコンポーネント
+///
+/// キツネは柵のある辺を越えて移動できない。
+#[derive(Component)]
+pub struct Fence {
+    /// 柵が立っているブロック
+    pub block: Entity,
+    /// 柵が立っているマスのグリッド座標
+    pub tile: IVec2,
+    /// 柵が立っている辺
+    pub side: FenceSide,
+}
+
+/// 柵を立てるマスの辺（北は-Z、東は+X方向）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FenceSide {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl FenceSide {
+    /// ブロック中心から見てクリック位置に最も近い辺
+    pub fn nearest(block_pos: Vec3, hit_point: Vec3) -> Self {
+        let offset = hit_point - block_pos;
+        if offset.x.abs() > offset.z.abs() {
+            if offset.x > 0.0 {
+                Self::East
+            } else {
+                Self::West
+            }
+        } else if offset.z > 0.0 {
+            Self::South
+        } else {
+            Self::North
+        }
+    }
+
+    /// 辺を挟んだ隣のマスへのグリッド座標の差分
+    pub fn offset(self) -> IVec2 {
+        match self {
+            Self::North => IVec2::NEG_Y,
+            Self::East => IVec2::X,
+            Self::South => IVec2::Y,
+            Self::West => IVec2::NEG_X,
+        }
+    }
+
+    /// マス中心から+Z側の辺をこの辺へ向けるY軸回転
+    pub fn rotation(self) -> Quat {
+        let angle = match self {
+            Self::South => 0.0,
+            Self::East => std::f32::consts::FRAC_PI_2,
+            Self::North => std::f32::consts::PI,
+            Self::West => -std::f32::consts::FRAC_PI_2,
+        };
+        Quat::from_rotation_y(angle)
+    }
+}
+
+/// ブロックの上に設置された松明のコンポーネント
+#[derive(Component)]
+pub struct Torch {
+    /// 松明が置かれているブロック
+    pub block: Entity,
+    /// 明るさの揺らぎの位相（松明ごとにずらす）
+    pub flicker_phase: f32,
 }
 
 /// アイテムの種類
 #[derive(Clone, Debug)]
 pub enum ItemType {
     Fox,
+    Seed,
+    Produce,
+    Ore,
+    Fence,
+    Torch,
+    /// ツルハシ（選択中のみ岩や石のブロックを採掘できる）
+    Pickaxe {
+        /// 壊れるまでに採掘できる残りの回数
+        durability: u32,
+    },
+}
+
+impl ItemType {
+    /// 新品のツルハシ
+    pub fn new_pickaxe() -> Self {
+        ItemType::Pickaxe {
+            durability: PICKAXE_DURABILITY,
+        }
+    }
 }
 
 impl Storable for ItemType {
     fn display_name(&self) -> &'static str {
         match self {
             ItemType::Fox => "キツネ",
+            ItemType::Seed => "種",
+            ItemType::Produce => "作物",
+            ItemType::Ore => "鉱石",
+            ItemType::Fence => "柵",
+            ItemType::Torch => "松明",
+            ItemType::Pickaxe { .. } => "ツルハシ",
         }
     }
 
     fn icon_path(&self) -> &'static str {
         match self {
             ItemType::Fox => "animated/Fox_img_512x512.png",
+            ItemType::Seed => "damp_soil_texture.png",
+            ItemType::Produce => "farmland_texture.png",
+            ItemType::Ore | ItemType::Fence | ItemType::Torch | ItemType::Pickaxe { .. } => {
+                "array_texture.png"
+            }
         }
     }
 }
@@ -102,6 +567,17 @@ pub enum FoxActionButton {
     Move,
     Box,
     Possession,
+    Follow,
+    Cancel,
+}
+
+/// ブロックのアクションボタンの種類
+#[derive(Component)]
+pub enum BlockActionButton {
+    ClearItem,
+    ResetTimer,
+    Inspect,
+    Break,
 }
 
 /// インタラクティブな設定UIボタンのコンポーネント
@@ -109,21 +585,52 @@ pub enum FoxActionButton {
 pub enum SettingButton {
     MouseSensitivityUp,
     MouseSensitivityDown,
+    PossessionSensitivityUp,
+    PossessionSensitivityDown,
+    DashFovKickUp,
+    DashFovKickDown,
     KeyboardSensitivityUp,
     KeyboardSensitivityDown,
     MovementSpeedUp,
     MovementSpeedDown,
     ZoomSpeedUp,
     ZoomSpeedDown,
+    GamepadDeadzoneUp,
+    GamepadDeadzoneDown,
+    DashTimeoutUp,
+    DashTimeoutDown,
+    ToggleShadows,
+    ShadowResolutionUp,
+    ShadowResolutionDown,
+    AmbientBrightnessUp,
+    AmbientBrightnessDown,
+    ToggleManualSun,
+    SunAzimuthUp,
+    SunAzimuthDown,
+    SunElevationUp,
+    SunElevationDown,
     SaveSettings,
     LoadSettings,
+    ConfirmLoadSettings,
+    CancelLoadSettings,
+    ToggleLanguage,
 }
 
 /// 設定値を表示するテキストをマークするコンポーネント
 #[derive(Component)]
 pub enum SettingValueText {
     MouseSensitivity,
+    PossessionSensitivity,
+    DashFovKick,
     KeyboardSensitivity,
     MovementSpeed,
     ZoomSpeed,
+    GamepadDeadzone,
+    DashTimeout,
+    Shadows,
+    ShadowResolution,
+    AmbientBrightness,
+    ManualSun,
+    SunAzimuth,
+    SunElevation,
 }
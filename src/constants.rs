@@ -5,12 +5,34 @@
 /// フィールドのサイズ（ブロック数）
 pub const FIELD_SIZE: i32 = 9;
 
+/// 起動引数で指定できるフィールドサイズの範囲
+pub const FIELD_SIZE_MIN: i32 = 3;
+pub const FIELD_SIZE_MAX: i32 = 64;
+
+/// `--level`で読み込むレベルファイルを置くディレクトリ
+pub const LEVELS_DIR: &str = "assets/levels";
+
+/// レベルファイルで指定できるマスの高さの上限（ブロック数）
+pub const LEVEL_TILE_HEIGHT_MAX: i32 = 4;
+
 /// ブロックのサイズ（各辺の長さ）
 pub const BLOCK_SIZE: f32 = 16.0;
 
 /// ブロックの半分のサイズ
 pub const BLOCK_HALF_SIZE: f32 = BLOCK_SIZE / 2.0;
 
+/// 草のブロックのシーン
+pub const GRASS_BLOCK_SCENE: &str = "animated/GrassBlock.glb#Scene0";
+
+/// 草のブロックのメッシュ（[`FieldConfig::batched`](crate::resources::FieldConfig::batched)の時に使う）
+pub const GRASS_BLOCK_MESH: &str = "animated/GrassBlock.glb#Mesh0/Primitive0";
+
+/// 草のブロックのマテリアル（[`FieldConfig::batched`](crate::resources::FieldConfig::batched)の時に使う）
+pub const GRASS_BLOCK_MATERIAL: &str = "animated/GrassBlock.glb#Material0";
+
+/// 草のブロックのモデルの拡大率
+pub const GRASS_BLOCK_SCALE: f32 = 17.0;
+
 /// ブロック間の間隔
 pub const BLOCK_SPACING: f32 = BLOCK_SIZE;
 
@@ -20,6 +42,24 @@ pub const SELECTABLE_AREA_START: i32 = 3;
 /// 選択可能エリアの終了インデックス
 pub const SELECTABLE_AREA_END: i32 = 5;
 
+/// 元に戻せる操作の履歴の最大件数
+pub const ACTION_HISTORY_LIMIT: usize = 50;
+
+/// 配置グリッドの分割数の候補（Gキーで順に切り替える）
+pub const PLACEMENT_GRID_DIVISION_STEPS: [u32; 3] = [1, 2, 4];
+
+/// 選択可能エリアを1周広げるのに必要な鉱石の数
+pub const BUILD_AREA_EXPAND_ORE_COST: usize = 2;
+
+/// 選択可能エリアの枠線を、起動直後やエリアが広がった直後に表示しておく時間（秒）
+pub const BUILD_AREA_OUTLINE_INTRO_SECS: f32 = 8.0;
+
+/// 選択可能エリアの枠線が消える時にフェードアウトする時間（秒）
+pub const BUILD_AREA_OUTLINE_FADE_SECS: f32 = 1.5;
+
+/// 選択可能エリアの枠線をブロックの上面から浮かせる高さ
+pub const BUILD_AREA_OUTLINE_LIFT: f32 = 0.02;
+
 // ========================================
 // Camera Constants
 // ========================================
@@ -30,6 +70,90 @@ pub const CAMERA_INITIAL_POSITION: (f32, f32, f32) = (200.0, 300.0, 200.0);
 /// カメラのピッチ制限（ラジアン）
 pub const CAMERA_PITCH_LIMIT: f32 = 1.5;
 
+/// 視点プリセット（正面・側面）の見下ろし角（ラジアン）
+pub const CAMERA_VIEW_PRESET_ELEVATION: f32 = 0.2;
+
+/// 視点プリセットでフィールド全体を収める際の余白率
+pub const CAMERA_VIEW_PRESET_MARGIN: f32 = 1.2;
+
+/// 視点プリセットへの補間速度（1秒あたりの追従率）
+pub const CAMERA_VIEW_PRESET_LERP_SPEED: f32 = 5.0;
+
+/// 追従カメラの対象からの水平距離（後方への距離）
+pub const CAMERA_FOLLOW_DISTANCE: f32 = 40.0;
+
+/// 追従カメラの対象からの高さ
+pub const CAMERA_FOLLOW_HEIGHT: f32 = 40.0;
+
+/// 憑依中のホイール1段あたりの追従距離の変化量
+pub const POSSESSION_ZOOM_STEP: f32 = 4.0;
+
+/// 憑依中の追従カメラの最小距離（キツネにめり込まない距離）
+pub const POSSESSION_ZOOM_MIN_DISTANCE: f32 = 8.0;
+
+/// 憑依中の追従カメラの最大距離
+pub const POSSESSION_ZOOM_MAX_DISTANCE: f32 = 80.0;
+
+/// 障害物に遮られて追従カメラを引き寄せる時の、キツネからの最小距離
+pub const POSSESSION_CAMERA_PULL_IN_MIN_DISTANCE: f32 = 6.0;
+
+/// 追従カメラを引き寄せる時に、障害物の手前に空ける距離
+pub const POSSESSION_CAMERA_OBSTACLE_MARGIN: f32 = 1.5;
+
+/// カメラの基準の視野角（ラジアン、Bevyの既定値と同じ）
+pub const CAMERA_BASE_FOV: f32 = std::f32::consts::FRAC_PI_4;
+
+/// ダッシュ時に視野角を広げる量の既定値と最大値（度）
+pub const DASH_FOV_KICK_DEFAULT_DEGREES: f32 = 8.0;
+pub const DASH_FOV_KICK_MAX_DEGREES: f32 = 30.0;
+
+/// ダッシュ時の視野角の変化の速さ（大きいほど素早く追従する）
+pub const DASH_FOV_EASE_RATE: f32 = 8.0;
+
+/// スペクテイターモードでShiftを押した時の移動速度倍率
+pub const SPECTATOR_BOOST_MULTIPLIER: f32 = 3.0;
+
+/// 左ボタンを押してからカーソルがこの距離（ピクセル）より動いたら、離してもクリックとみなさない
+pub const CLICK_DRAG_THRESHOLD: f32 = 5.0;
+
+/// マウス感度（通常・憑依中とも）の設定できる範囲
+pub const MOUSE_SENSITIVITY_MIN: f32 = 0.001;
+pub const MOUSE_SENSITIVITY_MAX: f32 = 0.02;
+
+/// マウス感度（通常・憑依中とも）の既定値
+pub const MOUSE_SENSITIVITY_DEFAULT: f32 = 0.003;
+
+/// 矢印キーでのカメラ回転の速さの設定できる範囲（ラジアン/秒）
+pub const KEYBOARD_SENSITIVITY_MIN: f32 = 0.6;
+pub const KEYBOARD_SENSITIVITY_MAX: f32 = 6.0;
+
+/// WASDでのカメラ移動の速さの設定できる範囲（1秒あたりの移動量）
+pub const MOVEMENT_SPEED_MIN: f32 = 300.0;
+pub const MOVEMENT_SPEED_MAX: f32 = 6000.0;
+
+/// ズーム速度の設定できる範囲
+pub const ZOOM_SPEED_MIN: f32 = 10.0;
+pub const ZOOM_SPEED_MAX: f32 = 200.0;
+
+// ========================================
+// Gamepad Constants
+// ========================================
+
+/// スティックのデッドゾーンの初期値（0.0〜1.0）
+pub const GAMEPAD_DEFAULT_DEADZONE: f32 = 0.15;
+
+/// スティックのデッドゾーンの上限
+pub const GAMEPAD_DEADZONE_MAX: f32 = 0.5;
+
+/// ダッシュのダブルタップとみなす間隔（秒）の既定値・設定できる範囲・1段階の変化量
+pub const DASH_TIMEOUT_DEFAULT: f32 = 0.3;
+pub const DASH_TIMEOUT_MIN: f32 = 0.1;
+pub const DASH_TIMEOUT_MAX: f32 = 0.8;
+pub const DASH_TIMEOUT_STEP: f32 = 0.05;
+
+/// トリガーを最後まで引いた時のズーム量（1秒あたり、ズーム速度に対する倍率）
+pub const GAMEPAD_TRIGGER_ZOOM_RATE: f32 = 4.0;
+
 // ========================================
 // Entity Positioning Constants
 // ========================================
@@ -40,15 +164,307 @@ pub const FOX_INITIAL_HEIGHT: f32 = 8.0;
 /// Foxのスケール
 pub const FOX_SCALE: f32 = 0.1;
 
+/// キツネの成長段階（その段階になるまでの秒数と、[`FOX_SCALE`]に対する大きさの比率）
+///
+/// 最初の段階が生まれたばかりの子ギツネ、最後の段階が大人。
+pub const FOX_GROWTH_STAGES: [(f32, f32); 3] = [(0.0, 0.5), (120.0, 0.75), (300.0, 1.0)];
+
 /// Foxのバウンディングボックスの半分のサイズ
 pub const FOX_HALF_SIZE: f32 = 5.0;
 
+/// 選択中のキツネの足元に出す輪の内側・外側の半径（大人の大きさの時）
+pub const FOX_SELECTION_RING_INNER_RADIUS: f32 = FOX_HALF_SIZE + 0.5;
+pub const FOX_SELECTION_RING_OUTER_RADIUS: f32 = FOX_HALF_SIZE + 1.5;
+
+/// 選択中のキツネの足元に出す輪の、足元からの高さ（ブロックの上面と重ならないように浮かせる）
+pub const FOX_SELECTION_RING_HEIGHT: f32 = 0.2;
+
 /// キツネを掴んでいる時の追加高さ
 pub const FOX_HOVER_HEIGHT: f32 = 2.0;
 
+/// 複数のキツネをまとめて移動する時の、基準のキツネから周りのキツネまでの距離
+pub const FOX_FORMATION_SPACING: f32 = 10.0;
+
+/// Possessionモード時のキツネの歩行速度
+pub const FOX_WALK_SPEED: f32 = 15.0;
+
+/// Possessionモード時のキツネのダッシュ速度
+pub const FOX_DASH_SPEED: f32 = 50.0;
+
+/// Possessionモード時のキツネの加速度（歩行時・ダッシュ時、1秒あたりの速度の増加量）
+pub const FOX_WALK_ACCELERATION: f32 = 60.0;
+pub const FOX_DASH_ACCELERATION: f32 = 150.0;
+
+/// Possessionモード時に移動キーを離した時の減速度（1秒あたりの速度の減少量）
+pub const FOX_FRICTION: f32 = 80.0;
+
+/// ダッシュ中に土ぼこりを出す間隔（秒）
+pub const DUST_EMIT_INTERVAL: f32 = 0.12;
+
+/// 土ぼこり1回分の粒の数
+pub const DUST_PUFF_COUNT: usize = 4;
+
+/// 土ぼこりの粒の大きさ
+pub const DUST_PARTICLE_SIZE: f32 = 0.8;
+
+/// 土ぼこりが消えるまでの時間（秒）
+pub const DUST_LIFETIME: f32 = 0.5;
+
+/// 土ぼこりが横に広がる速さと舞い上がる速さ
+pub const DUST_SPREAD_SPEED: f32 = 4.0;
+pub const DUST_RISE_SPEED: f32 = 3.0;
+
+/// 水のブロックの上での憑依中のキツネの速さの倍率
+pub const WATER_SPEED_FACTOR: f32 = 0.5;
+
+/// 水のブロックの上を歩く時に波紋を出す間隔（秒）
+pub const RIPPLE_EMIT_INTERVAL: f32 = 0.35;
+
+/// 波紋が消えるまでの時間（秒）
+pub const RIPPLE_LIFETIME: f32 = 1.2;
+
+/// 出た時の波紋の半径と輪の太さ
+pub const RIPPLE_RADIUS: f32 = 1.0;
+pub const RIPPLE_WIDTH: f32 = 0.15;
+
+/// 消える時の波紋の大きさ（出た時の何倍か）
+pub const RIPPLE_MAX_SCALE: f32 = 6.0;
+
+/// 波紋が薄くなっていく段階の数
+pub const RIPPLE_FADE_STEPS: usize = 8;
+
+/// 波紋を水面から浮かせる高さ（ちらつき防止）
+pub const RIPPLE_LIFT: f32 = 0.05;
+
+/// 移動先まで歩く時に到着したとみなす距離
+pub const FOX_PATH_ARRIVE_DISTANCE: f32 = 1.0;
+
+/// 歩行中に岩を避け始める、岩の半径からの余裕
+pub const FOX_PATH_AVOID_MARGIN: f32 = 8.0;
+
+/// 歩行中に岩を避ける強さ（移動先への向きに対する比率）
+pub const FOX_PATH_AVOID_STRENGTH: f32 = 1.5;
+
+/// 歩行中に進めない状態がこの秒数続いたら移動を諦める
+pub const FOX_PATH_GIVE_UP_SECS: f32 = 3.0;
+
+/// 追従中のキツネが、カメラの注視点からこの距離より離れたら歩き出す
+pub const FOX_FOLLOW_DISTANCE: f32 = 12.0;
+
+/// キツネの行動範囲の境界を描く円のブロック上面からの高さ
+pub const LEASH_GIZMO_LIFT: f32 = 0.5;
+
+/// 岩の縁からこの距離以内にいるキツネは雨宿りしているとみなす
+pub const FOX_SHELTER_DISTANCE: f32 = 6.0;
+
+/// 雨の強さがこの値以上の間に稲光が走ると、キツネがおびえる
+pub const FOX_SPOOK_RAIN_INTENSITY: f32 = 0.9;
+
+/// 雨が弱まってから、おびえたキツネが落ち着くまでの時間（秒）
+pub const FOX_SPOOK_CALM_DOWN_SECS: f32 = 5.0;
+
+/// おびえたキツネが歩く速さの倍率
+pub const FOX_SPOOKED_SPEED_FACTOR: f32 = 1.8;
+
+/// 岩の生成数
+pub const ROCK_COUNT: usize = 4;
+
+/// 岩のスケール
+pub const ROCK_SCALE: f32 = 18.0;
+
+/// 岩の中心の高さ
+pub const ROCK_HEIGHT: f32 = 8.0;
+
+/// 岩のおおよその半径（配置時の重なり判定用）
+pub const ROCK_RADIUS: f32 = 17.0;
+
+/// 地面から岩のてっぺんまでの高さ（雨粒を岩の上で止める判定用）
+pub const ROCK_TOP_HEIGHT: f32 = 16.0;
+
+/// 追従カメラの遮蔽判定で岩とみなす箱の高さ（岩の中心から上下に半分ずつ）
+pub const ROCK_CAMERA_OBSTACLE_HEIGHT: f32 = 16.0;
+
+/// 岩同士の最小間隔（中心間の距離）
+pub const ROCK_MIN_SPACING: f32 = 30.0;
+
+/// 岩の配置位置を探す最大試行回数
+pub const ROCK_PLACEMENT_ATTEMPTS: usize = 100;
+
+/// 岩を採掘しきるまでの打撃回数
+pub const ROCK_MINE_HITS: u32 = 5;
+
+/// 岩を採掘しきった時にドロップする鉱石の数
+pub const ROCK_ORE_YIELD: usize = 2;
+
+/// フィールドのブロックが石のブロックになる確率（初期の選択可能エリアの外のみ）
+pub const STONE_BLOCK_CHANCE: f64 = 0.15;
+
+/// 石のブロックを採掘しきるまでの打撃回数
+pub const STONE_BLOCK_MINE_HITS: u32 = 3;
+
+/// 石のブロックを採掘しきった時にドロップする鉱石の数
+pub const STONE_BLOCK_ORE_YIELD: usize = 1;
+
+/// 採掘された岩が再出現するまでの時間（秒）
+pub const ROCK_RESPAWN_DELAY_SECS: f32 = 90.0;
+
 /// ブロックハイライトのサイズ
 pub const BLOCK_HIGHLIGHT_SIZE: f32 = 17.0;
 
+/// ブロックのタイマーが最も古い色になるまでの時間（秒）
+pub const BLOCK_TIMER_STALE_SECS: f32 = 120.0;
+
+/// ブロックのタイマーの色の段階数（段階ごとにマテリアルを共有する）
+pub const BLOCK_TIMER_TINT_STEPS: usize = 8;
+
+/// タイマーの色を表示する板のブロック上面からの高さ（ちらつき防止）
+pub const BLOCK_TIMER_TINT_LIFT: f32 = 0.05;
+
+// ========================================
+// Animation Constants
+// ========================================
+
+/// キツネのアニメーション切り替え時のクロスフェード時間（秒）
+pub const FOX_ANIMATION_TRANSITION_SECS: f32 = 0.2;
+
+/// 移動速度に対するアニメーション再生速度の追従率
+///
+/// 0.0 で常に等速、1.0 で移動速度に完全比例する。
+pub const FOX_ANIMATION_SPEED_FACTOR: f32 = 0.3;
+
+/// 待機がこの時間（秒）続くと、別の待機アニメーションを1回だけ再生する
+pub const FOX_IDLE_VARIANT_DELAY_SECS: f32 = 8.0;
+
+/// 別の待機アニメーションとして使う`Fox.glb`のアニメーション番号
+///
+/// 0〜2は待機（Survey）・歩き・走りに使っている。
+/// 同梱のモデルにはこの3つしかないため、待機アニメーションを追加したモデルに差し替えた時に番号を足す。
+pub const FOX_IDLE_VARIANT_ANIMATIONS: &[usize] = &[];
+
+// ========================================
+// Dropped Item Constants
+// ========================================
+
+/// ドロップアイテムの大きさ（一辺の長さ）
+pub const DROPPED_ITEM_SIZE: f32 = 3.0;
+
+/// ドロップアイテムの回転速度（ラジアン/秒）
+pub const DROPPED_ITEM_SPIN_SPEED: f32 = 2.0;
+
+/// ドロップアイテムを自動で拾う半径
+pub const DROPPED_ITEM_PICKUP_RADIUS: f32 = 10.0;
+
+/// キツネから見てアイテムを落とす距離
+pub const DROPPED_ITEM_DROP_DISTANCE: f32 = 15.0;
+
+/// キツネがドロップアイテムを引き寄せる半径
+///
+/// 落とした直後のアイテムをすぐ拾い直さないよう、[`DROPPED_ITEM_DROP_DISTANCE`]より小さくする。
+pub const AUTO_PICKUP_RADIUS: f32 = 14.0;
+
+/// キツネに引き寄せられるドロップアイテムの速さ（1秒あたりの移動量）
+pub const AUTO_PICKUP_PULL_SPEED: f32 = 30.0;
+
+/// 引き寄せたドロップアイテムをキツネが拾う距離
+pub const AUTO_PICKUP_CONTACT_DISTANCE: f32 = FOX_HALF_SIZE;
+
+/// ドロップアイテムの色
+pub const DROPPED_ITEM_COLOR: (f32, f32, f32) = (0.9, 0.7, 0.3);
+
+/// 設置プレビュー（ゴースト）の一辺の長さ
+pub const PLACEMENT_PREVIEW_SIZE: f32 = 6.0;
+
+// ========================================
+// Farming Constants
+// ========================================
+
+/// 植物が完全に成長するまでの時間（秒、晴天時）
+pub const PLANT_GROWTH_SECS: f32 = 60.0;
+
+/// 雨天時の成長速度の倍率
+pub const PLANT_RAIN_GROWTH_MULTIPLIER: f32 = 2.0;
+
+/// 植物メッシュの半径（完全に成長した時）
+pub const PLANT_RADIUS: f32 = 4.0;
+
+/// キツネが実った植物を食べる距離（水平距離）
+pub const FOX_EAT_DISTANCE: f32 = 8.0;
+
+/// 実った植物を1つ食べた時に減る空腹度
+pub const FOX_EAT_HUNGER_RELIEF: f32 = 0.5;
+
+/// 植えた直後の植物のスケール
+pub const PLANT_MIN_SCALE: f32 = 0.2;
+
+/// 成長中の植物の色
+pub const PLANT_GROWING_COLOR: (f32, f32, f32) = (0.4, 0.7, 0.3);
+
+/// 収穫可能な植物の色
+pub const PLANT_MATURE_COLOR: (f32, f32, f32) = (0.6, 0.9, 0.2);
+
+/// ゲーム開始時に所持している種の数
+pub const STARTING_SEED_COUNT: usize = 3;
+
+// ========================================
+// Fence Constants
+// ========================================
+
+/// 柵の高さ
+pub const FENCE_HEIGHT: f32 = 6.0;
+
+/// 柵の支柱の太さ
+pub const FENCE_POST_SIZE: f32 = 1.2;
+
+/// 柵の横木の太さ
+pub const FENCE_RAIL_THICKNESS: f32 = 0.6;
+
+/// 柵の色
+pub const FENCE_COLOR: (f32, f32, f32) = (0.55, 0.38, 0.2);
+
+/// ゲーム開始時に所持している柵の数（種の次のスロットから入れる）
+pub const STARTING_FENCE_COUNT: usize = 3;
+
+// ========================================
+// Torch Constants
+// ========================================
+
+/// 松明の柄の長さ
+pub const TORCH_HEIGHT: f32 = 8.0;
+
+/// 松明の柄の半径
+pub const TORCH_STICK_RADIUS: f32 = 0.6;
+
+/// 松明の炎の半径
+pub const TORCH_FLAME_RADIUS: f32 = 1.2;
+
+/// 松明の光の色（暖色）
+pub const TORCH_LIGHT_COLOR: (f32, f32, f32) = (1.0, 0.6, 0.25);
+
+/// 松明の光の基本の明るさ（ルーメン）
+pub const TORCH_LIGHT_INTENSITY: f32 = 800_000.0;
+
+/// 松明の光が届く距離
+pub const TORCH_LIGHT_RANGE: f32 = 80.0;
+
+/// 明るさの揺らぎの大きさ（基本の明るさに対する割合）
+pub const TORCH_FLICKER_AMOUNT: f32 = 0.15;
+
+/// 明るさの揺らぎの速さ
+pub const TORCH_FLICKER_SPEED: f32 = 12.0;
+
+/// ゲーム開始時に所持している松明の数（柵の次のスロットから入れる）
+pub const STARTING_TORCH_COUNT: usize = 2;
+
+// ========================================
+// Pickaxe Constants
+// ========================================
+
+/// 新品のツルハシで採掘できる回数
+pub const PICKAXE_DURABILITY: u32 = 20;
+
+/// ゲーム開始時に所持しているツルハシの数（松明の次のスロットから入れる）
+pub const STARTING_PICKAXE_COUNT: usize = 1;
+
 // ========================================
 // Lighting Constants
 // ========================================
@@ -59,6 +475,46 @@ pub const SUN_ILLUMINANCE_CLEAR: f32 = 30000.0;
 /// 太陽光の明るさ（雨天時）
 pub const SUN_ILLUMINANCE_RAIN: f32 = 8000.0;
 
+// ========================================
+// Sky Constants
+// ========================================
+
+/// 空のドームの半径（カメラの描画距離より内側）
+pub const SKY_RADIUS: f32 = 900.0;
+
+/// 天頂の空の色
+pub const SKY_ZENITH_COLOR: (f32, f32, f32) = (0.25, 0.5, 0.9);
+
+/// 地平線の空の色
+pub const SKY_HORIZON_COLOR: (f32, f32, f32) = (0.75, 0.85, 0.95);
+
+/// 雨天時に空に掛け合わせる色（暗い灰色）
+pub const SKY_RAIN_TINT: (f32, f32, f32) = (0.45, 0.48, 0.52);
+
+/// 天候変化時の空の色の補間速度（1秒あたりの追従率）
+pub const SKY_TRANSITION_SPEED: f32 = 0.5;
+
+/// 昼間の空に掛け合わせる色
+pub const SKY_DAY_TINT: (f32, f32, f32) = (1.0, 1.0, 1.0);
+
+/// 夕暮れ・明け方の空に掛け合わせる色（橙色）
+pub const SKY_DUSK_TINT: (f32, f32, f32) = (1.0, 0.62, 0.45);
+
+/// 夜の空に掛け合わせる色（暗い藍色）
+pub const SKY_NIGHT_TINT: (f32, f32, f32) = (0.12, 0.15, 0.28);
+
+/// 1日の中での空の色の移り変わり（1日に対する割合と色味）
+///
+/// 1日は明け方から始まり、最後のキーから先頭のキーへ戻って次の日に続く。
+pub const SKY_DAY_CYCLE_TINTS: [(f32, (f32, f32, f32)); 6] = [
+    (0.0, SKY_DUSK_TINT),
+    (0.08, SKY_DAY_TINT),
+    (0.42, SKY_DAY_TINT),
+    (0.5, SKY_DUSK_TINT),
+    (0.58, SKY_NIGHT_TINT),
+    (0.92, SKY_NIGHT_TINT),
+];
+
 // ========================================
 // Weather Constants
 // ========================================
@@ -66,6 +522,12 @@ pub const SUN_ILLUMINANCE_RAIN: f32 = 8000.0;
 /// 雨粒の生成レート（個/秒）
 pub const RAIN_SPAWN_RATE: f32 = 150.0;
 
+/// ストレステストモードでの生成レートの倍率
+pub const RAIN_STRESS_SPAWN_MULTIPLIER: f32 = 50.0;
+
+/// ストレステストモードでFPSをログに出力する間隔（秒）
+pub const RAIN_STRESS_REPORT_INTERVAL: f32 = 1.0;
+
 /// 雨粒の生成高度
 pub const RAIN_SPAWN_HEIGHT: f32 = 200.0;
 
@@ -75,10 +537,68 @@ pub const RAIN_FALL_VELOCITY: f32 = -200.0;
 /// 雨粒のライフタイム（秒）
 pub const RAIN_LIFETIME: f32 = 5.0;
 
-/// 雨粒のサイズ（半径、高さ）
+/// 雨粒のサイズ（半径、高さ）の既定値（[`crate::resources::RainConfig`]）
 pub const RAIN_CAPSULE_RADIUS: f32 = 0.1;
 pub const RAIN_CAPSULE_HEIGHT: f32 = 2.0;
 
+/// 雨粒の設定で指定できる半径・高さの範囲
+pub const RAIN_CAPSULE_RADIUS_MIN: f32 = 0.02;
+pub const RAIN_CAPSULE_RADIUS_MAX: f32 = 1.0;
+pub const RAIN_CAPSULE_HEIGHT_MIN: f32 = 0.2;
+pub const RAIN_CAPSULE_HEIGHT_MAX: f32 = 10.0;
+
+/// 雨を降らせる範囲からこの距離より外に出た雨粒は、地面に届く前に消す
+pub const RAIN_CULL_MARGIN: f32 = BLOCK_SIZE;
+
+/// カメラ追従モードで雨を降らせる範囲の半径（注視点を中心とする正方形の半分の辺）
+pub const RAIN_CAMERA_AREA_HALF_EXTENT: f32 = 120.0;
+
+/// 雨粒が着地した時の水しぶきの半径
+pub const RAIN_SPLASH_RADIUS: f32 = 0.6;
+
+/// 水しぶきが消えるまでの時間（秒）
+pub const RAIN_SPLASH_LIFETIME: f32 = 0.2;
+
+/// 水しぶきが消えるまでに広がる倍率
+pub const RAIN_SPLASH_GROWTH: f32 = 3.0;
+
+/// 雨が降り始めてからブロックが完全に濡れるまでの時間（秒）
+pub const WETNESS_SOAK_SECS: f32 = 20.0;
+
+/// 雨が止んでからブロックが完全に乾くまでの時間（秒）
+pub const WETNESS_DRY_SECS: f32 = 40.0;
+
+/// ブロックを濡れたマテリアルに差し替える濡れ具合
+pub const WETNESS_SHEEN_THRESHOLD: f32 = 0.5;
+
+/// 濡れたブロックのマテリアルの粗さ・反射率・色の暗さ（元の色に掛ける）
+pub const WET_BLOCK_ROUGHNESS: f32 = 0.25;
+pub const WET_BLOCK_REFLECTANCE: f32 = 0.7;
+pub const WET_BLOCK_DARKEN: f32 = 0.85;
+
+/// 本降りの雨で水たまりが最大まで広がるまでの時間（秒）
+pub const PUDDLE_FILL_SECS: f32 = 60.0;
+
+/// 雨が止んでから水たまりが蒸発しきるまでの時間（秒）
+pub const PUDDLE_EVAPORATE_SECS: f32 = 90.0;
+
+/// 水たまりの最大の大きさ（一辺、マスより小さくしてあふれないようにする）
+pub const PUDDLE_MAX_SIZE: f32 = BLOCK_SIZE * 0.8;
+
+/// マスごとの水のたまりやすさ（[`PUDDLE_MAX_SIZE`]に掛ける比率）の下限
+pub const PUDDLE_CAPACITY_MIN: f32 = 0.2;
+
+/// 水たまりのブロック上面からの高さ（上面と重ならないように浮かせる）
+pub const PUDDLE_HEIGHT: f32 = 0.05;
+
+/// 憑依中のキツネが水しぶきを上げる、水たまりの大きさと移動速度の下限
+pub const PUDDLE_SPLASH_MIN_SIZE: f32 = BLOCK_SIZE * 0.25;
+pub const PUDDLE_SPLASH_MIN_SPEED: f32 = 5.0;
+
+/// 水たまりを走っている間に水しぶきを上げる間隔（秒）と、1回に上げる数
+pub const PUDDLE_SPLASH_INTERVAL: f32 = 0.15;
+pub const PUDDLE_SPLASH_COUNT: usize = 4;
+
 /// 天候変化の時間範囲（秒）
 pub const WEATHER_INITIAL_CHANGE_MIN: f32 = 30.0;
 pub const WEATHER_INITIAL_CHANGE_MAX: f32 = 120.0;
@@ -87,6 +607,62 @@ pub const WEATHER_RAIN_DURATION_MAX: f32 = 180.0;
 pub const WEATHER_CLEAR_DURATION_MIN: f32 = 60.0;
 pub const WEATHER_CLEAR_DURATION_MAX: f32 = 300.0;
 
+/// 天候が切り替わる時に晴れ・雨が選ばれる重みの既定値（[`crate::resources::WeatherConfig`]）
+pub const WEATHER_CLEAR_WEIGHT: f32 = 1.0;
+pub const WEATHER_RAIN_WEIGHT: f32 = 1.0;
+
+/// 雨の降り始め・止み際に、雨の強さが0から1（1から0）に変わるまでの時間（秒）
+pub const WEATHER_RAIN_RAMP_SECS: f32 = 5.0;
+
+/// 晴れの残り時間がこの秒数を切ると、天気アイコンをくもりにして雨を予告する
+pub const WEATHER_FORECAST_CLOUD_SECS: f32 = 15.0;
+
+/// 雨の強さがこの値以上の時に稲光が起きる（嵐）
+pub const LIGHTNING_MIN_RAIN_INTENSITY: f32 = 0.8;
+
+/// 稲光の間隔（秒）の範囲
+pub const LIGHTNING_INTERVAL_MIN: f32 = 8.0;
+pub const LIGHTNING_INTERVAL_MAX: f32 = 25.0;
+
+/// 稲光の明るさの最小値（最大は1.0）
+pub const LIGHTNING_BRIGHTNESS_MIN: f32 = 0.3;
+
+/// 明るさ1.0の稲光で太陽光に足す明るさ（lux）
+pub const LIGHTNING_FLASH_ILLUMINANCE: f32 = 40000.0;
+
+/// 明るさ1.0の稲光が消えるまでの時間（秒）
+pub const LIGHTNING_FLASH_FADE_SECS: f32 = 0.25;
+
+/// 稲光から雷鳴が届くまでの遅れ（秒）の範囲（遠い雷ほど遅れる）
+pub const THUNDER_DELAY_MIN: f32 = 0.4;
+pub const THUNDER_DELAY_MAX: f32 = 3.0;
+
+// ========================================
+// Season Constants
+// ========================================
+
+/// ゲーム内の1日の長さ（秒）
+pub const DAY_LENGTH_SECS: f32 = 240.0;
+
+/// 1つの季節が続く日数
+pub const SEASON_LENGTH_DAYS: u32 = 3;
+
+/// 季節ごとに雨（冬は雪）の重みに掛ける倍率
+pub const SEASON_SPRING_RAIN_MULTIPLIER: f32 = 1.8;
+pub const SEASON_SUMMER_RAIN_MULTIPLIER: f32 = 0.6;
+pub const SEASON_AUTUMN_RAIN_MULTIPLIER: f32 = 1.0;
+pub const SEASON_WINTER_RAIN_MULTIPLIER: f32 = 1.2;
+
+/// 冬の植物の成長速度の倍率
+pub const WINTER_PLANT_GROWTH_MULTIPLIER: f32 = 0.3;
+
+/// 雪の落下速度（Y軸方向）と寿命（秒）
+pub const SNOW_FALL_VELOCITY: f32 = -30.0;
+pub const SNOW_LIFETIME: f32 = 10.0;
+
+/// 雪片の半径
+pub const SNOW_FLAKE_RADIUS: f32 = 0.35;
+
 // ========================================
 // UI Constants
 // ========================================
@@ -106,6 +682,158 @@ pub const ITEM_AREA_WIDTH: f32 = 540.0;
 /// アイテムエリアの高さ
 pub const ITEM_AREA_HEIGHT: f32 = 60.0;
 
+/// 日本語表示用フォントのパス（assetsからの相対パス）
+///
+/// 日本語のグリフを含むフォント（Noto Sans JPなど）を配置しておくと、
+/// すべてのテキストのデフォルトフォントとして使われる。
+pub const JAPANESE_FONT_PATH: &str = "fonts/NotoSansJP-Regular.ttf";
+
+/// [`JAPANESE_FONT_PATH`]がない場合に探す、OSに入っている日本語フォントのパス（上から順に探す）
+pub const JAPANESE_SYSTEM_FONT_PATHS: &[&str] = &[
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/opentype/ipafont-gothic/ipagp.ttf",
+    "/usr/share/fonts/truetype/fonts-japanese-gothic.ttf",
+    "/System/Library/Fonts/ヒラギノ角ゴシック W3.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\meiryo.ttc",
+    "C:\\Windows\\Fonts\\msgothic.ttc",
+];
+
+/// フィードバックメッセージを表示しておく時間（秒、フェードアウトを含む）
+pub const FEEDBACK_DISPLAY_SECS: f32 = 4.0;
+
+/// フィードバックメッセージがフェードアウトする時間（秒）
+pub const FEEDBACK_FADE_SECS: f32 = 1.0;
+
+/// タイマー一覧パネルに並べるタイマーの最大行数（超えた分は件数だけ表示する）
+pub const TIMER_PANEL_MAX_LINES: usize = 12;
+
+/// タイマー一覧パネルの文字の大きさ
+pub const TIMER_PANEL_FONT_SIZE: f32 = 14.0;
+
+/// ミニマップの一辺の大きさ（ピクセル）
+pub const MINIMAP_SIZE: f32 = 160.0;
+
+/// ミニマップ上のキツネ・岩・ドロップアイテムの点の大きさ（ピクセル）
+pub const MINIMAP_FOX_MARKER_SIZE: f32 = 8.0;
+pub const MINIMAP_ROCK_MARKER_SIZE: f32 = 6.0;
+pub const MINIMAP_ITEM_MARKER_SIZE: f32 = 4.0;
+
+/// キツネのアクションメニューの幅（ボタン幅と間隔の合計）
+pub const FOX_ACTION_MENU_WIDTH: f32 = 55.0 + 55.0 + 80.0 + 70.0 + 55.0 + 10.0 * 4.0;
+
+/// キツネのアクションメニューの高さ（ボタン行と名前入力欄の合計）
+pub const FOX_ACTION_MENU_HEIGHT: f32 = 30.0 + 6.0 + TEXT_INPUT_HEIGHT;
+
+/// ブロックのアクションメニューの幅（ボタン幅と間隔の合計）
+pub const BLOCK_ACTION_MENU_WIDTH: f32 = 70.0 + 90.0 + 70.0 + 55.0 + 10.0 * 3.0;
+
+/// ブロックのアクションメニューの高さ
+pub const BLOCK_ACTION_MENU_HEIGHT: f32 = 30.0;
+
+/// キツネの画面座標からアクションメニューまでの左方向のオフセット
+pub const FOX_ACTION_MENU_OFFSET_X: f32 = 60.0;
+
+/// キツネの画面座標からアクションメニューまでの上方向のオフセット
+pub const FOX_ACTION_MENU_OFFSET_Y: f32 = 80.0;
+
+/// キツネの鳴き声の周波数（Hz）
+pub const FOX_BARK_FREQUENCY: f32 = 620.0;
+
+/// キツネの鳴き声の長さ（秒）
+pub const FOX_BARK_DURATION_SECS: f32 = 0.12;
+
+/// キツネの鳴き声の音量（0.0〜1.0）
+pub const FOX_BARK_VOLUME: f32 = 0.3;
+
+/// キツネの鳴き声を続けて鳴らせるまでの間隔（秒）
+pub const FOX_BARK_COOLDOWN_SECS: f32 = 0.6;
+
+/// エラー音の周波数（Hz）・長さ（秒）・音量（0.0〜1.0）
+pub const ERROR_SOUND_FREQUENCY: f32 = 180.0;
+pub const ERROR_SOUND_DURATION_SECS: f32 = 0.2;
+pub const ERROR_SOUND_VOLUME: f32 = 0.3;
+
+/// アイテムを拾った時の音の周波数（Hz）・長さ（秒）・音量（0.0〜1.0）
+pub const PICKUP_SOUND_FREQUENCY: f32 = 990.0;
+pub const PICKUP_SOUND_DURATION_SECS: f32 = 0.08;
+pub const PICKUP_SOUND_VOLUME: f32 = 0.25;
+
+/// 雷鳴の周波数（Hz）・長さ（秒）・明るさ1.0の稲光での音量（0.0〜1.0）
+pub const THUNDER_SOUND_FREQUENCY: f32 = 55.0;
+pub const THUNDER_SOUND_DURATION_SECS: f32 = 1.4;
+pub const THUNDER_SOUND_VOLUME: f32 = 0.6;
+
+/// テキスト入力欄の高さ
+pub const TEXT_INPUT_HEIGHT: f32 = 28.0;
+
+/// テキスト入力欄のキャレットの点滅間隔（秒）
+pub const TEXT_INPUT_CARET_BLINK_INTERVAL: f32 = 0.5;
+
+/// キツネの名前の最大文字数
+pub const FOX_NAME_MAX_LENGTH: usize = 12;
+
+/// デバッグコンソールの幅
+pub const DEBUG_CONSOLE_WIDTH: f32 = 520.0;
+
+/// デバッグコンソールに表示するログの行数
+pub const DEBUG_CONSOLE_VISIBLE_LINES: usize = 10;
+
+/// デバッグコンソールが保持するログの最大行数
+pub const DEBUG_CONSOLE_HISTORY_LINES: usize = 100;
+
+/// デバッグコンソールの入力欄の最大文字数
+pub const DEBUG_CONSOLE_INPUT_MAX_LENGTH: usize = 64;
+
+/// 天気アイコンの大きさ
+pub const WEATHER_ICON_SIZE: f32 = 18.0;
+
+/// 選択できるシャドウマップの解像度（2の累乗）
+pub const SHADOW_MAP_SIZES: [u32; 4] = [512, 1024, 2048, 4096];
+
+/// シャドウマップの既定の解像度
+pub const SHADOW_MAP_DEFAULT_SIZE: u32 = 2048;
+
+/// ウィンドウの既定のサイズ（論理ピクセル）
+pub const WINDOW_DEFAULT_WIDTH: u32 = 1280;
+pub const WINDOW_DEFAULT_HEIGHT: u32 = 720;
+
+/// 設定ファイルから読み込むウィンドウの最小サイズ（論理ピクセル）
+pub const WINDOW_MIN_WIDTH: u32 = 320;
+pub const WINDOW_MIN_HEIGHT: u32 = 240;
+
+/// 環境光の明るさの既定値・設定できる最大値・1段階の変化量
+pub const AMBIENT_BRIGHTNESS_DEFAULT: f32 = 80.0;
+pub const AMBIENT_BRIGHTNESS_MAX: f32 = 500.0;
+pub const AMBIENT_BRIGHTNESS_STEP: f32 = 20.0;
+
+/// 太陽の向きを手動で設定する時の方位・高さ（度）の既定値（自動の向きに近い値）
+pub const SUN_AZIMUTH_DEFAULT_DEGREES: f32 = 55.0;
+pub const SUN_ELEVATION_DEFAULT_DEGREES: f32 = 30.0;
+
+/// 太陽の方位・高さを設定画面の+/-ボタン1回で変える量（度）
+pub const SUN_AZIMUTH_STEP_DEGREES: f32 = 15.0;
+pub const SUN_ELEVATION_STEP_DEGREES: f32 = 5.0;
+
+/// 太陽の高さとして設定できる範囲（度）
+pub const SUN_ELEVATION_MIN_DEGREES: f32 = 5.0;
+pub const SUN_ELEVATION_MAX_DEGREES: f32 = 90.0;
+
+/// 設定メニューをマウスホイール1段でスクロールする量（ピクセル）
+pub const SETTINGS_SCROLL_LINE_HEIGHT: f32 = 24.0;
+
+/// 「建築家」の実績に必要な設置数（植物・柵・松明の合計）
+pub const ACHIEVEMENT_BUILDER_COUNT: u32 = 10;
+
+/// 累計プレイ時間をセーブファイルに書き出す間隔（秒）
+pub const PLAYTIME_SAVE_INTERVAL_SECS: f32 = 30.0;
+
+/// 天候と日付をセーブファイルに書き出す間隔（秒）
+pub const WEATHER_SAVE_INTERVAL_SECS: f32 = 30.0;
+
 // ========================================
 // Color Constants
 // ========================================
@@ -122,11 +850,112 @@ pub const HIGHLIGHT_COLOR_NORMAL: (f32, f32, f32, f32) = (1.0, 1.0, 1.0, 0.3);
 /// ハイライト色（移動モード時）
 pub const HIGHLIGHT_COLOR_MOVE: (f32, f32, f32, f32) = (0.0, 1.0, 0.0, 0.4);
 
-/// 雨粒の色
+/// 設置プレビューの色（設置できる場所）
+pub const PLACEMENT_PREVIEW_VALID_COLOR: (f32, f32, f32, f32) = (0.2, 1.0, 0.3, 0.4);
+
+/// 設置プレビューの色（設置できない場所）
+pub const PLACEMENT_PREVIEW_INVALID_COLOR: (f32, f32, f32, f32) = (1.0, 0.2, 0.2, 0.4);
+
+/// キツネの機嫌ごとの色（ティント）: ご機嫌
+pub const FOX_MOOD_HAPPY_TINT: (f32, f32, f32) = (1.0, 1.0, 1.0);
+
+/// キツネの機嫌ごとの色（ティント）: ふつう
+pub const FOX_MOOD_NEUTRAL_TINT: (f32, f32, f32) = (0.9, 0.9, 0.95);
+
+/// キツネの機嫌ごとの色（ティント）: 不機嫌
+pub const FOX_MOOD_UNHAPPY_TINT: (f32, f32, f32) = (0.65, 0.7, 0.9);
+
+/// キツネの機嫌ごとの色（ティント）: おびえている
+pub const FOX_MOOD_SCARED_TINT: (f32, f32, f32) = (0.8, 0.75, 0.95);
+
+/// キツネの空腹度が1秒あたりに増える量
+pub const FOX_HUNGER_RATE: f32 = 1.0 / 240.0;
+
+/// 空腹度がこの値以上のキツネはお腹が空いているとみなす
+pub const FOX_HUNGRY_THRESHOLD: f32 = 0.6;
+
+/// 歩いて向かう途中で諦めたキツネが、再び食べ物を探しに行くまでの秒数
+pub const FOX_FOOD_SEEK_COOLDOWN_SECS: f32 = 10.0;
+
+/// 天気アイコンの色（晴れ・くもり・雨）
+pub const WEATHER_ICON_SUN_COLOR: (f32, f32, f32) = (1.0, 0.85, 0.2);
+pub const WEATHER_ICON_CLOUD_COLOR: (f32, f32, f32) = (0.75, 0.75, 0.8);
+pub const WEATHER_ICON_RAIN_COLOR: (f32, f32, f32) = (0.35, 0.5, 0.85);
+pub const WEATHER_ICON_SNOW_COLOR: (f32, f32, f32) = (0.9, 0.93, 0.98);
+
+/// ブロックのタイマーの色（リセット直後）
+pub const BLOCK_TIMER_FRESH_COLOR: (f32, f32, f32, f32) = (0.2, 0.9, 0.3, 0.25);
+
+/// ブロックのタイマーの色（[`BLOCK_TIMER_STALE_SECS`]以上経過）
+pub const BLOCK_TIMER_STALE_COLOR: (f32, f32, f32, f32) = (0.95, 0.15, 0.1, 0.45);
+
+/// キツネの行動範囲の境界を描く円の色
+pub const LEASH_GIZMO_COLOR: (f32, f32, f32) = (1.0, 0.6, 0.1);
+
+/// 選択可能エリアの枠線の色
+pub const BUILD_AREA_OUTLINE_COLOR: (f32, f32, f32, f32) = (1.0, 1.0, 0.85, 0.6);
+
+/// ミニマップの背景の色
+pub const MINIMAP_BACKGROUND_COLOR: (f32, f32, f32, f32) = (0.1, 0.2, 0.1, 0.6);
+
+/// ミニマップ上のキツネの点の色（通常・憑依中）
+pub const MINIMAP_FOX_COLOR: (f32, f32, f32) = (1.0, 0.6, 0.2);
+pub const MINIMAP_POSSESSED_FOX_COLOR: (f32, f32, f32) = (0.3, 0.9, 1.0);
+
+/// ミニマップ上の岩の点の色
+pub const MINIMAP_ROCK_COLOR: (f32, f32, f32) = (0.6, 0.6, 0.6);
+
+/// ミニマップ上のドロップアイテムの点の色
+pub const MINIMAP_ITEM_COLOR: (f32, f32, f32) = (1.0, 0.95, 0.4);
+
+/// 石のブロックの色
+pub const STONE_BLOCK_COLOR: (f32, f32, f32) = (0.5, 0.5, 0.52);
+
+/// 水のブロックの色
+pub const WATER_BLOCK_COLOR: (f32, f32, f32, f32) = (0.2, 0.45, 0.75, 0.85);
+
+/// 波紋の色（出た時）
+pub const RIPPLE_COLOR: (f32, f32, f32, f32) = (0.85, 0.93, 1.0, 0.7);
+
+/// スロットがいっぱいの時などのエラーのフィードバックメッセージの色
+pub const FEEDBACK_ERROR_COLOR: (f32, f32, f32) = (1.0, 0.35, 0.3);
+
+/// 選択中のキツネの足元に出す輪の色
+pub const FOX_SELECTION_RING_COLOR: (f32, f32, f32, f32) = (1.0, 0.85, 0.3, 0.8);
+
+/// 水たまりの色
+pub const PUDDLE_COLOR: (f32, f32, f32, f32) = (0.35, 0.45, 0.6, 0.55);
+
+/// 季節ごとに草のブロックのマテリアルに掛ける色（春はそのまま）
+pub const SEASON_SUMMER_FIELD_TINT: (f32, f32, f32) = (0.9, 1.0, 0.8);
+pub const SEASON_AUTUMN_FIELD_TINT: (f32, f32, f32) = (1.2, 0.8, 0.45);
+pub const SEASON_WINTER_FIELD_TINT: (f32, f32, f32) = (1.3, 1.35, 1.45);
+
+/// 季節ごとに空に掛ける色（春はそのまま）
+pub const SEASON_SUMMER_SKY_TINT: (f32, f32, f32) = (0.95, 1.0, 1.05);
+pub const SEASON_AUTUMN_SKY_TINT: (f32, f32, f32) = (1.05, 0.95, 0.85);
+pub const SEASON_WINTER_SKY_TINT: (f32, f32, f32) = (0.9, 0.93, 1.0);
+
+/// 雪片の色
+pub const SNOW_COLOR: (f32, f32, f32, f32) = (0.95, 0.97, 1.0, 0.9);
+
+/// 土ぼこりの色
+pub const DUST_COLOR: (f32, f32, f32, f32) = (0.65, 0.55, 0.4, 0.7);
+
+/// 雨粒の色（本降りの時）
 pub const RAIN_COLOR: (f32, f32, f32, f32) = (0.7, 0.8, 1.0, 0.6);
 
+/// 降り始め・降り終わりの小雨の雨粒の色（薄く明るい）
+pub const RAIN_DRIZZLE_COLOR: (f32, f32, f32, f32) = (0.8, 0.85, 1.0, 0.2);
+
 /// 選択されたスロットのボーダー色
 pub const SELECTED_SLOT_BORDER_COLOR: (f32, f32, f32) = (1.0, 0.8, 0.0);
 
 /// 通常のスロットのボーダー色
 pub const NORMAL_SLOT_BORDER_COLOR: (f32, f32, f32) = (0.5, 0.5, 0.5);
+
+/// 設定メニューのタブボタンの背景色（選択中）
+pub const SETTINGS_TAB_ACTIVE_COLOR: (f32, f32, f32) = (0.3, 0.5, 0.7);
+
+/// 設定メニューのタブボタンの背景色（未選択）
+pub const SETTINGS_TAB_INACTIVE_COLOR: (f32, f32, f32) = (0.25, 0.25, 0.25);
@@ -0,0 +1,67 @@
+//! ゲームプレイ用のメッセージ（イベント）を定義するモジュール
+//!
+//! 入力の判定とゲームプレイの反応を分離するため、クリック対象の判定結果を
+//! メッセージとして送信し、各システムが必要なものだけを購読する。
+
+use bevy::prelude::*;
+
+/// 選択可能なブロックがクリックされたことを通知するメッセージ
+#[derive(Message)]
+pub struct BlockClicked {
+    pub entity: Entity,
+    /// クリックした視線とブロックの交点（ワールド座標）
+    pub hit_point: Vec3,
+}
+
+/// キツネがクリックされたことを通知するメッセージ
+#[derive(Message)]
+pub struct FoxClicked(pub Entity);
+
+/// 岩がクリックされたことを通知するメッセージ
+#[derive(Message)]
+pub struct RockClicked(pub Entity);
+
+/// 採掘できるブロック（石のブロック）がクリックされたことを通知するメッセージ
+#[derive(Message)]
+pub struct MineableBlockClicked(pub Entity);
+
+/// 選択可能なブロックか採掘できるブロックが右クリックされたことを通知するメッセージ
+#[derive(Message)]
+pub struct BlockRightClicked(pub Entity);
+
+/// クリック可能な対象がない場所がクリックされたことを通知するメッセージ
+#[derive(Message)]
+pub struct EmptySpaceClicked;
+
+/// 岩を壊して鉱石を掘り出したことを通知するメッセージ
+#[derive(Message)]
+pub struct RockMined;
+
+/// キツネへの憑依を開始したことを通知するメッセージ
+#[derive(Message)]
+pub struct FoxPossessed;
+
+/// 植物・柵・松明を設置したことを通知するメッセージ
+#[derive(Message)]
+pub struct ObjectBuilt;
+
+/// タイマー（[`cf_tool::timer::Timer`](crate::cf_tool::timer::Timer)）の経過時間が
+/// しきい値を超えたことを通知するメッセージ
+///
+/// クリックなどでタイマーが0に戻ると、再びしきい値を超えた時に送信される。
+#[derive(Message)]
+pub struct TimerThresholdReached(pub Entity);
+
+/// アイテムスロットに空きがなく、アイテムを格納できなかったことを通知するメッセージ
+///
+/// [`show_inventory_full`](crate::cf_systems::show_inventory_full)が
+/// 赤いフィードバックメッセージとエラー音に変える。
+#[derive(Message)]
+pub struct InventoryFull;
+
+/// テキスト入力欄でEnterが押され、入力が確定したことを通知するメッセージ
+#[derive(Message)]
+pub struct TextInputSubmitted {
+    pub input: Entity,
+    pub value: String,
+}
@@ -0,0 +1,184 @@
+//! レベルファイルからフィールドの配置を読み込むモジュール
+//!
+//! `--level <name>`を指定すると`assets/levels/<name>.ron`を読み込み、
+//! 手順生成の代わりにファイルに書かれたマスごとの種類・選択可能か・高さでフィールドを作る。
+//! ファイルは以下の形式で、書かれていないマスは選択不可の草のブロックになる：
+//!
+//! ```ron
+//! (
+//!     size: 9,
+//!     tiles: [
+//!         (x: 4, z: 4, kind: Grass, selectable: true),
+//!         (x: 0, z: 0, kind: Stone, height: 1),
+//!         (x: 1, z: 0, kind: Water),
+//!     ],
+//! )
+//! ```
+//!
+//! デバッグコンソールの`level export <name>`で、今のフィールドを同じ形式で書き出せる。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::components::BlockType;
+use crate::constants::*;
+
+/// レベルファイルの内容
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LevelFile {
+    pub size: i32,
+    #[serde(default)]
+    pub tiles: Vec<LevelTile>,
+}
+
+impl LevelFile {
+    /// 指定した名前のレベルファイルとして書き出し、書き出したパスを返す
+    ///
+    /// マスは1マス1行で並べ、[`FieldLevel::load`]でそのまま読み込める形式で保存する。
+    pub fn save(&self, name: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let path = FieldLevel::path(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::new().depth_limit(2))?;
+        fs::write(&path, text)?;
+        Ok(path)
+    }
+}
+
+/// レベルファイルの1マス分の設定
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct LevelTile {
+    pub x: i32,
+    pub z: i32,
+    #[serde(default)]
+    pub kind: BlockType,
+    #[serde(default)]
+    pub selectable: bool,
+    /// 地面からの高さ（ブロック数）
+    #[serde(default)]
+    pub height: i32,
+}
+
+/// 検証済みのフィールドの配置
+///
+/// すべてのマスを`x * size + z`の順に並べて持つ。
+#[derive(Clone, Debug)]
+pub struct FieldLevel {
+    pub size: i32,
+    tiles: Vec<LevelTile>,
+}
+
+impl FieldLevel {
+    /// レベル名からファイルのパスを取得
+    pub fn path(name: &str) -> PathBuf {
+        Path::new(LEVELS_DIR).join(format!("{}.ron", name))
+    }
+
+    /// レベル名として使えるか（英数字・`-`・`_`のみ）
+    ///
+    /// `assets/levels`の外を指せないよう、パスの区切りや`..`を含む名前は受け付けない。
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// レベルファイルを読み込んで検証する
+    ///
+    /// 読み込めない場合やサイズが不正な場合は警告を出して`None`を返し、
+    /// 呼び出し側は手順生成にフォールバックする。
+    pub fn load(name: &str) -> Option<Self> {
+        let path = Self::path(name);
+        let level = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| ron::from_str::<LevelFile>(&text).map_err(|e| e.to_string()))
+            .and_then(Self::from_file);
+
+        match level {
+            Ok(level) => Some(level),
+            Err(e) => {
+                eprintln!(
+                    "レベル {} を読み込めませんでした（{}）。フィールドを自動生成します",
+                    path.display(),
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// レベルファイルの内容を検証し、全マスの配置を組み立てる
+    ///
+    /// サイズが範囲外ならエラーにする。範囲外のマスは無視し、重複したマスは後の設定を使い、
+    /// 範囲外の高さは補正する。いずれも警告を出して読み込みは続ける。
+    pub fn from_file(file: LevelFile) -> Result<Self, String> {
+        if !(FIELD_SIZE_MIN..=FIELD_SIZE_MAX).contains(&file.size) {
+            return Err(format!(
+                "size は {}〜{} の範囲で指定してください（{}）",
+                FIELD_SIZE_MIN, FIELD_SIZE_MAX, file.size
+            ));
+        }
+
+        let size = file.size;
+        let mut tiles: Vec<Option<LevelTile>> = vec![None; (size * size) as usize];
+        for mut tile in file.tiles {
+            if !(0..size).contains(&tile.x) || !(0..size).contains(&tile.z) {
+                eprintln!(
+                    "レベルのマス ({}, {}) はフィールドの範囲外のため無視します",
+                    tile.x, tile.z
+                );
+                continue;
+            }
+            if !(0..=LEVEL_TILE_HEIGHT_MAX).contains(&tile.height) {
+                let height = tile.height.clamp(0, LEVEL_TILE_HEIGHT_MAX);
+                eprintln!(
+                    "レベルのマス ({}, {}) の高さ {} を {} に補正しました",
+                    tile.x, tile.z, tile.height, height
+                );
+                tile.height = height;
+            }
+
+            let slot = &mut tiles[(tile.x * size + tile.z) as usize];
+            if slot.is_some() {
+                eprintln!(
+                    "レベルのマス ({}, {}) が重複しています。後の設定を使用します",
+                    tile.x, tile.z
+                );
+            }
+            *slot = Some(tile);
+        }
+
+        let missing = tiles.iter().filter(|tile| tile.is_none()).count();
+        if missing > 0 {
+            eprintln!(
+                "レベルに書かれていない {} マスは選択不可の草のブロックにします",
+                missing
+            );
+        }
+
+        let tiles = tiles
+            .into_iter()
+            .enumerate()
+            .map(|(index, tile)| {
+                tile.unwrap_or(LevelTile {
+                    x: index as i32 / size,
+                    z: index as i32 % size,
+                    ..Default::default()
+                })
+            })
+            .collect();
+
+        Ok(Self { size, tiles })
+    }
+
+    /// グリッド座標のマスの設定（範囲外なら`None`）
+    pub fn tile(&self, x: i32, z: i32) -> Option<&LevelTile> {
+        if !(0..self.size).contains(&x) || !(0..self.size).contains(&z) {
+            return None;
+        }
+        self.tiles.get((x * self.size + z) as usize)
+    }
+}
@@ -0,0 +1,579 @@
+//! ユーザー向け文字列の多言語対応モジュール
+//!
+//! 呼び出し側に文字列を直接書かず、[`TextKey`]をキーとして
+//! [`Locale::t`]で現在の言語の文字列を引く。
+//! 値を埋め込む文字列は`{}`をプレースホルダとして[`Locale::tf`]で展開する。
+
+use bevy::prelude::*;
+
+use crate::components::ItemType;
+use crate::traits::Storable;
+
+/// 表示言語
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Locale {
+    #[default]
+    Japanese,
+    English,
+}
+
+/// 翻訳対象の文字列キー
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextKey {
+    // フィードバックメッセージ
+    ItemSelected,
+    ItemDeselected,
+    EmptySlotSelected,
+    ItemPlaced,
+    ItemNotPlaceable,
+    PickaxeRequired,
+    PickaxeDurability,
+    PickaxeBroken,
+    ItemDropped,
+    ItemPickedUp,
+    FoxPickedUpItem,
+    SeedPlanted,
+    AlreadyPlanted,
+    FencePlaced,
+    FenceRemoved,
+    TorchPlaced,
+    TorchRemoved,
+    BlockOccupied,
+    PlantGrowing,
+    CropHarvested,
+    AchievementUnlocked,
+    AchievementFirstOreMined,
+    AchievementFirstPossession,
+    AchievementBuilder,
+    FoxAtePlant,
+    FoxAtePlantSeedReturned,
+    SlotsFull,
+    TimerReset,
+    FoxPlaced,
+    SelectableBlocksOnly,
+    MoveModeStarted,
+    FoxStored,
+    PossessionStarted,
+    PossessionEnded,
+    FoxResetToSpawn,
+    FoxGrew,
+    FoxGrownUp,
+    RockMining,
+    RockMined,
+    StoneBlockMining,
+    StoneBlockMined,
+    NoTimers,
+    MoreTimers,
+    BlockInspect,
+    BlockInspectMineable,
+    BlockTypeGrass,
+    BlockTypeStone,
+    BlockTypeWater,
+    SpectatorStarted,
+    SpectatorEnded,
+    BuildAreaExpanded,
+    NotEnoughOre,
+    BuildAreaMaxed,
+    BuildAreaOutlineShown,
+    BuildAreaOutlineHidden,
+    FoxRenamed,
+    PlacementGridChanged,
+    FoxWalking,
+    FoxMoveStyleWalk,
+    FoxMoveStyleTeleport,
+    Undone,
+    Redone,
+    NothingToUndo,
+    NothingToRedo,
+    CannotUndo,
+    CameraRecordingStarted,
+    CameraRecordingSaved,
+    CameraPlaybackStarted,
+    CameraPlaybackFinished,
+    NoCameraRecording,
+
+    // HUD
+    ResourcesLabel,
+    WeatherForecast,
+    PlaytimeLabel,
+    WeatherSunny,
+    WeatherCloudy,
+    WeatherRainy,
+    WeatherSnowy,
+    SeasonDay,
+    SeasonSpring,
+    SeasonSummer,
+    SeasonAutumn,
+    SeasonWinter,
+
+    // デバッグコンソール
+    ConsoleHelp,
+    ConsoleUnknownCommand,
+    ConsoleInvalidArgs,
+    ConsoleFoxSpawned,
+    ConsoleNoFox,
+    ConsoleWeatherSet,
+    ConsoleItemsGiven,
+    ConsoleFieldResized,
+    ConsoleTeleported,
+    ConsoleEntityCount,
+    ConsoleSettingsExported,
+    ConsoleSettingsExportFailed,
+    ConsoleSettingsImported,
+    ConsoleSettingsImportFailed,
+    ConsoleLevelExported,
+    ConsoleLevelExportFailed,
+    ConsoleRainConfigChanged,
+    ConsoleRainConfigSaved,
+    ConsoleRainConfigSaveFailed,
+
+    // メインメニュー
+    MainMenuTitle,
+    MainMenuStart,
+    MainMenuContinue,
+    MainMenuQuit,
+    ReturnToMenu,
+
+    // 設定メニュー
+    SettingsTitle,
+    CameraSettingsHeader,
+    MouseSensitivity,
+    PossessionSensitivity,
+    DashFovKick,
+    DashTimeout,
+    KeyboardSensitivity,
+    MovementSpeed,
+    ZoomSpeed,
+    GamepadDeadzone,
+    SaveSettings,
+    LoadSettings,
+    ConfirmLoadSettings,
+    Yes,
+    No,
+    LanguageLabel,
+    LanguageButton,
+    ControlsHeader,
+    ControlsHelp,
+    PressEscToClose,
+    SettingsTabCamera,
+    SettingsTabControls,
+    SettingsTabAudio,
+    SettingsTabGraphics,
+    NoSettingsInTab,
+    ShadowsLabel,
+    ShadowResolution,
+    AmbientBrightness,
+    ManualSun,
+    SunAzimuth,
+    SunElevation,
+    SettingOn,
+    SettingOff,
+    ToggleButton,
+
+    // キツネのアクションメニュー
+    MoveButton,
+    BoxButton,
+    PossessionButton,
+    FollowButton,
+    CancelButton,
+    FoxFollowStarted,
+    FoxFollowStopped,
+    ClearItemButton,
+    ResetTimerButton,
+    InspectButton,
+    BreakButton,
+    BlockNothingToClear,
+    BlockNoTimer,
+    BlockNotBreakable,
+}
+
+impl Locale {
+    /// もう一方の言語に切り替える
+    pub fn toggle(&mut self) {
+        *self = match self {
+            Locale::Japanese => Locale::English,
+            Locale::English => Locale::Japanese,
+        };
+    }
+
+    /// キーに対応する現在の言語の文字列を返す
+    pub fn t(self, key: TextKey) -> &'static str {
+        match self {
+            Locale::Japanese => japanese(key),
+            Locale::English => english(key),
+        }
+    }
+
+    /// キーに対応する文字列の`{}`を引数で順に置き換えて返す
+    pub fn tf(self, key: TextKey, args: &[&dyn std::fmt::Display]) -> String {
+        let mut parts = self.t(key).split("{}");
+        let mut text = parts.next().unwrap_or_default().to_string();
+        for (part, arg) in parts.zip(args) {
+            text.push_str(&arg.to_string());
+            text.push_str(part);
+        }
+        text
+    }
+
+    /// アイテムの表示名を返す
+    pub fn item_name(self, item: &ItemType) -> &'static str {
+        match self {
+            Locale::Japanese => item.display_name(),
+            Locale::English => match item {
+                ItemType::Fox => "Fox",
+                ItemType::Seed => "Seed",
+                ItemType::Produce => "Produce",
+                ItemType::Ore => "Ore",
+                ItemType::Fence => "Fence",
+                ItemType::Torch => "Torch",
+                ItemType::Pickaxe { .. } => "Pickaxe",
+            },
+        }
+    }
+}
+
+/// 日本語の文字列テーブル
+fn japanese(key: TextKey) -> &'static str {
+    match key {
+        TextKey::ItemSelected => "アイテムを選択しました: {}",
+        TextKey::ItemDeselected => "アイテムの選択を解除しました",
+        TextKey::EmptySlotSelected => "空きスロット{}をキツネの格納先に選びました",
+        TextKey::ItemPlaced => "アイテムを設置しました！",
+        TextKey::ItemNotPlaceable => "このアイテムは設置できません",
+        TextKey::PickaxeRequired => "採掘するにはツルハシを選択してください",
+        TextKey::PickaxeDurability => "（ツルハシの耐久度: {}）",
+        TextKey::PickaxeBroken => "ツルハシが壊れてしまいました！",
+        TextKey::ItemDropped => "{}を落としました",
+        TextKey::ItemPickedUp => "{}を拾いました！",
+        TextKey::FoxPickedUpItem => "キツネが{}を拾ってきました！",
+        TextKey::SeedPlanted => "種を植えました！",
+        TextKey::AlreadyPlanted => "このブロックには既に植えられています",
+        TextKey::FencePlaced => "柵を設置しました！",
+        TextKey::FenceRemoved => "柵を撤去しました",
+        TextKey::TorchPlaced => "松明を設置しました！",
+        TextKey::TorchRemoved => "松明を回収しました",
+        TextKey::BlockOccupied => "このブロックには既に何かが置かれています",
+        TextKey::PlantGrowing => "成長中です... {}%",
+        TextKey::CropHarvested => "作物を収穫しました！",
+        TextKey::AchievementUnlocked => "実績解除: {}",
+        TextKey::AchievementFirstOreMined => "はじめての鉱石",
+        TextKey::AchievementFirstPossession => "キツネになりきる",
+        TextKey::AchievementBuilder => "建築家（10個設置）",
+        TextKey::FoxAtePlant => "キツネが作物を食べました",
+        TextKey::FoxAtePlantSeedReturned => "キツネが作物を食べました（種を1つ回収）",
+        TextKey::SlotsFull => "アイテムスロットがいっぱいです！",
+        TextKey::TimerReset => "{}をクリック！タイマーをリセットしました！",
+        TextKey::FoxPlaced => "キツネを設置しました！",
+        TextKey::SelectableBlocksOnly => "選択可能なブロックにのみ設置できます！",
+        TextKey::MoveModeStarted => "移動モード: 移動先をクリックして設置してください",
+        TextKey::FoxStored => "キツネをアイテムエリアに格納しました！",
+        TextKey::PossessionStarted => {
+            "Possessionモード: WASDキーでキツネを操作できます (Tで初期位置に戻る / Escで解除)"
+        }
+        TextKey::PossessionEnded => "Possessionモードを解除しました",
+        TextKey::FoxResetToSpawn => "キツネを初期位置に戻しました",
+        TextKey::FoxGrew => "{}が少し大きくなりました！",
+        TextKey::FoxGrownUp => "{}が大人になりました！",
+        TextKey::RockMining => "採掘中... 残り{}回",
+        TextKey::RockMined => "岩を採掘しました！鉱石が落ちています",
+        TextKey::StoneBlockMining => "石のブロックを採掘中... 残り{}回",
+        TextKey::StoneBlockMined => "石のブロックを掘り崩しました！鉱石が落ちています",
+        TextKey::NoTimers => "タイマーなし",
+        TextKey::MoreTimers => "他 {} 件",
+        TextKey::BlockInspect => "ブロック ({}, {})\n種類: {}\n選択可能: {}\nタイマー: {}",
+        TextKey::BlockInspectMineable => "採掘: 残り{}回",
+        TextKey::BlockTypeGrass => "草",
+        TextKey::BlockTypeStone => "石",
+        TextKey::BlockTypeWater => "水",
+        TextKey::SpectatorStarted => {
+            "スペクテイターモード: WASD/Q/Eで自由に移動できます (Shiftで加速、Fで解除)"
+        }
+        TextKey::SpectatorEnded => "スペクテイターモードを解除しました",
+        TextKey::BuildAreaExpanded => "建築エリアが広がりました！新しく{}マス解放されました",
+        TextKey::NotEnoughOre => "鉱石が足りません（{}個必要です）",
+        TextKey::BuildAreaMaxed => "建築エリアはこれ以上広げられません",
+        TextKey::BuildAreaOutlineShown => "建築エリアの枠線を表示します",
+        TextKey::BuildAreaOutlineHidden => "建築エリアの枠線を隠しました",
+        TextKey::FoxRenamed => "キツネの名前を「{}」にしました",
+        TextKey::PlacementGridChanged => "配置グリッド: 1ブロックを{}x{}に分割",
+        TextKey::FoxWalking => "キツネが移動先に向かっています",
+        TextKey::FoxMoveStyleWalk => "キツネの移動: 歩いて向かう",
+        TextKey::FoxMoveStyleTeleport => "キツネの移動: 掴んで瞬間移動",
+        TextKey::Undone => "元に戻しました",
+        TextKey::Redone => "やり直しました",
+        TextKey::NothingToUndo => "元に戻す操作はありません",
+        TextKey::NothingToRedo => "やり直す操作はありません",
+        TextKey::CannotUndo => "状況が変わったため、この操作は元に戻せません",
+        TextKey::CameraRecordingStarted => "カメラの動きを記録中...（F5で停止）",
+        TextKey::CameraRecordingSaved => "カメラの動きを記録しました（{}秒）",
+        TextKey::CameraPlaybackStarted => "カメラの動きを再生中...（F6で停止）",
+        TextKey::CameraPlaybackFinished => "カメラの動きの再生を終了しました",
+        TextKey::NoCameraRecording => "記録されたカメラの動きがありません（F5で記録）",
+
+        TextKey::ResourcesLabel => "鉱石: {}  コイン: {}",
+        TextKey::WeatherForecast => "{}  次の変化まで {}",
+        TextKey::PlaytimeLabel => "プレイ時間: {}",
+        TextKey::WeatherSunny => "晴れ",
+        TextKey::WeatherCloudy => "くもり",
+        TextKey::WeatherRainy => "雨",
+        TextKey::WeatherSnowy => "雪",
+        TextKey::SeasonDay => "{} {}日目",
+        TextKey::SeasonSpring => "春",
+        TextKey::SeasonSummer => "夏",
+        TextKey::SeasonAutumn => "秋",
+        TextKey::SeasonWinter => "冬",
+
+        TextKey::ConsoleHelp => {
+            "コマンド: spawn fox / weather <rain|clear> / give <item> <数> / field <サイズ> / tp <x> <y> <z> / entities / settings <export|import> <パス> / level export <名前> / rain <radius|length|alpha> <値> / rain save"
+        }
+        TextKey::ConsoleUnknownCommand => "不明なコマンドです: {}（helpで一覧を表示）",
+        TextKey::ConsoleInvalidArgs => "引数が正しくありません。使い方: {}",
+        TextKey::ConsoleFoxSpawned => "キツネを生成しました",
+        TextKey::ConsoleNoFox => "キツネが見つかりません",
+        TextKey::ConsoleWeatherSet => "天候を{}にしました",
+        TextKey::ConsoleItemsGiven => "{}を{}個追加しました",
+        TextKey::ConsoleFieldResized => "フィールドを{}x{}で作り直しました",
+        TextKey::ConsoleTeleported => "キツネを({}, {}, {})に移動しました",
+        TextKey::ConsoleEntityCount => "エンティティ数: {}（ブロック: {}個）",
+        TextKey::ConsoleSettingsExported => "設定を{}に書き出しました",
+        TextKey::ConsoleSettingsExportFailed => "設定を{}に書き出せませんでした: {}",
+        TextKey::ConsoleSettingsImported => "{}から設定を読み込みました",
+        TextKey::ConsoleSettingsImportFailed => "{}から設定を読み込めませんでした: {}",
+        TextKey::ConsoleLevelExported => "フィールドを{}に書き出しました（ブロック: {}個）",
+        TextKey::ConsoleLevelExportFailed => "レベル{}を書き出せませんでした: {}",
+        TextKey::ConsoleRainConfigChanged => "雨粒の{}を{}にしました",
+        TextKey::ConsoleRainConfigSaved => "雨粒の設定を保存しました",
+        TextKey::ConsoleRainConfigSaveFailed => "雨粒の設定を保存できませんでした: {}",
+
+        TextKey::MainMenuTitle => "クラフトファーム",
+        TextKey::MainMenuStart => "スタート",
+        TextKey::MainMenuContinue => "つづける",
+        TextKey::MainMenuQuit => "終了",
+        TextKey::ReturnToMenu => "メインメニューへ",
+
+        TextKey::SettingsTitle => "設定",
+        TextKey::CameraSettingsHeader => "カメラ設定",
+        TextKey::MouseSensitivity => "マウス感度: {}",
+        TextKey::PossessionSensitivity => "憑依中のマウス感度: {}",
+        TextKey::DashFovKick => "ダッシュ時の視野の広がり: {}°",
+        TextKey::DashTimeout => "ダッシュのダブルタップ間隔: {}秒",
+        TextKey::KeyboardSensitivity => "キーボード感度: {}",
+        TextKey::MovementSpeed => "移動速度: {}",
+        TextKey::ZoomSpeed => "ズーム速度: {}",
+        TextKey::GamepadDeadzone => "スティックのデッドゾーン: {}",
+        TextKey::SaveSettings => "設定を保存",
+        TextKey::LoadSettings => "設定を読込",
+        TextKey::ConfirmLoadSettings => "保存していない変更は失われます。\n設定を読み込みますか？",
+        TextKey::Yes => "はい",
+        TextKey::No => "いいえ",
+        TextKey::LanguageLabel => "言語: 日本語",
+        TextKey::LanguageButton => "English",
+        TextKey::ControlsHeader => "操作方法",
+        TextKey::ControlsHelp => {
+            "WASD - 移動\n矢印キー - 回転\nマウスドラッグ - 回転\nホイール - ズーム\nテンキー 7/1/3 - 上/正面/側面ビュー\nQ - アイテムを落とす\nF - スペクテイターモード\nB - 鉱石で建築エリアを拡張\nO - 建築エリアの枠線の表示切り替え\nG - 配置グリッドの切り替え\nM - キツネの移動方法の切り替え\nF11 - フルスクリーンの切り替え\nF5 / F6 - カメラの動きの記録 / 再生\nF8 - 雨の範囲（フィールド / カメラ追従）\nAlt + ホバー - ブロックの情報\n右クリック - ブロックのメニュー\nX - キツネの追従をやめる\nP - 選択中のキツネに憑依 / 解除\nCtrl+Z / Ctrl+Y - 元に戻す / やり直し\n` - デバッグコンソール\nESC - 切り替え\nゲームパッド: 左スティック - 移動 / 右スティック - 回転 / LT・RT - ズーム / A - 憑依"
+        }
+        TextKey::PressEscToClose => "ESCで閉じる",
+        TextKey::SettingsTabCamera => "カメラ",
+        TextKey::SettingsTabControls => "操作",
+        TextKey::SettingsTabAudio => "サウンド",
+        TextKey::SettingsTabGraphics => "グラフィック",
+        TextKey::NoSettingsInTab => "このタブにはまだ設定項目がありません",
+        TextKey::ShadowsLabel => "影: {}",
+        TextKey::ShadowResolution => "影の解像度: {}",
+        TextKey::AmbientBrightness => "環境光の明るさ: {}",
+        TextKey::ManualSun => "太陽の向きを手動で設定: {}",
+        TextKey::SunAzimuth => "太陽の方位: {}°",
+        TextKey::SunElevation => "太陽の高さ: {}°",
+        TextKey::SettingOn => "オン",
+        TextKey::SettingOff => "オフ",
+        TextKey::ToggleButton => "切り替え",
+
+        TextKey::MoveButton => "移動",
+        TextKey::BoxButton => "格納",
+        TextKey::PossessionButton => "憑依",
+        TextKey::FollowButton => "ついて来て",
+        TextKey::CancelButton => "閉じる",
+        TextKey::FoxFollowStarted => "キツネがカメラについて来ます（Xでやめる）",
+        TextKey::FoxFollowStopped => "キツネがついて来るのをやめました",
+        TextKey::ClearItemButton => "片付ける",
+        TextKey::ResetTimerButton => "タイマーをリセット",
+        TextKey::InspectButton => "調べる",
+        TextKey::BreakButton => "壊す",
+        TextKey::BlockNothingToClear => "このブロックの上には何もありません",
+        TextKey::BlockNoTimer => "このブロックにはタイマーがありません",
+        TextKey::BlockNotBreakable => "このブロックは壊せません",
+    }
+}
+
+/// 英語の文字列テーブル
+fn english(key: TextKey) -> &'static str {
+    match key {
+        TextKey::ItemSelected => "Selected item: {}",
+        TextKey::ItemDeselected => "Item deselected",
+        TextKey::EmptySlotSelected => "Empty slot {} will be used to store the fox",
+        TextKey::ItemPlaced => "Item placed!",
+        TextKey::ItemNotPlaceable => "This item can't be placed",
+        TextKey::PickaxeRequired => "Select a pickaxe to mine",
+        TextKey::PickaxeDurability => "(pickaxe durability: {})",
+        TextKey::PickaxeBroken => "Your pickaxe broke!",
+        TextKey::ItemDropped => "Dropped {}",
+        TextKey::ItemPickedUp => "Picked up {}!",
+        TextKey::FoxPickedUpItem => "The fox brought back {}!",
+        TextKey::SeedPlanted => "Seed planted!",
+        TextKey::AlreadyPlanted => "Something is already planted here",
+        TextKey::FencePlaced => "Fence placed!",
+        TextKey::FenceRemoved => "Fence removed",
+        TextKey::TorchPlaced => "Torch placed!",
+        TextKey::TorchRemoved => "Torch picked up",
+        TextKey::BlockOccupied => "This block is already occupied",
+        TextKey::PlantGrowing => "Growing... {}%",
+        TextKey::CropHarvested => "Crop harvested!",
+        TextKey::AchievementUnlocked => "Achievement unlocked: {}",
+        TextKey::AchievementFirstOreMined => "First Ore Mined",
+        TextKey::AchievementFirstPossession => "Possessed the Fox",
+        TextKey::AchievementBuilder => "Builder (placed 10 objects)",
+        TextKey::FoxAtePlant => "The fox ate a crop",
+        TextKey::FoxAtePlantSeedReturned => "The fox ate a crop (got a seed back)",
+        TextKey::SlotsFull => "Item slots are full!",
+        TextKey::TimerReset => "{} clicked! Timer reset!",
+        TextKey::FoxPlaced => "Fox placed!",
+        TextKey::SelectableBlocksOnly => "You can only place on selectable blocks!",
+        TextKey::MoveModeStarted => "Move mode: click a destination to place the fox",
+        TextKey::FoxStored => "Fox stored in the item area!",
+        TextKey::PossessionStarted => {
+            "Possession mode: control the fox with WASD (T to reset to spawn / Esc to exit)"
+        }
+        TextKey::PossessionEnded => "Possession mode ended",
+        TextKey::FoxResetToSpawn => "Moved the fox back to its spawn point",
+        TextKey::FoxGrew => "{} has grown a little!",
+        TextKey::FoxGrownUp => "{} is all grown up!",
+        TextKey::RockMining => "Mining... {} hits left",
+        TextKey::RockMined => "Rock mined! Ore has dropped",
+        TextKey::StoneBlockMining => "Mining stone block... {} hits left",
+        TextKey::StoneBlockMined => "Stone block mined out! Ore has dropped",
+        TextKey::NoTimers => "No timers",
+        TextKey::MoreTimers => "+{} more",
+        TextKey::BlockInspect => "Block ({}, {})\nType: {}\nSelectable: {}\nTimer: {}",
+        TextKey::BlockInspectMineable => "Mining: {} hits left",
+        TextKey::BlockTypeGrass => "Grass",
+        TextKey::BlockTypeStone => "Stone",
+        TextKey::BlockTypeWater => "Water",
+        TextKey::SpectatorStarted => {
+            "Spectator mode: fly freely with WASD/Q/E (Shift to boost, F to exit)"
+        }
+        TextKey::SpectatorEnded => "Spectator mode ended",
+        TextKey::BuildAreaExpanded => "Build area expanded! {} new tiles unlocked",
+        TextKey::NotEnoughOre => "Not enough ore ({} needed)",
+        TextKey::BuildAreaMaxed => "The build area can't be expanded any further",
+        TextKey::BuildAreaOutlineShown => "Showing the build area outline",
+        TextKey::BuildAreaOutlineHidden => "Build area outline hidden",
+        TextKey::FoxRenamed => "Renamed the fox to \"{}\"",
+        TextKey::PlacementGridChanged => "Placement grid: {}x{} per block",
+        TextKey::FoxWalking => "The fox is heading to the destination",
+        TextKey::FoxMoveStyleWalk => "Fox movement: walk there",
+        TextKey::FoxMoveStyleTeleport => "Fox movement: pick up and teleport",
+        TextKey::Undone => "Undone",
+        TextKey::Redone => "Redone",
+        TextKey::NothingToUndo => "Nothing to undo",
+        TextKey::NothingToRedo => "Nothing to redo",
+        TextKey::CannotUndo => "Things have changed, so this action can't be reverted",
+        TextKey::CameraRecordingStarted => "Recording camera path... (F5 to stop)",
+        TextKey::CameraRecordingSaved => "Camera path recorded ({}s)",
+        TextKey::CameraPlaybackStarted => "Playing camera path... (F6 to stop)",
+        TextKey::CameraPlaybackFinished => "Camera path playback finished",
+        TextKey::NoCameraRecording => "No camera path recorded (F5 to record)",
+
+        TextKey::ResourcesLabel => "Ore: {}  Coins: {}",
+        TextKey::WeatherForecast => "{}  next change in {}",
+        TextKey::PlaytimeLabel => "Playtime: {}",
+        TextKey::WeatherSunny => "Sunny",
+        TextKey::WeatherCloudy => "Cloudy",
+        TextKey::WeatherRainy => "Rain",
+        TextKey::WeatherSnowy => "Snow",
+        TextKey::SeasonDay => "{} day {}",
+        TextKey::SeasonSpring => "Spring",
+        TextKey::SeasonSummer => "Summer",
+        TextKey::SeasonAutumn => "Autumn",
+        TextKey::SeasonWinter => "Winter",
+
+        TextKey::ConsoleHelp => {
+            "Commands: spawn fox / weather <rain|clear> / give <item> <count> / field <size> / tp <x> <y> <z> / entities / settings <export|import> <path> / level export <name> / rain <radius|length|alpha> <value> / rain save"
+        }
+        TextKey::ConsoleUnknownCommand => "Unknown command: {} (type help for a list)",
+        TextKey::ConsoleInvalidArgs => "Invalid arguments. Usage: {}",
+        TextKey::ConsoleFoxSpawned => "Spawned a fox",
+        TextKey::ConsoleNoFox => "No fox found",
+        TextKey::ConsoleWeatherSet => "Weather set to {}",
+        TextKey::ConsoleItemsGiven => "Added {} x{}",
+        TextKey::ConsoleFieldResized => "Rebuilt the field at {}x{}",
+        TextKey::ConsoleTeleported => "Moved the fox to ({}, {}, {})",
+        TextKey::ConsoleEntityCount => "Entities: {} (blocks: {})",
+        TextKey::ConsoleSettingsExported => "Exported settings to {}",
+        TextKey::ConsoleSettingsExportFailed => "Could not export settings to {}: {}",
+        TextKey::ConsoleSettingsImported => "Imported settings from {}",
+        TextKey::ConsoleSettingsImportFailed => "Could not import settings from {}: {}",
+        TextKey::ConsoleLevelExported => "Exported the field to {} ({} blocks)",
+        TextKey::ConsoleLevelExportFailed => "Could not export level {}: {}",
+        TextKey::ConsoleRainConfigChanged => "Set rain drop {} to {}",
+        TextKey::ConsoleRainConfigSaved => "Saved the rain drop settings",
+        TextKey::ConsoleRainConfigSaveFailed => "Could not save the rain drop settings: {}",
+
+        TextKey::MainMenuTitle => "Craft Farm",
+        TextKey::MainMenuStart => "Start",
+        TextKey::MainMenuContinue => "Continue",
+        TextKey::MainMenuQuit => "Quit",
+        TextKey::ReturnToMenu => "Main Menu",
+
+        TextKey::SettingsTitle => "Settings",
+        TextKey::CameraSettingsHeader => "Camera Settings",
+        TextKey::MouseSensitivity => "Mouse Sensitivity: {}",
+        TextKey::PossessionSensitivity => "Possession Mouse Sensitivity: {}",
+        TextKey::DashFovKick => "Dash FOV Kick: {}°",
+        TextKey::DashTimeout => "Dash Double-Tap Window: {}s",
+        TextKey::KeyboardSensitivity => "Keyboard Sensitivity: {}",
+        TextKey::MovementSpeed => "Movement Speed: {}",
+        TextKey::ZoomSpeed => "Zoom Speed: {}",
+        TextKey::GamepadDeadzone => "Stick Deadzone: {}",
+        TextKey::SaveSettings => "Save Settings",
+        TextKey::LoadSettings => "Load Settings",
+        TextKey::ConfirmLoadSettings => "Unsaved changes will be lost.\nLoad settings from file?",
+        TextKey::Yes => "Yes",
+        TextKey::No => "No",
+        TextKey::LanguageLabel => "Language: English",
+        TextKey::LanguageButton => "日本語",
+        TextKey::ControlsHeader => "Controls",
+        TextKey::ControlsHelp => {
+            "WASD - Move\nArrows - Rotate\nMouse Drag - Rotate\nWheel - Zoom\nNumpad 7/1/3 - Top/Front/Side View\nQ - Drop Item\nF - Spectator Mode\nB - Expand Build Area with Ore\nO - Toggle Build Area Outline\nG - Cycle Placement Grid\nM - Toggle Fox Move Style\nF11 - Toggle Fullscreen\nF5 / F6 - Record / Play Camera Path\nF8 - Rain Area (Field / Follow Camera)\nAlt + Hover - Inspect Block\nRight Click - Block Menu\nX - Stop Fox Following\nP - Possess Selected Fox / Exit\nCtrl+Z / Ctrl+Y - Undo / Redo\n` - Debug Console\nESC - Toggle\nGamepad: Left Stick - Move / Right Stick - Rotate / LT/RT - Zoom / A - Possession"
+        }
+        TextKey::PressEscToClose => "Press ESC to close",
+        TextKey::SettingsTabCamera => "Camera",
+        TextKey::SettingsTabControls => "Controls",
+        TextKey::SettingsTabAudio => "Audio",
+        TextKey::SettingsTabGraphics => "Graphics",
+        TextKey::NoSettingsInTab => "No settings in this tab yet",
+        TextKey::ShadowsLabel => "Shadows: {}",
+        TextKey::ShadowResolution => "Shadow Resolution: {}",
+        TextKey::AmbientBrightness => "Ambient Brightness: {}",
+        TextKey::ManualSun => "Manual Sun: {}",
+        TextKey::SunAzimuth => "Sun Azimuth: {}°",
+        TextKey::SunElevation => "Sun Elevation: {}°",
+        TextKey::SettingOn => "On",
+        TextKey::SettingOff => "Off",
+        TextKey::ToggleButton => "Toggle",
+
+        TextKey::MoveButton => "Move",
+        TextKey::BoxButton => "Box",
+        TextKey::PossessionButton => "Possession",
+        TextKey::FollowButton => "Follow",
+        TextKey::CancelButton => "Cancel",
+        TextKey::FoxFollowStarted => "The fox is following the camera (X to stop)",
+        TextKey::FoxFollowStopped => "The fox stopped following",
+        TextKey::ClearItemButton => "Clear",
+        TextKey::ResetTimerButton => "Reset Timer",
+        TextKey::InspectButton => "Inspect",
+        TextKey::BreakButton => "Break",
+        TextKey::BlockNothingToClear => "There is nothing on this block",
+        TextKey::BlockNoTimer => "This block has no timer",
+        TextKey::BlockNotBreakable => "This block can't be broken",
+    }
+}
@@ -121,7 +121,13 @@ pub mod button_styles {
     }
 
     impl ButtonStyle {
-        pub const fn new(width: f32, height: f32, font_size: f32, bg: (f32, f32, f32), border: (f32, f32, f32)) -> Self {
+        pub const fn new(
+            width: f32,
+            height: f32,
+            font_size: f32,
+            bg: (f32, f32, f32),
+            border: (f32, f32, f32),
+        ) -> Self {
             Self {
                 width,
                 height,
@@ -133,12 +139,18 @@ pub mod button_styles {
     }
 
     /// 定義済みボタンスタイル
-    pub const MOVE_BUTTON: ButtonStyle = ButtonStyle::new(55.0, 30.0, 14.0, (0.3, 0.5, 0.7), (0.5, 0.7, 0.9));
-    pub const BOX_BUTTON: ButtonStyle = ButtonStyle::new(55.0, 30.0, 14.0, (0.5, 0.4, 0.3), (0.7, 0.6, 0.5));
-    pub const POSSESSION_BUTTON: ButtonStyle = ButtonStyle::new(80.0, 30.0, 12.0, (0.6, 0.3, 0.6), (0.8, 0.5, 0.8));
-    pub const SAVE_BUTTON: ButtonStyle = ButtonStyle::new(120.0, 40.0, 18.0, (0.2, 0.6, 0.2), (0.3, 0.7, 0.3));
-    pub const LOAD_BUTTON: ButtonStyle = ButtonStyle::new(120.0, 40.0, 18.0, (0.2, 0.4, 0.7), (0.3, 0.5, 0.8));
-    pub const SETTING_BUTTON: ButtonStyle = ButtonStyle::new(30.0, 30.0, 20.0, (0.4, 0.4, 0.4), (0.5, 0.5, 0.5));
+    pub const MOVE_BUTTON: ButtonStyle =
+        ButtonStyle::new(55.0, 30.0, 14.0, (0.3, 0.5, 0.7), (0.5, 0.7, 0.9));
+    pub const BOX_BUTTON: ButtonStyle =
+        ButtonStyle::new(55.0, 30.0, 14.0, (0.5, 0.4, 0.3), (0.7, 0.6, 0.5));
+    pub const POSSESSION_BUTTON: ButtonStyle =
+        ButtonStyle::new(80.0, 30.0, 12.0, (0.6, 0.3, 0.6), (0.8, 0.5, 0.8));
+    pub const SAVE_BUTTON: ButtonStyle =
+        ButtonStyle::new(120.0, 40.0, 18.0, (0.2, 0.6, 0.2), (0.3, 0.7, 0.3));
+    pub const LOAD_BUTTON: ButtonStyle =
+        ButtonStyle::new(120.0, 40.0, 18.0, (0.2, 0.4, 0.7), (0.3, 0.5, 0.8));
+    pub const SETTING_BUTTON: ButtonStyle =
+        ButtonStyle::new(30.0, 30.0, 20.0, (0.4, 0.4, 0.4), (0.5, 0.5, 0.5));
 }
 
 /// スタイル付きボタンを生成するマクロ
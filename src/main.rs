@@ -3,8 +3,12 @@ mod macros;
 mod cf_mesh;
 mod cf_systems;
 mod cf_tool;
+mod cli;
 mod components;
 mod constants;
+mod events;
+mod level;
+mod locale;
 mod plugins;
 mod resources;
 mod traits;
@@ -12,10 +16,38 @@ mod traits;
 use bevy::prelude::*;
 use bevy::render::RenderPlugin;
 use cf_systems::setup;
+use cli::LaunchArgs;
+use components::MainCamera;
+use level::FieldLevel;
 use plugins::*;
-use resources::CameraSettings;
+use resources::{
+    Achievements, BuildArea, CameraRecording, CameraSettings, FieldConfig, GameRng, GameState,
+    Playtime, RainConfig, RainStressMode, Resources, WeatherConfig, WeatherSave, WeatherState,
+};
+use traits::JsonSave;
 
 fn main() {
+    let args = LaunchArgs::parse();
+    let settings = CameraSettings::load_or_default();
+    let mut rng = GameRng::new(args.seed);
+    let weather_config = WeatherConfig::load_or_default();
+    // `--rain`の指定がなければ、前回終了時の天候と日付から再開する
+    let weather_save = WeatherSave::load_or_default();
+    let initial_weather = match weather_save.weather {
+        Some(weather) if !args.rain => weather,
+        _ => WeatherState::new(args.rain, &weather_config, &mut rng.0),
+    };
+    let level = args.level.as_deref().and_then(FieldLevel::load);
+    let field_config = FieldConfig {
+        size: level
+            .as_ref()
+            .map(|level| level.size)
+            .or(args.field_size)
+            .unwrap_or(constants::FIELD_SIZE),
+        batched: args.batch_field,
+        level,
+    };
+
     App::new()
         .add_plugins(
             DefaultPlugins
@@ -23,15 +55,42 @@ fn main() {
                     render_creation: bevy::render::settings::RenderCreation::Automatic(
                         bevy::render::settings::WgpuSettings {
                             backends: Some(bevy::render::settings::Backends::VULKAN),
-                            power_preference: bevy::render::settings::PowerPreference::HighPerformance,
+                            power_preference:
+                                bevy::render::settings::PowerPreference::HighPerformance,
                             ..default()
                         },
                     ),
                     ..default()
+                })
+                .set(WindowPlugin {
+                    primary_window: Some(cf_systems::primary_window(&settings)),
+                    ..default()
                 }),
         )
-        .insert_resource(CameraSettings::load_or_default())
-        .add_plugins((CameraPlugin, UIPlugin, GameLogicPlugin, WeatherPlugin))
-        .add_systems(Startup, setup)
+        .insert_resource(settings)
+        .insert_resource(Resources::load_or_default())
+        .insert_resource(BuildArea::load_or_default())
+        .insert_resource(Playtime::load_or_default())
+        .insert_resource(Achievements::load_or_default())
+        .insert_resource(CameraRecording::load_or_default())
+        .insert_resource(rng)
+        .insert_resource(weather_config)
+        .insert_resource(RainConfig::load_or_default())
+        .insert_resource(initial_weather)
+        .insert_resource(weather_save.calendar.unwrap_or_default())
+        .insert_resource(field_config)
+        .insert_resource(RainStressMode::new(args.rain_stress))
+        .add_plugins((
+            MainMenuPlugin,
+            CameraPlugin,
+            UIPlugin,
+            GameLogicPlugin,
+            WeatherPlugin,
+        ))
+        // 初めてゲームを始めた時だけワールドを生成する（メニューから戻った場合は既存のものを使う）
+        .add_systems(
+            OnEnter(GameState::InGame),
+            setup.run_if(not(any_with_component::<MainCamera>)),
+        )
         .run();
 }
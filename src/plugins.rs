@@ -1,11 +1,44 @@
 use bevy::prelude::*;
-use rand::Rng;
 
 use crate::cf_systems;
 use crate::cf_tool;
-use crate::constants::*;
+use crate::events::*;
+use crate::locale::Locale;
 use crate::resources::*;
 
+/// ゲーム中（[`GameState::InGame`]）だけ動かすシステムのセット
+///
+/// メインメニューを開いている間は、このセットのシステムは実行されない。
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameplaySystems;
+
+/// メインメニューとゲーム状態の管理プラグイン
+pub struct MainMenuPlugin;
+
+impl Plugin for MainMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>()
+            .configure_sets(Update, GameplaySystems.run_if(in_state(GameState::InGame)))
+            .configure_sets(
+                PostUpdate,
+                GameplaySystems.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                OnEnter(GameState::MainMenu),
+                (cf_systems::pause_world, cf_systems::spawn_main_menu),
+            )
+            .add_systems(OnExit(GameState::MainMenu), cf_systems::despawn_main_menu)
+            .add_systems(OnEnter(GameState::InGame), cf_systems::resume_world)
+            .add_systems(
+                Update,
+                (
+                    cf_systems::handle_main_menu_buttons.run_if(in_state(GameState::MainMenu)),
+                    cf_systems::handle_return_to_menu_button.in_set(GameplaySystems),
+                ),
+            );
+    }
+}
+
 /// カメラ制御プラグイン
 pub struct CameraPlugin;
 
@@ -13,18 +46,41 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MouseDragState>()
             .init_resource::<PossessionMode>()
+            .init_resource::<CameraViewTransition>()
+            .init_resource::<CameraTarget>()
+            .init_resource::<CameraMode>()
             .add_systems(
                 Update,
                 (
+                    cf_systems::camera_view_presets
+                        .before(cf_systems::apply_camera_view_transition),
+                    cf_systems::apply_camera_view_transition,
                     cf_systems::camera_zoom,
                     cf_systems::camera_drag_rotation,
                     cf_systems::camera_keyboard_rotation,
                     cf_systems::camera_keyboard_pan,
+                    cf_systems::toggle_spectator_mode,
+                    cf_systems::spectator_camera_movement,
+                    cf_systems::camera_gamepad_rotation.before(cf_systems::camera_follow_target),
                     // Possessionモードのシステムは順序が重要
-                    cf_systems::possession_camera_rotation
-                        .before(cf_systems::possession_camera_follow),
-                    cf_systems::possession_camera_follow,
-                ),
+                    cf_systems::possession_camera_rotation.before(cf_systems::camera_follow_target),
+                    cf_systems::sync_possession_camera_target
+                        .before(cf_systems::camera_follow_target),
+                    cf_systems::camera_follow_target,
+                    cf_systems::possession_cursor_grab,
+                    cf_systems::dash_fov_kick,
+                    cf_systems::toggle_camera_recording,
+                    cf_systems::toggle_camera_playback,
+                )
+                    .in_set(GameplaySystems),
+            )
+            // カメラを動かす他のシステムの後で、再生中の姿勢で上書きしてから記録する
+            .add_systems(
+                PostUpdate,
+                (cf_systems::play_camera_path, cf_systems::record_camera_path)
+                    .chain()
+                    .in_set(GameplaySystems)
+                    .before(bevy::transform::TransformSystems::Propagate),
             );
     }
 }
@@ -34,17 +90,64 @@ pub struct UIPlugin;
 
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<SettingsMenuState>().add_systems(
-            Update,
-            (
-                cf_systems::toggle_settings_menu,
-                cf_systems::handle_setting_buttons,
-                cf_systems::update_setting_value_texts,
-                cf_systems::update_item_slot_display,
-                cf_systems::update_item_slot_highlight,
-                cf_systems::handle_item_slot_click,
-            ),
-        );
+        app.init_resource::<SettingsMenuState>()
+            .init_resource::<SettingsTab>()
+            .init_resource::<cf_systems::JapaneseFont>()
+            .init_resource::<Locale>()
+            .add_message::<TextInputSubmitted>()
+            .init_resource::<DebugConsoleState>()
+            .add_systems(
+                PreUpdate,
+                cf_systems::text_input_keyboard.after(bevy::input::InputSystems),
+            )
+            .add_systems(
+                Last,
+                (cf_systems::save_playtime, cf_systems::save_window_settings),
+            )
+            .add_systems(
+                Update,
+                (
+                    cf_systems::apply_japanese_default_font,
+                    cf_systems::toggle_fullscreen,
+                    cf_systems::track_window_size,
+                    cf_systems::deselect_item_on_right_click.in_set(GameplaySystems),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    cf_systems::toggle_settings_menu,
+                    cf_systems::handle_setting_buttons,
+                    cf_systems::handle_settings_tab_buttons,
+                    cf_systems::update_settings_tab_display,
+                    cf_systems::scroll_settings_menu,
+                    (
+                        cf_systems::apply_shadow_settings,
+                        cf_systems::apply_ambient_light,
+                        cf_systems::apply_sun_angle,
+                    ),
+                    cf_systems::refresh_localized_ui,
+                    cf_systems::update_setting_value_texts,
+                    cf_systems::update_resources_text,
+                    cf_systems::update_weather_display,
+                    cf_systems::update_playtime_text,
+                    cf_systems::save_resources,
+                    cf_systems::update_item_slot_display,
+                    cf_systems::update_item_slot_highlight,
+                    cf_systems::handle_item_slot_click,
+                    cf_systems::focus_text_input,
+                    cf_systems::update_text_input_display,
+                    cf_systems::update_text_input_ime,
+                    cf_systems::toggle_debug_console,
+                    (
+                        cf_systems::submit_debug_console_command,
+                        cf_systems::run_debug_console_commands,
+                        cf_systems::update_debug_console_log,
+                    )
+                        .chain(),
+                )
+                    .in_set(GameplaySystems),
+            );
     }
 }
 
@@ -53,23 +156,150 @@ pub struct GameLogicPlugin;
 
 impl Plugin for GameLogicPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<FoxMoveMode>()
+        app.add_plugins(MeshPickingPlugin)
+            .init_resource::<FoxMoveMode>()
+            .init_resource::<FoxGroupSelection>()
             .init_resource::<SelectedItemSlot>()
             .init_resource::<DashInputState>()
             .init_resource::<cf_systems::FoxAnimationClips>()
+            .init_resource::<cf_systems::SoundEffects>()
+            .init_resource::<cf_systems::DroppedItemAssets>()
+            .init_resource::<cf_systems::StoneBlockAssets>()
+            .init_resource::<cf_systems::PlantAssets>()
+            .init_resource::<cf_systems::FenceAssets>()
+            .init_resource::<cf_systems::TorchAssets>()
+            .init_resource::<FencedEdges>()
+            .init_resource::<WaterTiles>()
+            .init_resource::<BuildAreaOutline>()
+            .init_resource::<PlacementGrid>()
+            .init_resource::<cf_systems::PlacementPreviewAssets>()
+            .init_resource::<cf_systems::DustAssets>()
+            .init_resource::<cf_systems::WaterAssets>()
+            .init_resource::<cf_systems::FoxSelectionAssets>()
+            .init_resource::<cf_systems::BlockTimerTintAssets>()
+            .init_resource::<ActionHistory>()
+            .init_resource::<FoxMoveStyle>()
+            .init_resource::<HoveredBlock>()
+            .add_message::<BlockClicked>()
+            .add_message::<FoxClicked>()
+            .add_message::<RockClicked>()
+            .add_message::<MineableBlockClicked>()
+            .add_message::<EmptySpaceClicked>()
+            .add_message::<BlockRightClicked>()
+            .add_message::<RockMined>()
+            .add_message::<FoxPossessed>()
+            .add_message::<ObjectBuilt>()
+            .add_message::<TimerThresholdReached>()
+            .add_message::<InventoryFull>()
+            .add_systems(Last, cf_systems::save_torches)
+            // クリック判定（ピッキングのオブザーバー） → 各反応の順に実行し、
+            // 反応側はクリック前の状態を参照する
+            .add_systems(
+                Update,
+                (
+                    cf_systems::track_click_drag,
+                    cf_systems::emit_empty_space_clicks,
+                    cf_systems::handle_fox_action_menu_click,
+                    cf_systems::handle_click_interaction,
+                    cf_systems::handle_item_placement,
+                    cf_systems::handle_move_mode_placement,
+                    cf_systems::mine_rock_on_click,
+                    cf_systems::mine_block_on_click,
+                )
+                    .chain()
+                    .in_set(GameplaySystems),
+            )
             .add_systems(
                 Update,
                 (
                     cf_systems::block_hover_highlight,
-                    cf_systems::block_click_handler,
                     cf_systems::handle_fox_action_buttons,
+                    cf_systems::apply_fox_name_input,
+                    cf_systems::cycle_placement_grid,
+                    cf_systems::update_placement_preview,
                     cf_systems::fox_follow_cursor,
                     cf_systems::exit_possession_mode,
+                    cf_systems::toggle_possession,
                     cf_systems::fox_possession_movement,
                     cf_systems::play_fox_animation,
+                    cf_systems::drop_selected_item,
+                    cf_systems::spin_dropped_items,
+                    cf_systems::pickup_dropped_items,
+                    cf_systems::update_plant_growth,
+                    (
+                        cf_systems::flicker_torches,
+                        cf_systems::restore_saved_torches,
+                    ),
+                    cf_systems::respawn_rocks,
+                    cf_systems::expand_build_area.before(cf_systems::apply_build_area),
+                    (cf_systems::apply_build_area, cf_systems::save_build_area),
                     cf_tool::timer::update_timers,
-                    cf_tool::timer::update_timer_ui,
-                ),
+                    (
+                        cf_tool::timer::update_timer_ui,
+                        cf_tool::timer::update_timer_panel,
+                    ),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(
+                Update,
+                (
+                    cf_systems::handle_undo_redo,
+                    cf_systems::toggle_fox_move_style,
+                    cf_systems::update_fox_pickable,
+                    cf_systems::seek_shelter_in_rain.before(cf_systems::fox_path_to),
+                    cf_systems::fox_path_to.after(cf_systems::handle_move_mode_placement),
+                    cf_systems::update_fox_shelter,
+                    (
+                        cf_systems::increase_fox_hunger,
+                        cf_systems::update_fox_mood
+                            .after(cf_systems::update_fox_shelter)
+                            .after(cf_systems::increase_fox_hunger),
+                    ),
+                    cf_systems::apply_fox_mood_tint.after(cf_systems::update_fox_mood),
+                    (cf_systems::update_dust, cf_systems::update_ripples),
+                    (
+                        cf_systems::seek_food_when_hungry.before(cf_systems::fox_path_to),
+                        cf_systems::eat_plant,
+                    ),
+                    cf_systems::unlock_achievements.after(cf_systems::mine_rock_on_click),
+                    cf_systems::attach_block_timers,
+                    cf_systems::update_block_timer_tint.after(cf_tool::timer::update_timers),
+                    cf_systems::puff_stale_blocks.after(cf_tool::timer::update_timers),
+                    cf_systems::show_inventory_full
+                        .after(cf_systems::handle_click_interaction)
+                        .after(cf_systems::handle_fox_action_buttons)
+                        .after(cf_systems::handle_block_action_buttons),
+                    cf_systems::auto_pickup.after(cf_systems::pickup_dropped_items),
+                    cf_systems::update_fox_leash,
+                    cf_systems::draw_leash_gizmos,
+                    cf_systems::reset_possessed_fox.before(cf_systems::fox_possession_movement),
+                    cf_systems::cleanup_after_possession
+                        .after(cf_systems::exit_possession_mode)
+                        .after(cf_systems::toggle_possession)
+                        .before(cf_systems::block_hover_highlight),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(
+                Update,
+                (
+                    cf_systems::grow_fox,
+                    cf_systems::update_fox_selection,
+                    cf_systems::follow_fox_selection_ring.after(cf_systems::update_fox_selection),
+                    cf_systems::inspect_block,
+                    cf_systems::follow_camera_focus.before(cf_systems::fox_path_to),
+                    cf_systems::toggle_build_area_outline,
+                    cf_systems::draw_build_area_outline.after(cf_systems::apply_build_area),
+                    cf_systems::fade_feedback_text.after(cf_systems::show_inventory_full),
+                    cf_systems::update_minimap,
+                    cf_systems::handle_minimap_click,
+                    cf_systems::handle_block_action_menu_click,
+                    cf_systems::handle_block_action_buttons
+                        .before(cf_systems::mine_block_on_click)
+                        .before(cf_systems::inspect_block),
+                )
+                    .in_set(GameplaySystems),
             );
     }
 }
@@ -79,19 +309,48 @@ pub struct WeatherPlugin;
 
 impl Plugin for WeatherPlugin {
     fn build(&self, app: &mut App) {
-        let mut rng = rand::rng();
-        app.insert_resource(WeatherState {
-            is_raining: false,
-            time_until_change: rng
-                .random_range(WEATHER_INITIAL_CHANGE_MIN..WEATHER_INITIAL_CHANGE_MAX),
-        })
-        .add_systems(
-            Update,
-            (
-                cf_systems::update_weather,
-                cf_systems::spawn_rain,
-                cf_systems::update_rain,
-            ),
-        );
+        // 起動引数で挿入済みの場合はそちらを優先する
+        app.init_resource::<GameRng>()
+            .init_resource::<WeatherConfig>()
+            .init_resource::<WeatherState>()
+            .init_resource::<RainStressMode>()
+            .init_resource::<RainArea>()
+            .init_resource::<RainConfig>()
+            .init_resource::<Calendar>()
+            .init_resource::<Lightning>()
+            .init_resource::<ThunderQueue>()
+            .init_resource::<cf_systems::RainDropAssets>()
+            .init_resource::<cf_systems::RainSplashAssets>()
+            .init_resource::<Wetness>()
+            .init_resource::<cf_systems::BlockWetMaterials>()
+            .init_resource::<cf_systems::PuddleAssets>()
+            .init_resource::<cf_systems::SeasonMaterials>()
+            .add_systems(Last, cf_systems::save_weather)
+            .add_systems(
+                Update,
+                (
+                    cf_systems::advance_calendar,
+                    cf_systems::update_weather.after(cf_systems::advance_calendar),
+                    cf_systems::apply_season_tint.after(cf_systems::advance_calendar),
+                    cf_systems::spawn_rain,
+                    cf_systems::update_rain_color.after(cf_systems::update_weather),
+                    cf_systems::apply_rain_config,
+                    cf_systems::update_rain,
+                    cf_systems::update_rain_splashes,
+                    cf_systems::update_sky,
+                    cf_systems::toggle_rain_stress_mode,
+                    cf_systems::toggle_rain_area,
+                    cf_systems::report_rain_stress,
+                    cf_systems::update_wetness,
+                    cf_systems::apply_block_wetness.after(cf_systems::update_wetness),
+                    cf_systems::spawn_puddles,
+                    cf_systems::update_puddles.after(cf_systems::spawn_puddles),
+                    cf_systems::splash_through_puddles.after(cf_systems::update_puddles),
+                    cf_systems::update_lightning.after(cf_systems::update_weather),
+                    (cf_systems::play_thunder, cf_systems::update_fox_spooked)
+                        .after(cf_systems::update_lightning),
+                )
+                    .in_set(GameplaySystems),
+            );
     }
 }
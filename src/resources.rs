@@ -1,28 +1,152 @@
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::path::PathBuf;
+use std::collections::VecDeque;
 
-use crate::components::ItemType;
-use crate::traits::GameMode;
+use crate::components::{FenceSide, ItemType};
+use crate::constants::*;
+use crate::level::FieldLevel;
+use crate::traits::{GameMode, JsonSave};
 
 // ========================================
 // Game State Resources
 // ========================================
 
+/// ゲーム全体の状態
+///
+/// 起動時はメインメニューから始まり、スタートでゲーム本編に移る。
+/// ゲーム中のシステムは[`crate::plugins::GameplaySystems`]に入れ、
+/// [`GameState::InGame`]の間だけ動かす。
+#[derive(States, Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GameState {
+    #[default]
+    MainMenu,
+    InGame,
+}
+
 /// マウスドラッグの状態を追跡するリソース
 #[derive(Resource, Default)]
 pub struct MouseDragState {
     pub is_dragging: bool,
     pub last_position: Option<Vec2>,
+    /// 左ボタンを押した時のカーソル位置
+    pub press_position: Option<Vec2>,
+    /// 左ボタンを押してからカーソルが[`CLICK_DRAG_THRESHOLD`]より動いたか
+    ///
+    /// ボタンを離した後のクリック判定で使うため、次に押すまで残す。
+    pub moved_since_press: bool,
+}
+
+/// 視点プリセットへのカメラ補間の目標を保持するリソース
+#[derive(Resource, Default)]
+pub struct CameraViewTransition {
+    pub target: Option<Transform>,
+}
+
+/// カメラの操作モード
+///
+/// スペクテイターモードではブロックのホバー・クリックなどの
+/// ゲーム操作を無効化し、カメラを自由に飛ばせる。
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CameraMode {
+    /// 通常の建築用カメラ
+    #[default]
+    Build,
+    /// ゲーム操作を行わない自由飛行カメラ
+    Spectator,
+}
+
+impl CameraMode {
+    pub fn is_spectator(self) -> bool {
+        self == CameraMode::Spectator
+    }
 }
 
 /// Fox移動モードの状態を追跡するリソース
+///
+/// 複数のキツネをまとめて動かせるよう、移動中のキツネを一覧で持つ。
+/// 先頭のキツネがカーソルやクリックした位置に置かれ、
+/// 残りはその周りに[`FoxMoveMode::formation_offset`]の隊列で並ぶ。
 #[derive(Resource, Default)]
 pub struct FoxMoveMode {
     pub is_active: bool,
     pub is_holding: bool,
-    pub fox_entity: Option<Entity>,
+    /// 移動中のキツネと、移動を始める前の位置（元に戻す操作で使う）
+    pub foxes: Vec<(Entity, Vec3)>,
+}
+
+impl FoxMoveMode {
+    /// キツネを掴んで移動モードを開始する
+    pub fn start(&mut self, foxes: Vec<(Entity, Vec3)>) {
+        self.is_active = true;
+        self.is_holding = true;
+        self.foxes = foxes;
+    }
+
+    /// 指定したキツネを移動中か
+    pub fn contains(&self, fox: Entity) -> bool {
+        self.foxes.iter().any(|(entity, _)| *entity == fox)
+    }
+
+    /// `index`番目のキツネの、基準の位置からのずれ
+    ///
+    /// 先頭のキツネは基準の位置に、残りはその周りの円周上に等間隔で並べる。
+    /// 1匹だけの場合は従来どおりずれない。
+    pub fn formation_offset(&self, index: usize) -> Vec3 {
+        if index == 0 {
+            return Vec3::ZERO;
+        }
+        let followers = (self.foxes.len() - 1).max(1) as f32;
+        let angle = (index - 1) as f32 / followers * std::f32::consts::TAU;
+        Vec3::new(angle.cos(), 0.0, angle.sin()) * FOX_FORMATION_SPACING
+    }
+}
+
+/// Shiftクリックでまとめて選んだキツネ
+///
+/// アクションメニューから移動を始めると、メニューを開いたキツネと一緒に
+/// ここに入っているキツネも[`FoxMoveMode`]で移動する。
+#[derive(Resource, Default)]
+pub struct FoxGroupSelection {
+    pub foxes: Vec<Entity>,
+}
+
+impl FoxGroupSelection {
+    /// キツネを選択に加える。既に選ばれていれば選択から外す
+    pub fn toggle(&mut self, fox: Entity) {
+        if let Some(index) = self.foxes.iter().position(|entity| *entity == fox) {
+            self.foxes.remove(index);
+        } else {
+            self.foxes.push(fox);
+        }
+    }
+
+    /// 指定したキツネが選ばれているか
+    pub fn contains(&self, fox: Entity) -> bool {
+        self.foxes.contains(&fox)
+    }
+}
+
+/// 移動モードでのキツネの動かし方
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FoxMoveStyle {
+    /// クリックしたブロックまで歩かせる
+    #[default]
+    Walk,
+    /// 掴んでクリックしたブロックに瞬間移動させる
+    Teleport,
+}
+
+impl FoxMoveStyle {
+    /// もう一方の動かし方に切り替える
+    pub fn toggle(&mut self) {
+        *self = match self {
+            FoxMoveStyle::Walk => FoxMoveStyle::Teleport,
+            FoxMoveStyle::Teleport => FoxMoveStyle::Walk,
+        };
+    }
 }
 
 impl GameMode for FoxMoveMode {
@@ -37,7 +161,7 @@ impl GameMode for FoxMoveMode {
     fn deactivate(&mut self) {
         self.is_active = false;
         self.is_holding = false;
-        self.fox_entity = None;
+        self.foxes.clear();
     }
 
     fn allows_camera_control(&self) -> bool {
@@ -50,12 +174,42 @@ impl GameMode for FoxMoveMode {
 }
 
 /// Possession（憑依）モードの状態を追跡するリソース
-#[derive(Resource, Default)]
+///
+/// `camera_distance`と`camera_height`は追従カメラのキツネからの距離で、
+/// 憑依中のホイール操作で変更でき、憑依し直しても保持される。
+#[derive(Resource)]
 pub struct PossessionMode {
     pub is_active: bool,
     pub fox_entity: Option<Entity>,
     pub camera_offset: Vec3,
     pub previous_camera_transform: Option<Transform>,
+    pub camera_distance: f32,
+    pub camera_height: f32,
+}
+
+impl Default for PossessionMode {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            fox_entity: None,
+            camera_offset: Vec3::ZERO,
+            previous_camera_transform: None,
+            camera_distance: CAMERA_FOLLOW_DISTANCE,
+            camera_height: CAMERA_FOLLOW_HEIGHT,
+        }
+    }
+}
+
+impl PossessionMode {
+    /// 追従カメラの距離を変更する（正の値で近づく）
+    ///
+    /// 高さは距離との比率を保ったまま変え、距離は
+    /// [`POSSESSION_ZOOM_MIN_DISTANCE`]から[`POSSESSION_ZOOM_MAX_DISTANCE`]の範囲に収める。
+    pub fn zoom(&mut self, amount: f32) {
+        self.camera_distance = (self.camera_distance - amount)
+            .clamp(POSSESSION_ZOOM_MIN_DISTANCE, POSSESSION_ZOOM_MAX_DISTANCE);
+        self.camera_height = self.camera_distance * (CAMERA_FOLLOW_HEIGHT / CAMERA_FOLLOW_DISTANCE);
+    }
 }
 
 impl GameMode for PossessionMode {
@@ -84,27 +238,188 @@ impl GameMode for PossessionMode {
     }
 }
 
-/// ダッシュ入力のダブルタップ検出用リソース
+/// アイテム設置位置をスナップするグリッド
+///
+/// 1ブロックを`divisions`×`divisions`に分割し、クリック位置に最も近い
+/// 区画の中心に設置する。`divisions`が1の場合はブロックの中心に置く。
 #[derive(Resource)]
+pub struct PlacementGrid {
+    pub divisions: u32,
+}
+
+impl Default for PlacementGrid {
+    fn default() -> Self {
+        Self { divisions: 1 }
+    }
+}
+
+impl PlacementGrid {
+    /// クリック位置をブロック内の区画の中心にスナップしたXZ座標を返す（Yはブロックのまま）
+    pub fn snap(&self, block_pos: Vec3, hit_point: Vec3) -> Vec3 {
+        let divisions = self.divisions.max(1);
+        let cell = BLOCK_SIZE / divisions as f32;
+        let snap_axis = |center: f32, hit: f32| {
+            let index = ((hit - center + BLOCK_HALF_SIZE) / cell)
+                .floor()
+                .clamp(0.0, (divisions - 1) as f32);
+            center - BLOCK_HALF_SIZE + (index + 0.5) * cell
+        };
+        Vec3::new(
+            snap_axis(block_pos.x, hit_point.x),
+            block_pos.y,
+            snap_axis(block_pos.z, hit_point.z),
+        )
+    }
+
+    /// 次の分割数に切り替える
+    pub fn cycle(&mut self) {
+        let steps = PLACEMENT_GRID_DIVISION_STEPS;
+        let current = steps.iter().position(|&d| d == self.divisions);
+        self.divisions = steps[current.map_or(0, |i| (i + 1) % steps.len())];
+    }
+}
+
+/// キツネの居場所（フィールド上の位置、または格納されているアイテムスロット）
+#[derive(Clone, Copy, Debug)]
+pub enum FoxLocation {
+    Field(Vec3),
+    Slot(usize),
+}
+
+/// ブロックの上に置かれる設置物の種類
+#[derive(Clone, Copy, Debug)]
+pub enum PlacedObject {
+    Plant { growth: f32 },
+    Fence { side: FenceSide },
+    Torch,
+}
+
+/// 元に戻せる操作
+///
+/// 各操作は取り消すと逆向きの操作になり、それを取り消すとやり直しになる。
+#[derive(Debug)]
+pub enum HistoryAction {
+    /// キツネを移動・設置・格納した
+    FoxMoved {
+        fox: Entity,
+        from: FoxLocation,
+        to: FoxLocation,
+    },
+    /// 複数のキツネをまとめて移動した（キツネ・移動元・移動先の組。まとめて取り消す）
+    FoxesMoved(Vec<(Entity, FoxLocation, FoxLocation)>),
+    /// 設置物をブロックに置いた（`item`をスロットから消費した）
+    ObjectPlaced {
+        object: PlacedObject,
+        entity: Entity,
+        block: Entity,
+        position: Vec3,
+        item: ItemType,
+        slot_index: usize,
+    },
+    /// 設置物をブロックから取り除いた（`item`をスロットに格納した）
+    ObjectRemoved {
+        object: PlacedObject,
+        block: Entity,
+        position: Vec3,
+        item: ItemType,
+        slot_index: usize,
+    },
+    /// 岩を叩いて残り打撃回数が変わった
+    RockHit { rock: Entity, from: u32, to: u32 },
+    /// 岩を壊して鉱石をドロップした
+    RockBroken {
+        position: Vec3,
+        rotation: Quat,
+        ores: Vec<Entity>,
+        respawn_timer: Entity,
+    },
+    /// 壊した岩を元に戻した
+    RockRestored { rock: Entity },
+}
+
+/// 元に戻す・やり直しの操作履歴
+#[derive(Resource, Default)]
+pub struct ActionHistory {
+    pub undo_stack: Vec<HistoryAction>,
+    pub redo_stack: Vec<HistoryAction>,
+}
+
+impl ActionHistory {
+    /// 新しい操作を記録する（やり直しの履歴は破棄する）
+    pub fn push(&mut self, action: HistoryAction) {
+        self.undo_stack.push(action);
+        if self.undo_stack.len() > ACTION_HISTORY_LIMIT {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    /// 履歴をすべて破棄する
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
+
+/// カーソルが乗っているブロック（ピッキングのオブザーバーが更新する）
+#[derive(Resource, Default)]
+pub struct HoveredBlock {
+    pub entity: Option<Entity>,
+}
+
+/// 追従カメラの対象を保持するリソース
+///
+/// `entity`が設定されている間、カメラはその`GlobalTransform`の周りを
+/// 現在の向きのまま追従する。キツネに限らず任意のエンティティを指定できる。
+#[derive(Resource, Default)]
+pub struct CameraTarget {
+    pub entity: Option<Entity>,
+}
+
+/// ダッシュ入力のダブルタップ検出用リソース
+///
+/// ダブルタップとみなす間隔は[`CameraSettings::dash_timeout`]で設定する。
+#[derive(Resource, Default)]
 pub struct DashInputState {
     pub is_dashing: bool,
+    /// 前回の移動入力を始めた時刻（秒）
     pub last_tap_time: Option<f32>,
-    pub last_key: Option<KeyCode>,
-    pub dash_timeout: f32,
 }
 
-impl Default for DashInputState {
-    fn default() -> Self {
-        Self {
-            is_dashing: false,
-            last_tap_time: None,
-            last_key: None,
-            dash_timeout: 0.3, // 300msの間にダブルタップ
+impl DashInputState {
+    /// ダブルタップを数える移動キー
+    pub const MOVEMENT_KEYS: [KeyCode; 4] =
+        [KeyCode::KeyW, KeyCode::KeyS, KeyCode::KeyA, KeyCode::KeyD];
+
+    /// このフレームに移動入力を始めたか（移動キーを何も押していない状態から押したか）
+    ///
+    /// キーの種類は問わず、同じフレームに複数のキーを押した場合も1回のタップと数える。
+    pub fn tap_started(keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        keyboard_input.any_just_pressed(Self::MOVEMENT_KEYS)
+            && Self::MOVEMENT_KEYS
+                .iter()
+                .all(|key| !keyboard_input.pressed(*key) || keyboard_input.just_pressed(*key))
+    }
+
+    /// 移動入力を始めた（移動キーを何も押していない状態から押した）時に呼ぶ
+    ///
+    /// 前回の入力開始から`timeout`秒以内ならダッシュを始める。
+    /// 同じキーである必要はないため、斜め（W+D）を2回押してもダッシュできる。
+    pub fn register_tap(&mut self, now: f32, timeout: f32) {
+        if self
+            .last_tap_time
+            .is_some_and(|last_time| now - last_time < timeout)
+        {
+            self.is_dashing = true;
         }
+        self.last_tap_time = Some(now);
     }
 }
 
 /// 選択されたアイテムスロットを追跡するリソース
+///
+/// 空のスロットを選択した場合は`slot_index`だけが設定され、
+/// キツネを格納する時の格納先として使われる。
 #[derive(Resource, Default)]
 pub struct SelectedItemSlot {
     pub slot_index: Option<usize>,
@@ -117,11 +432,642 @@ pub struct SettingsMenuState {
     pub is_open: bool,
 }
 
+/// 設定メニューで選択中のタブ
+///
+/// メニューを閉じても保持し、次に開いた時は同じタブを表示する。
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SettingsTab {
+    #[default]
+    Camera,
+    Controls,
+    Audio,
+    Graphics,
+}
+
+impl SettingsTab {
+    /// タブボタンの並び順
+    pub const ALL: [SettingsTab; 4] = [
+        SettingsTab::Camera,
+        SettingsTab::Controls,
+        SettingsTab::Audio,
+        SettingsTab::Graphics,
+    ];
+}
+
+/// 季節
+///
+/// [`Calendar`]が[`SEASON_LENGTH_DAYS`]日ごとに次の季節へ進める。
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Season {
+    #[default]
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// 次の季節
+    pub fn next(self) -> Self {
+        match self {
+            Self::Spring => Self::Summer,
+            Self::Summer => Self::Autumn,
+            Self::Autumn => Self::Winter,
+            Self::Winter => Self::Spring,
+        }
+    }
+
+    /// 天候の抽選で雨（冬は雪）の重みに掛ける倍率
+    pub fn rain_weight_multiplier(self) -> f32 {
+        match self {
+            Self::Spring => SEASON_SPRING_RAIN_MULTIPLIER,
+            Self::Summer => SEASON_SUMMER_RAIN_MULTIPLIER,
+            Self::Autumn => SEASON_AUTUMN_RAIN_MULTIPLIER,
+            Self::Winter => SEASON_WINTER_RAIN_MULTIPLIER,
+        }
+    }
+
+    /// 雨の代わりに雪が降るか
+    ///
+    /// 雪は地面を濡らさず、水たまりも作らない。
+    pub fn is_snowy(self) -> bool {
+        self == Self::Winter
+    }
+
+    /// 植物の成長速度に掛ける倍率
+    pub fn growth_multiplier(self) -> f32 {
+        if self == Self::Winter {
+            WINTER_PLANT_GROWTH_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    /// 草のブロックのマテリアルに掛ける色
+    pub fn field_tint(self) -> (f32, f32, f32) {
+        match self {
+            Self::Spring => (1.0, 1.0, 1.0),
+            Self::Summer => SEASON_SUMMER_FIELD_TINT,
+            Self::Autumn => SEASON_AUTUMN_FIELD_TINT,
+            Self::Winter => SEASON_WINTER_FIELD_TINT,
+        }
+    }
+
+    /// 空に掛ける色
+    pub fn sky_tint(self) -> (f32, f32, f32) {
+        match self {
+            Self::Spring => (1.0, 1.0, 1.0),
+            Self::Summer => SEASON_SUMMER_SKY_TINT,
+            Self::Autumn => SEASON_AUTUMN_SKY_TINT,
+            Self::Winter => SEASON_WINTER_SKY_TINT,
+        }
+    }
+}
+
+/// ゲーム内の日付と季節を管理するリソース
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+pub struct Calendar {
+    /// 経過した日数（0から数える）
+    pub day: u32,
+    /// 今日の経過時間（秒）
+    pub time_of_day: f32,
+    pub season: Season,
+}
+
+impl Calendar {
+    /// 時間を進め、季節が変わった場合は`true`を返す
+    pub fn advance(&mut self, secs: f32) -> bool {
+        self.time_of_day += secs;
+        let mut season_changed = false;
+        while self.time_of_day >= DAY_LENGTH_SECS {
+            self.time_of_day -= DAY_LENGTH_SECS;
+            self.day += 1;
+            if self.day.is_multiple_of(SEASON_LENGTH_DAYS) {
+                self.season = self.season.next();
+                season_changed = true;
+            }
+        }
+        season_changed
+    }
+
+    /// 今の季節の何日目か（1から数える）
+    pub fn day_of_season(&self) -> u32 {
+        self.day % SEASON_LENGTH_DAYS + 1
+    }
+
+    /// 時刻に応じた空の色味（昼・夕暮れ・夜のキーの間を線形に補間する）
+    pub fn daylight_tint(&self) -> (f32, f32, f32) {
+        let phase = (self.time_of_day / DAY_LENGTH_SECS).clamp(0.0, 1.0);
+        let keys = &SKY_DAY_CYCLE_TINTS;
+        let next = keys
+            .iter()
+            .position(|(at, _)| *at > phase)
+            .unwrap_or(keys.len());
+        let (from_at, from) = keys[next - 1];
+        let (to_at, to) = keys.get(next).copied().unwrap_or((1.0, keys[0].1));
+        let t = if to_at > from_at {
+            (phase - from_at) / (to_at - from_at)
+        } else {
+            0.0
+        };
+        (
+            from.0 + (to.0 - from.0) * t,
+            from.1 + (to.1 - from.1) * t,
+            from.2 + (to.2 - from.2) * t,
+        )
+    }
+}
+
 /// 天候状態を管理するリソース
-#[derive(Resource)]
+#[derive(Resource, Serialize, Deserialize, Clone)]
 pub struct WeatherState {
     pub is_raining: bool,
+    /// 次に切り替わった時に雨になるか
+    ///
+    /// 天気予報に使えるよう、切り替わった時点で[`WeatherConfig`]の重みで次の天候も抽選しておく。
+    /// 今と同じ天候が選ばれた場合は、切り替わってもそのまま続く。
+    pub next_is_raining: bool,
     pub time_until_change: f32,
+    /// 雨の強さ（0.0で晴れ、1.0で本降り）
+    ///
+    /// `is_raining`が切り替わると[`WEATHER_RAIN_RAMP_SECS`]秒かけて追従する。
+    pub intensity: f32,
+}
+
+impl WeatherState {
+    /// 初期天候を指定して作成（最初の変化までの時間と次の天候はランダム）
+    pub fn new(is_raining: bool, config: &WeatherConfig, rng: &mut impl Rng) -> Self {
+        Self {
+            is_raining,
+            next_is_raining: config.sample_is_raining(Season::default(), rng),
+            time_until_change: rng
+                .random_range(WEATHER_INITIAL_CHANGE_MIN..WEATHER_INITIAL_CHANGE_MAX),
+            intensity: if is_raining { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// 雨の強さに応じた太陽光の明るさ
+    pub fn sun_illuminance(&self) -> f32 {
+        SUN_ILLUMINANCE_CLEAR + (SUN_ILLUMINANCE_RAIN - SUN_ILLUMINANCE_CLEAR) * self.intensity
+    }
+}
+
+impl FromWorld for WeatherState {
+    fn from_world(world: &mut World) -> Self {
+        let config = world
+            .get_resource::<WeatherConfig>()
+            .cloned()
+            .unwrap_or_default();
+        let mut rng = world.resource_mut::<GameRng>();
+        Self::new(false, &config, &mut rng.0)
+    }
+}
+
+/// 前回終了時の天候と日付のセーブデータ
+///
+/// 起動時に読み込み、前回と同じ天候・季節から再開する。
+/// セーブファイルがない場合や項目が欠けている場合、その項目は新しく始める。
+#[derive(Serialize, Deserialize, Default)]
+pub struct WeatherSave {
+    #[serde(default)]
+    pub weather: Option<WeatherState>,
+    #[serde(default)]
+    pub calendar: Option<Calendar>,
+}
+
+impl JsonSave for WeatherSave {
+    const FILE_NAME: &'static str = "weather_state.json";
+
+    /// 天候の強さと残り時間、時刻を範囲内に補正する
+    fn sanitize(&mut self) {
+        if let Some(weather) = &mut self.weather {
+            weather.intensity = weather.intensity.clamp(0.0, 1.0);
+            weather.time_until_change = weather.time_until_change.max(0.0);
+        }
+        if let Some(calendar) = &mut self.calendar {
+            calendar.time_of_day = calendar.time_of_day.clamp(0.0, DAY_LENGTH_SECS);
+        }
+    }
+}
+
+/// 雨によるブロックの濡れ具合（0.0で乾燥、1.0でずぶ濡れ）
+#[derive(Resource, Default)]
+pub struct Wetness {
+    pub level: f32,
+}
+
+/// 雨粒を降らせる範囲
+///
+/// [`RainArea::Camera`]ではフィールドの外をカメラで見ていても雨が見えるよう、
+/// カメラの注視点を中心にした範囲に降らせる。
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RainArea {
+    /// フィールドの範囲に固定する
+    #[default]
+    Field,
+    /// カメラの注視点の周囲に追従する
+    Camera,
+}
+
+impl RainArea {
+    /// フィールド固定とカメラ追従を切り替える
+    pub fn toggle(&mut self) {
+        *self = match self {
+            Self::Field => Self::Camera,
+            Self::Camera => Self::Field,
+        };
+    }
+}
+
+/// 嵐の稲光の状態
+#[derive(Resource)]
+pub struct Lightning {
+    /// 次の稲光までの時間（秒）。嵐の間だけ減る
+    pub time_until_flash: f32,
+    /// 今の稲光の明るさ（0.0で光っていない）
+    pub flash: f32,
+}
+
+impl Default for Lightning {
+    fn default() -> Self {
+        Self {
+            time_until_flash: LIGHTNING_INTERVAL_MIN,
+            flash: 0.0,
+        }
+    }
+}
+
+/// 稲光の後に遅れて鳴らす雷鳴の予定
+#[derive(Resource, Default)]
+pub struct ThunderQueue {
+    pub claps: Vec<PendingThunder>,
+}
+
+/// 鳴らす予定の雷鳴1回分
+pub struct PendingThunder {
+    /// 鳴らすまでの残り時間（秒）
+    pub remaining: f32,
+    pub volume: f32,
+}
+
+/// 雨のストレステストモードの状態を保持するリソース
+///
+/// 有効な間は天候に関係なく大量の雨粒を生成し、
+/// 一定間隔でFPSと雨粒の数をログに出力する。
+#[derive(Resource, Default)]
+pub struct RainStressMode {
+    pub enabled: bool,
+    /// ログ出力までの残り時間
+    pub time_until_report: f32,
+    /// 前回のログ出力以降のフレーム数
+    pub frame_count: u32,
+    /// 前回のログ出力以降の経過時間の合計
+    pub elapsed: f32,
+}
+
+impl RainStressMode {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            time_until_report: RAIN_STRESS_REPORT_INTERVAL,
+            ..default()
+        }
+    }
+
+    /// 有効・無効を切り替え、計測をリセットする
+    pub fn toggle(&mut self) {
+        *self = Self::new(!self.enabled);
+    }
+
+    /// 現在の雨粒の生成レート（個/秒）
+    pub fn spawn_rate(&self) -> f32 {
+        if self.enabled {
+            RAIN_SPAWN_RATE * RAIN_STRESS_SPAWN_MULTIPLIER
+        } else {
+            RAIN_SPAWN_RATE
+        }
+    }
+}
+
+/// フィールド生成の設定を保持するリソース
+#[derive(Resource)]
+pub struct FieldConfig {
+    pub size: i32,
+    /// 草のブロックをシーンではなく共有メッシュで直接描画するか
+    ///
+    /// シーンはブロック1つにつき5エンティティ（ブロック・シーンのルート・ノード2つ・メッシュ）になるが、
+    /// 共有メッシュならブロック自体の1エンティティで済み、同じメッシュと
+    /// マテリアルのブロックはまとめて描画される。ピッキングはそのまま動く。
+    pub batched: bool,
+    /// レベルファイルから読み込んだ配置（`None`なら手順生成）
+    pub level: Option<FieldLevel>,
+}
+
+impl Default for FieldConfig {
+    fn default() -> Self {
+        Self {
+            size: FIELD_SIZE,
+            batched: false,
+            level: None,
+        }
+    }
+}
+
+impl FieldConfig {
+    /// フィールド全体の一辺の長さ（ワールド座標）
+    pub fn world_extent(&self) -> f32 {
+        self.size as f32 * BLOCK_SIZE
+    }
+
+    /// フィールドの中心位置（ワールド座標）
+    pub fn center(&self) -> Vec3 {
+        (self.block_position(0, 0) + self.block_position(self.size - 1, self.size - 1)) / 2.0
+    }
+
+    /// グリッド座標のブロックの中心位置（ワールド座標）
+    pub fn block_position(&self, x: i32, z: i32) -> Vec3 {
+        Vec3::new(
+            (x as f32 - self.size as f32 / 2.0) * BLOCK_SPACING,
+            0.0,
+            (z as f32 - self.size as f32 / 2.0) * BLOCK_SPACING,
+        )
+    }
+
+    /// ワールド座標が含まれるマスのグリッド座標（`block_position`の逆変換）
+    pub fn grid_coords(&self, position: Vec3) -> IVec2 {
+        let half = self.size as f32 / 2.0;
+        IVec2::new(
+            (position.x / BLOCK_SPACING + half).round() as i32,
+            (position.z / BLOCK_SPACING + half).round() as i32,
+        )
+    }
+
+    /// グリッド座標のブロックを持ち上げる高さ（レベルファイルの高さ、手順生成では0）
+    pub fn tile_elevation(&self, x: i32, z: i32) -> f32 {
+        self.level
+            .as_ref()
+            .and_then(|level| level.tile(x, z))
+            .map_or(0.0, |tile| tile.height as f32 * BLOCK_SIZE)
+    }
+
+    /// ワールド座標が含まれるマスのブロックを持ち上げる高さ（フィールド外は0）
+    ///
+    /// ブロックの上に置くものは、この高さの分だけ持ち上げたブロックの上面に置く。
+    pub fn elevation_at(&self, position: Vec3) -> f32 {
+        let tile = self.grid_coords(position);
+        self.tile_elevation(tile.x, tile.y)
+    }
+
+    /// キツネの初期位置（原点のマスのブロックの上に立たせた位置）
+    pub fn fox_spawn_position(&self) -> Vec3 {
+        Vec3::Y * (self.elevation_at(Vec3::ZERO) + FOX_INITIAL_HEIGHT)
+    }
+
+    /// 視線が最初に当たるブロック上面の点
+    ///
+    /// 持ち上げたマスの上面を高い順に調べ、視線が当たった高さのマスがあればその点を返す。
+    /// どこにも当たらなければ、持ち上げていないブロック上面の高さの平面との交点を返す。
+    pub fn ray_block_top(&self, ray: Ray3d) -> Option<Vec3> {
+        let hit_plane = |height: f32| {
+            let distance = ray.intersect_plane(Vec3::Y * height, InfinitePlane3d::new(Vec3::Y))?;
+            Some(ray.get_point(distance))
+        };
+        (1..=LEVEL_TILE_HEIGHT_MAX)
+            .rev()
+            .map(|height| height as f32 * BLOCK_SIZE)
+            .find_map(|elevation| {
+                hit_plane(BLOCK_HALF_SIZE + elevation)
+                    .filter(|point| self.elevation_at(*point) == elevation)
+            })
+            .or_else(|| hit_plane(BLOCK_HALF_SIZE))
+    }
+
+    /// ワールド座標の真下にある地面の高さ
+    ///
+    /// フィールド内ならそのマスのブロックの上面、フィールド外なら0を返す。
+    /// 岩（`rocks`は各岩の中心の位置）の上では、岩を半球とみなした表面の方が高ければそちらを返す。
+    pub fn surface_height(&self, position: Vec3, rocks: &[Vec3]) -> f32 {
+        let tile = self.grid_coords(position);
+        let in_field = (0..self.size).contains(&tile.x) && (0..self.size).contains(&tile.y);
+        let ground = if in_field {
+            BLOCK_HALF_SIZE + self.tile_elevation(tile.x, tile.y)
+        } else {
+            0.0
+        };
+
+        rocks.iter().fold(ground, |height, rock| {
+            let distance = (position - *rock).xz().length();
+            if distance >= ROCK_RADIUS {
+                return height;
+            }
+            let rock_base = rock.y - ROCK_HEIGHT;
+            let dome = (1.0 - (distance / ROCK_RADIUS).powi(2)).sqrt();
+            height.max(rock_base + ROCK_TOP_HEIGHT * dome)
+        })
+    }
+
+    /// フィールド全体のXZ平面上の範囲
+    pub fn field_rect(&self) -> Rect {
+        let min = self.block_position(0, 0);
+        let max = self.block_position(self.size - 1, self.size - 1);
+        Rect::new(min.x, min.z, max.x, max.z).inflate(BLOCK_HALF_SIZE)
+    }
+
+    /// 選択可能エリアの一辺のグリッド座標の範囲
+    ///
+    /// デフォルトサイズ基準の定数をフィールド中央に寄せ、`expansion`周分だけ
+    /// 外側へ広げる。フィールドの外にははみ出さない。
+    pub fn selectable_range(&self, expansion: i32) -> std::ops::RangeInclusive<i32> {
+        let offset = (self.size - FIELD_SIZE) / 2;
+        let start = (SELECTABLE_AREA_START + offset - expansion).max(0);
+        let end = (SELECTABLE_AREA_END + offset + expansion).min(self.size - 1);
+        start..=end
+    }
+
+    /// 選択可能エリアをこれ以上広げられない拡張数
+    ///
+    /// レベルファイルの配置では選択可能なマスをファイルで決めるので、エリアは広げない。
+    pub fn max_expansion(&self) -> i32 {
+        if self.level.is_some() {
+            return 0;
+        }
+        let offset = (self.size - FIELD_SIZE) / 2;
+        (SELECTABLE_AREA_START + offset).max(self.size - 1 - (SELECTABLE_AREA_END + offset))
+    }
+
+    /// 選択可能エリアのXZ平面上の範囲
+    pub fn selectable_rect(&self, expansion: i32) -> Rect {
+        let range = self.selectable_range(expansion);
+        let min = self.block_position(*range.start(), *range.start());
+        let max = self.block_position(*range.end(), *range.end());
+        Rect::new(min.x, min.z, max.x, max.z).inflate(BLOCK_HALF_SIZE)
+    }
+
+    /// グリッド座標のブロックが選択可能エリア（中央）に含まれるか
+    pub fn is_selectable(&self, x: i32, z: i32, expansion: i32) -> bool {
+        let area = self.selectable_range(expansion);
+        area.contains(&x) && area.contains(&z)
+    }
+
+    /// グリッド座標の草のブロックを選択可能にするか
+    ///
+    /// レベルファイルがある場合はエリアの広さに関係なくマスの`selectable`に従い、
+    /// ない場合は`expansion`周広げた選択可能エリアに含まれるかで決める。
+    pub fn tile_selectable(&self, x: i32, z: i32, expansion: i32) -> bool {
+        match self.level.as_ref().and_then(|level| level.tile(x, z)) {
+            Some(tile) => tile.selectable,
+            None => self.is_selectable(x, z, expansion),
+        }
+    }
+}
+
+/// 選択可能エリア（建築エリア）の拡張状態
+///
+/// 鉱石を消費するたびに`expansion`が1増え、中央のエリアが1周ずつ広がる。
+/// 広げたエリアは保存し、次回の起動時にも引き継ぐ。
+#[derive(Resource, Serialize, Deserialize, Default)]
+pub struct BuildArea {
+    pub expansion: i32,
+}
+
+impl JsonSave for BuildArea {
+    const FILE_NAME: &'static str = "build_area.json";
+
+    fn sanitize(&mut self) {
+        self.expansion = self.expansion.max(0);
+    }
+}
+
+/// 選択可能エリアの枠線の表示状態
+///
+/// `always_visible`がオフでも、起動直後とエリアが広がった直後は
+/// `intro_remaining`秒だけ表示する。
+#[derive(Resource)]
+pub struct BuildAreaOutline {
+    pub always_visible: bool,
+    pub intro_remaining: f32,
+}
+
+impl Default for BuildAreaOutline {
+    fn default() -> Self {
+        Self {
+            always_visible: false,
+            intro_remaining: BUILD_AREA_OUTLINE_INTRO_SECS,
+        }
+    }
+}
+
+/// デバッグコンソールの状態を保持するリソース
+#[derive(Resource, Default)]
+pub struct DebugConsoleState {
+    pub is_open: bool,
+    /// 入力したコマンドと実行結果のログ（古い順）
+    pub log: VecDeque<String>,
+    /// 入力されて未実行のコマンド
+    pub pending: Vec<String>,
+}
+
+impl DebugConsoleState {
+    /// ログに1行追加する（古い行は上限を超えた分だけ捨てる）
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.log.push_back(line.into());
+        while self.log.len() > DEBUG_CONSOLE_HISTORY_LINES {
+            self.log.pop_front();
+        }
+    }
+}
+
+/// 柵が立っているマスの辺の集合
+///
+/// 辺は隣り合う2マスの組で表すので、どちら側のマスから立てた柵も同じ辺になる。
+/// キツネの移動判定でグリッド座標を引くだけで済むよう、柵の設置時に登録する。
+#[derive(Resource, Default)]
+pub struct FencedEdges {
+    edges: HashSet<(IVec2, IVec2)>,
+}
+
+impl FencedEdges {
+    /// 隣り合う2マスの間の辺を、マスの順序によらない組にする
+    fn edge(a: IVec2, b: IVec2) -> (IVec2, IVec2) {
+        if (a.x, a.y) <= (b.x, b.y) {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// 辺に柵を登録する
+    pub fn insert(&mut self, tile: IVec2, side: FenceSide) {
+        self.edges.insert(Self::edge(tile, tile + side.offset()));
+    }
+
+    /// 辺から柵を取り除く
+    pub fn remove(&mut self, tile: IVec2, side: FenceSide) {
+        self.edges.remove(&Self::edge(tile, tile + side.offset()));
+    }
+
+    /// 辺に柵が立っているか
+    pub fn contains(&self, tile: IVec2, side: FenceSide) -> bool {
+        self.edges.contains(&Self::edge(tile, tile + side.offset()))
+    }
+
+    /// すべての柵を取り除く
+    pub fn clear(&mut self) {
+        self.edges.clear();
+    }
+
+    /// 隣り合う2マスの間に柵があるか
+    fn crosses(&self, from: IVec2, to: IVec2) -> bool {
+        self.edges.contains(&Self::edge(from, to))
+    }
+
+    /// `from`から`to`への移動が柵に阻まれるか
+    ///
+    /// 斜めの移動は、縦横どちらの順に回り込んでも柵を越える場合だけ阻む。
+    pub fn blocks_move(&self, field_config: &FieldConfig, from: Vec3, to: Vec3) -> bool {
+        let from_tile = field_config.grid_coords(from);
+        let step = (field_config.grid_coords(to) - from_tile).signum();
+        match (step.x != 0, step.y != 0) {
+            (false, false) => false,
+            (true, true) => {
+                let to_tile = from_tile + step;
+                let via_x = from_tile + IVec2::new(step.x, 0);
+                let via_z = from_tile + IVec2::new(0, step.y);
+                (self.crosses(from_tile, via_x) || self.crosses(via_x, to_tile))
+                    && (self.crosses(from_tile, via_z) || self.crosses(via_z, to_tile))
+            }
+            _ => self.crosses(from_tile, from_tile + step),
+        }
+    }
+}
+
+/// 水のブロックがあるマスの集合
+///
+/// 憑依中のキツネの足元が水かを毎フレームすべてのブロックから探さずに済むよう、
+/// [`spawn_field`](crate::cf_systems::spawn_field)で水のブロックを置く時に登録する。
+#[derive(Resource, Default)]
+pub struct WaterTiles {
+    pub tiles: HashSet<IVec2>,
+}
+
+/// ゲーム全体で共有するシード付き乱数生成器
+#[derive(Resource)]
+pub struct GameRng(pub StdRng);
+
+impl GameRng {
+    /// シードを指定して作成（`None`の場合はOSの乱数から初期化）
+    pub fn new(seed: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => Self(StdRng::seed_from_u64(seed)),
+            None => Self(StdRng::from_os_rng()),
+        }
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(None)
+    }
 }
 
 // ========================================
@@ -129,53 +1075,749 @@ pub struct WeatherState {
 // ========================================
 
 /// カメラ設定を保存するリソース
-#[derive(Resource, Serialize, Deserialize, Clone)]
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CameraSettings {
     pub mouse_sensitivity: f32,
+    /// 憑依モード中のカメラ回転に使うマウス感度
+    #[serde(default = "default_possession_sensitivity")]
+    pub possession_sensitivity: f32,
+    /// 憑依中のダッシュで視野角を広げる量（度、0で無効）
+    #[serde(default = "default_dash_fov_kick")]
+    pub dash_fov_kick: f32,
+    /// 矢印キーでのカメラ回転の速さ（ラジアン/秒）
     pub keyboard_sensitivity: f32,
+    /// WASDでのカメラ移動の速さ（1秒あたりの移動量）
     pub movement_speed: f32,
     pub zoom_speed: f32,
+    /// ゲームパッドのスティックのデッドゾーン
+    #[serde(default = "default_gamepad_deadzone")]
+    pub gamepad_deadzone: f32,
+    /// 移動キーのダブルタップでダッシュするとみなす間隔（秒）
+    #[serde(default = "default_dash_timeout")]
+    pub dash_timeout: f32,
+    /// 太陽光の影を描画するか
+    #[serde(default = "default_shadows_enabled")]
+    pub shadows_enabled: bool,
+    /// シャドウマップの解像度（[`SHADOW_MAP_SIZES`]のいずれか）
+    #[serde(default = "default_shadow_map_size")]
+    pub shadow_map_size: u32,
+    /// 環境光の明るさ
+    #[serde(default = "default_ambient_brightness")]
+    pub ambient_brightness: f32,
+    /// 太陽の向きを自動ではなく[`Self::sun_azimuth`]と[`Self::sun_elevation`]で決めるか
+    #[serde(default)]
+    pub manual_sun: bool,
+    /// 手動で設定した太陽の方位（度、0〜360）
+    #[serde(default = "default_sun_azimuth")]
+    pub sun_azimuth: f32,
+    /// 手動で設定した太陽の高さ（度）
+    #[serde(default = "default_sun_elevation")]
+    pub sun_elevation: f32,
+    /// ウィンドウ表示時の幅と高さ（論理ピクセル）
+    #[serde(default = "default_window_width")]
+    pub window_width: u32,
+    #[serde(default = "default_window_height")]
+    pub window_height: u32,
+    /// フルスクリーンで表示するか
+    #[serde(default)]
+    pub fullscreen: bool,
+}
+
+/// 古い設定ファイルに`possession_sensitivity`がない場合の値
+///
+/// JSONに書けない値にしておき、読み込み後の[`CameraSettings::clamp_to_valid_ranges`]で
+/// 同じファイルの`mouse_sensitivity`に置き換える。
+fn default_possession_sensitivity() -> f32 {
+    f32::NAN
+}
+
+/// 古い設定ファイルに`dash_fov_kick`がない場合の値
+fn default_dash_fov_kick() -> f32 {
+    DASH_FOV_KICK_DEFAULT_DEGREES
+}
+
+/// 古い設定ファイルに`gamepad_deadzone`がない場合の値
+fn default_gamepad_deadzone() -> f32 {
+    GAMEPAD_DEFAULT_DEADZONE
+}
+
+/// 古い設定ファイルに`dash_timeout`がない場合の値
+fn default_dash_timeout() -> f32 {
+    DASH_TIMEOUT_DEFAULT
+}
+
+/// 古い設定ファイルに`shadows_enabled`がない場合の値
+fn default_shadows_enabled() -> bool {
+    true
+}
+
+/// 古い設定ファイルに`shadow_map_size`がない場合の値
+fn default_shadow_map_size() -> u32 {
+    SHADOW_MAP_DEFAULT_SIZE
+}
+
+/// 古い設定ファイルに`ambient_brightness`がない場合の値
+fn default_ambient_brightness() -> f32 {
+    AMBIENT_BRIGHTNESS_DEFAULT
+}
+
+/// 古い設定ファイルに太陽の向きがない場合の値
+fn default_sun_azimuth() -> f32 {
+    SUN_AZIMUTH_DEFAULT_DEGREES
+}
+
+fn default_sun_elevation() -> f32 {
+    SUN_ELEVATION_DEFAULT_DEGREES
+}
+
+/// 古い設定ファイルにウィンドウサイズがない場合の値
+fn default_window_width() -> u32 {
+    WINDOW_DEFAULT_WIDTH
+}
+
+fn default_window_height() -> u32 {
+    WINDOW_DEFAULT_HEIGHT
+}
+
+/// 設定値を範囲内に収め、補正した場合はログに出力する
+///
+/// 数値でない値（NaNや無限大）は範囲の下限にする。
+fn clamp_setting(name: &str, value: &mut f32, range: std::ops::RangeInclusive<f32>) {
+    let clamped = if value.is_finite() {
+        value.clamp(*range.start(), *range.end())
+    } else {
+        *range.start()
+    };
+    if clamped != *value {
+        warn!(
+            "設定値 {} ({}) が範囲外のため {} に補正しました（{}〜{}）",
+            name,
+            value,
+            clamped,
+            range.start(),
+            range.end()
+        );
+        *value = clamped;
+    }
 }
 
 impl Default for CameraSettings {
     fn default() -> Self {
         Self {
-            mouse_sensitivity: 0.003,
-            keyboard_sensitivity: 0.02,
-            movement_speed: 10.0,
+            mouse_sensitivity: MOUSE_SENSITIVITY_DEFAULT,
+            possession_sensitivity: MOUSE_SENSITIVITY_DEFAULT,
+            dash_fov_kick: default_dash_fov_kick(),
+            keyboard_sensitivity: 1.2,
+            movement_speed: 600.0,
             zoom_speed: 50.0,
+            gamepad_deadzone: GAMEPAD_DEFAULT_DEADZONE,
+            dash_timeout: default_dash_timeout(),
+            shadows_enabled: default_shadows_enabled(),
+            shadow_map_size: default_shadow_map_size(),
+            ambient_brightness: default_ambient_brightness(),
+            manual_sun: false,
+            sun_azimuth: default_sun_azimuth(),
+            sun_elevation: default_sun_elevation(),
+            window_width: default_window_width(),
+            window_height: default_window_height(),
+            fullscreen: false,
         }
     }
 }
 
+impl JsonSave for CameraSettings {
+    const FILE_NAME: &'static str = "camera_settings.json";
+
+    fn sanitize(&mut self) {
+        self.clamp_to_valid_ranges();
+    }
+}
+
 impl CameraSettings {
-    /// 設定ファイルのパスを取得
-    pub fn settings_path() -> PathBuf {
-        PathBuf::from("assets/user/camera_settings.json")
+    /// 各値を設定画面の+/-ボタンと同じ範囲に収める
+    ///
+    /// 手で編集された設定ファイルの極端な値で操作が壊れないようにする。
+    /// 補正した項目はログに出力する。
+    pub fn clamp_to_valid_ranges(&mut self) {
+        let sensitivity = MOUSE_SENSITIVITY_MIN..=MOUSE_SENSITIVITY_MAX;
+        clamp_setting(
+            "mouse_sensitivity",
+            &mut self.mouse_sensitivity,
+            sensitivity.clone(),
+        );
+        // 憑依中の感度がない古い設定ファイルでは、通常のマウス感度をそのまま使う
+        if self.possession_sensitivity.is_nan() {
+            self.possession_sensitivity = self.mouse_sensitivity;
+        }
+        clamp_setting(
+            "possession_sensitivity",
+            &mut self.possession_sensitivity,
+            sensitivity,
+        );
+        clamp_setting(
+            "dash_fov_kick",
+            &mut self.dash_fov_kick,
+            0.0..=DASH_FOV_KICK_MAX_DEGREES,
+        );
+        clamp_setting(
+            "keyboard_sensitivity",
+            &mut self.keyboard_sensitivity,
+            KEYBOARD_SENSITIVITY_MIN..=KEYBOARD_SENSITIVITY_MAX,
+        );
+        clamp_setting(
+            "movement_speed",
+            &mut self.movement_speed,
+            MOVEMENT_SPEED_MIN..=MOVEMENT_SPEED_MAX,
+        );
+        clamp_setting(
+            "zoom_speed",
+            &mut self.zoom_speed,
+            ZOOM_SPEED_MIN..=ZOOM_SPEED_MAX,
+        );
+        clamp_setting(
+            "gamepad_deadzone",
+            &mut self.gamepad_deadzone,
+            0.0..=GAMEPAD_DEADZONE_MAX,
+        );
+        clamp_setting(
+            "dash_timeout",
+            &mut self.dash_timeout,
+            DASH_TIMEOUT_MIN..=DASH_TIMEOUT_MAX,
+        );
+        clamp_setting(
+            "ambient_brightness",
+            &mut self.ambient_brightness,
+            0.0..=AMBIENT_BRIGHTNESS_MAX,
+        );
+        clamp_setting("sun_azimuth", &mut self.sun_azimuth, 0.0..=360.0);
+        clamp_setting(
+            "sun_elevation",
+            &mut self.sun_elevation,
+            SUN_ELEVATION_MIN_DEGREES..=SUN_ELEVATION_MAX_DEGREES,
+        );
+
+        if !SHADOW_MAP_SIZES.contains(&self.shadow_map_size) {
+            warn!(
+                "設定値 shadow_map_size ({}) は選択できない解像度のため {} に補正しました",
+                self.shadow_map_size, SHADOW_MAP_DEFAULT_SIZE
+            );
+            self.shadow_map_size = SHADOW_MAP_DEFAULT_SIZE;
+        }
+        if self.window_width < WINDOW_MIN_WIDTH || self.window_height < WINDOW_MIN_HEIGHT {
+            warn!(
+                "設定値のウィンドウサイズ ({}x{}) が小さすぎるため補正しました",
+                self.window_width, self.window_height
+            );
+            self.window_width = self.window_width.max(WINDOW_MIN_WIDTH);
+            self.window_height = self.window_height.max(WINDOW_MIN_HEIGHT);
+        }
     }
 
-    /// 設定をファイルに保存
-    pub fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
-        if let Some(parent) = Self::settings_path().parent() {
-            fs::create_dir_all(parent)?;
+    /// 保存済みの設定と比べて、保存していない変更があるか
+    ///
+    /// ウィンドウの状態は自動で書き戻すため比較しない。
+    pub fn has_unsaved_changes(&self, saved: &Self) -> bool {
+        let mut current = self.clone();
+        current.window_width = saved.window_width;
+        current.window_height = saved.window_height;
+        current.fullscreen = saved.fullscreen;
+        current != *saved
+    }
+
+    /// ウィンドウの状態だけを設定ファイルに書き戻す
+    ///
+    /// 保存ボタンを押していない他の設定の変更は書き込まない。
+    pub fn save_window_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut saved = Self::load_from_file().unwrap_or_default();
+        saved.window_width = self.window_width;
+        saved.window_height = self.window_height;
+        saved.fullscreen = self.fullscreen;
+        saved.save_to_file()
+    }
+}
+
+/// 天候1種類分の出やすさと続く時間
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct WeatherOdds {
+    /// 天候が切り替わる時にこの天候が選ばれる重み（0で選ばれない）
+    pub weight: f32,
+    /// この天候が続く時間の範囲（秒）
+    pub duration_min: f32,
+    pub duration_max: f32,
+}
+
+impl WeatherOdds {
+    /// 負の重みや逆転した時間の範囲を補正する
+    fn sanitize(&mut self, name: &str) {
+        if self.weight.is_nan() || self.weight < 0.0 {
+            warn!(
+                "天候設定 {}.weight ({}) を 0 に補正しました",
+                name, self.weight
+            );
+            self.weight = 0.0;
+        }
+        let min = self.duration_min.min(self.duration_max).max(1.0);
+        let max = self.duration_min.max(self.duration_max).max(min);
+        if (min, max) != (self.duration_min, self.duration_max) {
+            warn!(
+                "天候設定 {} の時間の範囲 ({}..{}) を {}..{} に補正しました",
+                name, self.duration_min, self.duration_max, min, max
+            );
+            self.duration_min = min;
+            self.duration_max = max;
         }
-        let json = serde_json::to_string_pretty(self)?;
-        fs::write(Self::settings_path(), json)?;
-        Ok(())
     }
+}
 
-    /// ファイルから設定を読み込み
-    pub fn load_from_file() -> Result<Self, Box<dyn std::error::Error>> {
-        let json = fs::read_to_string(Self::settings_path())?;
-        let settings = serde_json::from_str(&json)?;
-        Ok(settings)
+/// 天候の出やすさの設定（マップごとの気候）
+///
+/// 天候が切り替わるたびに、各天候の重みに比例した確率で次の天候を選ぶ。
+/// 雨の重みを小さくすれば砂漠のようにめったに降らず、大きくすれば熱帯雨林のようによく降る。
+/// カメラ設定と同じ`assets/user`に保存し、ファイルを編集して調整する。
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct WeatherConfig {
+    pub clear: WeatherOdds,
+    pub rain: WeatherOdds,
+}
+
+impl Default for WeatherConfig {
+    fn default() -> Self {
+        Self {
+            clear: WeatherOdds {
+                weight: WEATHER_CLEAR_WEIGHT,
+                duration_min: WEATHER_CLEAR_DURATION_MIN,
+                duration_max: WEATHER_CLEAR_DURATION_MAX,
+            },
+            rain: WeatherOdds {
+                weight: WEATHER_RAIN_WEIGHT,
+                duration_min: WEATHER_RAIN_DURATION_MIN,
+                duration_max: WEATHER_RAIN_DURATION_MAX,
+            },
+        }
     }
+}
+
+impl JsonSave for WeatherConfig {
+    const FILE_NAME: &'static str = "weather_config.json";
+    const WRITE_DEFAULT_IF_MISSING: bool = true;
 
-    /// ファイルから設定を読み込むか、デフォルトを使用
-    pub fn load_or_default() -> Self {
-        Self::load_from_file().unwrap_or_else(|_| {
-            println!("設定ファイルが見つかりません。デフォルトを使用します");
-            Self::default()
+    /// 負の重みや逆転した時間の範囲は補正し、重みがすべて0の場合は既定の重みに戻す
+    fn sanitize(&mut self) {
+        self.clear.sanitize("clear");
+        self.rain.sanitize("rain");
+        if self.clear.weight + self.rain.weight <= 0.0 {
+            warn!("天候設定の重みがすべて 0 のため既定の重みを使用します");
+            self.clear.weight = WEATHER_CLEAR_WEIGHT;
+            self.rain.weight = WEATHER_RAIN_WEIGHT;
+        }
+    }
+}
+
+impl WeatherConfig {
+    /// 重みに従って次の天候を抽選する（雨ならtrue）
+    ///
+    /// 雨の重みには季節ごとの倍率（[`Season::rain_weight_multiplier`]）を掛ける。
+    pub fn sample_is_raining(&self, season: Season, rng: &mut impl Rng) -> bool {
+        let rain_weight = self.rain.weight * season.rain_weight_multiplier();
+        let total = self.clear.weight + rain_weight;
+        total > 0.0 && rng.random_range(0.0..total) < rain_weight
+    }
+
+    /// 天候が続く時間（秒）を抽選する
+    pub fn sample_duration(&self, is_raining: bool, rng: &mut impl Rng) -> f32 {
+        let odds = if is_raining { self.rain } else { self.clear };
+        if odds.duration_min >= odds.duration_max {
+            return odds.duration_min;
+        }
+        rng.random_range(odds.duration_min..odds.duration_max)
+    }
+}
+
+/// 雨粒の見た目の設定
+///
+/// 雨粒のカプセルの太さ・長さと、小雨・本降りの色を決める。色は雨の強さに応じて補間する。
+/// 天候設定と同じ`assets/user`に保存し、デバッグコンソールの`rain`コマンドで実行中にも変えられる。
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RainConfig {
+    pub capsule_radius: f32,
+    pub capsule_height: f32,
+    /// 本降りの雨粒の色（sRGBA）
+    pub color: (f32, f32, f32, f32),
+    /// 小雨の雨粒の色（sRGBA）
+    pub drizzle_color: (f32, f32, f32, f32),
+}
+
+impl Default for RainConfig {
+    fn default() -> Self {
+        Self {
+            capsule_radius: RAIN_CAPSULE_RADIUS,
+            capsule_height: RAIN_CAPSULE_HEIGHT,
+            color: RAIN_COLOR,
+            drizzle_color: RAIN_DRIZZLE_COLOR,
+        }
+    }
+}
+
+impl JsonSave for RainConfig {
+    const FILE_NAME: &'static str = "rain_config.json";
+    const WRITE_DEFAULT_IF_MISSING: bool = true;
+
+    /// 範囲外の半径・高さは[`RAIN_CAPSULE_RADIUS_MIN`]などの範囲に収める
+    fn sanitize(&mut self) {
+        self.capsule_radius = self
+            .capsule_radius
+            .clamp(RAIN_CAPSULE_RADIUS_MIN, RAIN_CAPSULE_RADIUS_MAX);
+        self.capsule_height = self
+            .capsule_height
+            .clamp(RAIN_CAPSULE_HEIGHT_MIN, RAIN_CAPSULE_HEIGHT_MAX);
+    }
+}
+
+// ========================================
+// Save Data Resources
+// ========================================
+
+/// 採掘などで貯まる所持資源（スコア）
+///
+/// 鉱石の数は拾うたびに増え、インベントリから落としても減らない。
+/// タイル解放や松明の購入などで消費することを想定している。
+/// 鉱石・コインとも次回の起動時に引き継げるようセーブファイルに保存する。
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Resources {
+    pub ore: u32,
+    pub coins: u32,
+}
+
+impl JsonSave for Resources {
+    const FILE_NAME: &'static str = "resources.json";
+}
+
+/// 設置した松明のセーブデータ
+///
+/// マスのグリッド座標とマス中心からのずれを保存し、起動時にフィールドのブロックへ置き直す。
+#[derive(Serialize, Deserialize, Default)]
+pub struct TorchSave {
+    pub torches: Vec<SavedTorch>,
+}
+
+/// セーブファイルに保存する松明1本の位置
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct SavedTorch {
+    /// 松明が置かれているマスのグリッド座標
+    pub tile: [i32; 2],
+    /// マス中心から松明までの水平方向のずれ（X, Z）
+    pub offset: [f32; 2],
+}
+
+impl JsonSave for TorchSave {
+    const FILE_NAME: &'static str = "torches.json";
+}
+
+/// カメラの動きの記録・再生の状態
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CameraRecordingState {
+    #[default]
+    Idle,
+    Recording,
+    Playing,
+}
+
+/// 記録したメインカメラの動き（デモ動画の撮影用）
+///
+/// 記録中は毎フレームのカメラの姿勢を積み、再生中は経過時間に合わせて
+/// 前後のサンプルを補間した姿勢でカメラを上書きする。
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+#[serde(from = "Vec<CameraSample>", into = "Vec<CameraSample>")]
+pub struct CameraRecording {
+    /// 記録開始からの秒数とその時のカメラの姿勢（古い順）
+    pub samples: Vec<(f32, Transform)>,
+    pub state: CameraRecordingState,
+    /// 記録・再生を始めてからの秒数
+    pub elapsed: f32,
+}
+
+/// ファイルに保存するカメラの姿勢のサンプル
+#[derive(Serialize, Deserialize)]
+struct CameraSample {
+    time: f32,
+    translation: [f32; 3],
+    rotation: [f32; 4],
+}
+
+impl From<CameraRecording> for Vec<CameraSample> {
+    fn from(recording: CameraRecording) -> Self {
+        recording
+            .samples
+            .iter()
+            .map(|(time, transform)| CameraSample {
+                time: *time,
+                translation: transform.translation.to_array(),
+                rotation: transform.rotation.to_array(),
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<CameraSample>> for CameraRecording {
+    fn from(samples: Vec<CameraSample>) -> Self {
+        Self {
+            samples: samples
+                .into_iter()
+                .map(|sample| {
+                    let transform =
+                        Transform::from_translation(Vec3::from_array(sample.translation))
+                            .with_rotation(Quat::from_array(sample.rotation).normalize());
+                    (sample.time, transform)
+                })
+                .collect(),
+            ..default()
+        }
+    }
+}
+
+impl JsonSave for CameraRecording {
+    const FILE_NAME: &'static str = "camera_path.json";
+}
+
+impl CameraRecording {
+    /// 記録の長さ（秒）
+    pub fn duration(&self) -> f32 {
+        self.samples.last().map_or(0.0, |(time, _)| *time)
+    }
+
+    /// 指定した時刻のカメラの姿勢を前後のサンプルから補間して返す
+    pub fn sample_at(&self, time: f32) -> Option<Transform> {
+        let next = self.samples.partition_point(|(t, _)| *t < time);
+        let (t1, to) = *self.samples.get(next).or(self.samples.last())?;
+        let Some(&(t0, from)) = next.checked_sub(1).and_then(|i| self.samples.get(i)) else {
+            return Some(to);
+        };
+
+        let t = if t1 > t0 {
+            ((time - t0) / (t1 - t0)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        Some(Transform {
+            translation: from.translation.lerp(to.translation, t),
+            rotation: from.rotation.slerp(to.rotation, t),
+            scale: from.scale.lerp(to.scale, t),
         })
     }
 }
+
+/// 実績の種類
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Achievement {
+    /// 初めて岩を壊して鉱石を掘り出した
+    FirstOreMined,
+    /// 初めてキツネに憑依した
+    FirstPossession,
+    /// 植物・柵・松明を合計で一定数設置した
+    Builder,
+}
+
+/// 解除済みの実績と、実績の条件に使う累計の記録
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+pub struct Achievements {
+    pub unlocked: Vec<Achievement>,
+    /// これまでに設置した植物・柵・松明の数
+    pub objects_built: u32,
+}
+
+impl JsonSave for Achievements {
+    const FILE_NAME: &'static str = "achievements.json";
+}
+
+impl Achievements {
+    /// 実績を解除する（初めて解除した場合のみ`true`を返す）
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        if self.unlocked.contains(&achievement) {
+            return false;
+        }
+        self.unlocked.push(achievement);
+        true
+    }
+}
+
+/// これまでの累計プレイ時間
+///
+/// 毎フレーム増えるため、セーブファイルへの書き出しは一定間隔と終了時に行う。
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+pub struct Playtime {
+    pub total_secs: f64,
+}
+
+impl JsonSave for Playtime {
+    const FILE_NAME: &'static str = "playtime.json";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: f32 = 0.3;
+
+    /// キーを押してから離すまでの1回のタップを入力し、タップとして数えた場合は登録する
+    fn tap(
+        dash_state: &mut DashInputState,
+        keyboard_input: &mut ButtonInput<KeyCode>,
+        key: KeyCode,
+        now: f32,
+    ) {
+        keyboard_input.clear();
+        keyboard_input.press(key);
+        if DashInputState::tap_started(keyboard_input) {
+            dash_state.register_tap(now, TIMEOUT);
+        }
+        keyboard_input.clear();
+        keyboard_input.release(key);
+    }
+
+    #[test]
+    fn first_tap_does_not_dash() {
+        let mut dash_state = DashInputState::default();
+        dash_state.register_tap(1.0, TIMEOUT);
+        assert!(!dash_state.is_dashing);
+        assert_eq!(dash_state.last_tap_time, Some(1.0));
+    }
+
+    #[test]
+    fn second_tap_within_timeout_dashes() {
+        let mut dash_state = DashInputState::default();
+        dash_state.register_tap(1.0, TIMEOUT);
+        dash_state.register_tap(1.2, TIMEOUT);
+        assert!(dash_state.is_dashing);
+    }
+
+    #[test]
+    fn second_tap_after_timeout_does_not_dash() {
+        let mut dash_state = DashInputState::default();
+        dash_state.register_tap(1.0, TIMEOUT);
+        dash_state.register_tap(1.5, TIMEOUT);
+        assert!(!dash_state.is_dashing);
+
+        // 遅れたタップが次のダブルタップの1回目になる
+        dash_state.register_tap(1.6, TIMEOUT);
+        assert!(dash_state.is_dashing);
+    }
+
+    #[test]
+    fn taps_on_different_keys_dash() {
+        let mut dash_state = DashInputState::default();
+        let mut keyboard_input = ButtonInput::default();
+        tap(&mut dash_state, &mut keyboard_input, KeyCode::KeyW, 1.0);
+        assert!(!dash_state.is_dashing);
+        tap(&mut dash_state, &mut keyboard_input, KeyCode::KeyD, 1.2);
+        assert!(dash_state.is_dashing);
+    }
+
+    #[test]
+    fn pressing_a_key_while_another_is_held_is_not_a_tap() {
+        let mut keyboard_input = ButtonInput::default();
+        keyboard_input.press(KeyCode::KeyW);
+        keyboard_input.clear();
+        keyboard_input.press(KeyCode::KeyD);
+        assert!(!DashInputState::tap_started(&keyboard_input));
+    }
+
+    #[test]
+    fn pressing_two_keys_in_one_frame_is_one_tap() {
+        let mut keyboard_input = ButtonInput::default();
+        keyboard_input.press(KeyCode::KeyW);
+        keyboard_input.press(KeyCode::KeyD);
+        assert!(DashInputState::tap_started(&keyboard_input));
+    }
+
+    /// (1, 1)のマスだけを2段持ち上げたフィールド
+    fn raised_field() -> FieldConfig {
+        let file = crate::level::LevelFile {
+            size: 3,
+            tiles: vec![crate::level::LevelTile {
+                x: 1,
+                z: 1,
+                height: 2,
+                ..Default::default()
+            }],
+        };
+        FieldConfig {
+            size: 3,
+            batched: false,
+            level: FieldLevel::from_file(file).ok(),
+        }
+    }
+
+    /// ブロックの真上から見下ろす視線
+    fn ray_down_onto(field_config: &FieldConfig, x: i32, z: i32) -> Ray3d {
+        let origin = field_config.block_position(x, z) + Vec3::Y * 200.0;
+        Ray3d::new(origin, Dir3::NEG_Y)
+    }
+
+    #[test]
+    fn ray_hits_the_top_of_a_raised_tile() {
+        let field_config = raised_field();
+        let hit = field_config
+            .ray_block_top(ray_down_onto(&field_config, 1, 1))
+            .unwrap();
+        assert_eq!(hit.y, BLOCK_HALF_SIZE + 2.0 * BLOCK_SIZE);
+        assert_eq!(field_config.elevation_at(hit), 2.0 * BLOCK_SIZE);
+    }
+
+    #[test]
+    fn ray_hits_the_top_of_a_flat_tile() {
+        let field_config = raised_field();
+        let hit = field_config
+            .ray_block_top(ray_down_onto(&field_config, 0, 0))
+            .unwrap();
+        assert_eq!(hit.y, BLOCK_HALF_SIZE);
+        assert_eq!(field_config.elevation_at(hit), 0.0);
+    }
+
+    #[test]
+    fn formation_places_followers_around_the_lead_fox() {
+        let mut move_mode = FoxMoveMode::default();
+        move_mode.start(vec![(Entity::PLACEHOLDER, Vec3::ZERO); 3]);
+
+        assert_eq!(move_mode.formation_offset(0), Vec3::ZERO);
+        for index in 1..3 {
+            let offset = move_mode.formation_offset(index);
+            assert_eq!(offset.y, 0.0);
+            assert!((offset.length() - FOX_FORMATION_SPACING).abs() < 1e-4);
+        }
+        let (first, second) = (move_mode.formation_offset(1), move_mode.formation_offset(2));
+        assert!(first.distance(second) > 1.0);
+    }
+
+    #[test]
+    fn missing_possession_sensitivity_follows_mouse_sensitivity() {
+        let mut json = serde_json::to_value(CameraSettings {
+            mouse_sensitivity: 0.008,
+            ..default()
+        })
+        .unwrap();
+        json.as_object_mut()
+            .unwrap()
+            .remove("possession_sensitivity");
+
+        let mut settings: CameraSettings = serde_json::from_value(json).unwrap();
+        settings.sanitize();
+        assert_eq!(settings.possession_sensitivity, 0.008);
+    }
+
+    #[test]
+    fn daylight_tint_cycles_from_dawn_through_night() {
+        let at = |fraction: f32| Calendar {
+            time_of_day: DAY_LENGTH_SECS * fraction,
+            ..default()
+        };
+
+        assert_eq!(at(0.0).daylight_tint(), SKY_DUSK_TINT);
+        assert_eq!(at(0.25).daylight_tint(), SKY_DAY_TINT);
+        assert_eq!(at(0.5).daylight_tint(), SKY_DUSK_TINT);
+        assert_eq!(at(0.75).daylight_tint(), SKY_NIGHT_TINT);
+
+        let before_dawn = at(0.99).daylight_tint();
+        assert!(before_dawn.0 > SKY_NIGHT_TINT.0 && before_dawn.0 < SKY_DUSK_TINT.0);
+    }
+}
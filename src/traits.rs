@@ -5,11 +5,16 @@
 //! - `Adjustable`: 設定値の増減を抽象化
 //! - `Storable`: インベントリアイテムの共通インターフェース
 //! - `GameMode`: ゲームモードの状態管理
+//! - `JsonSave`: `assets/user`以下のJSONファイルへの保存と読み込み
 //! - `CameraRotation`: カメラ回転のヘルパー
 
 #![allow(dead_code)]
 
 use bevy::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 // ========================================
 // Ray Intersection Trait
@@ -38,7 +43,10 @@ pub struct Aabb {
 impl Aabb {
     /// 新しいAABBを作成
     pub fn new(center: Vec3, half_extents: Vec3) -> Self {
-        Self { center, half_extents }
+        Self {
+            center,
+            half_extents,
+        }
     }
 
     /// 中心座標とサイズから立方体のAABBを作成
@@ -97,11 +105,7 @@ impl RayIntersectable for Sphere {
                 Some(t)
             } else {
                 let t2 = (-b + discriminant.sqrt()) / (2.0 * a);
-                if t2 >= 0.0 {
-                    Some(t2)
-                } else {
-                    None
-                }
+                if t2 >= 0.0 { Some(t2) } else { None }
             }
         }
     }
@@ -140,8 +144,22 @@ pub struct AdjustableValue {
 }
 
 impl AdjustableValue {
-    pub const fn new(value: f32, step: f32, min: f32, max: f32, precision: usize, label: &'static str) -> Self {
-        Self { value, step, min, max, precision, label }
+    pub const fn new(
+        value: f32,
+        step: f32,
+        min: f32,
+        max: f32,
+        precision: usize,
+        label: &'static str,
+    ) -> Self {
+        Self {
+            value,
+            step,
+            min,
+            max,
+            precision,
+            label,
+        }
     }
 }
 
@@ -204,6 +222,81 @@ pub trait GameMode {
     fn allows_wasd_input(&self) -> bool;
 }
 
+// ========================================
+// JSON Save Trait
+// ========================================
+
+/// `assets/user`以下のJSONファイルに保存するセーブデータや設定を抽象化するトレイト
+///
+/// [`JsonSave::FILE_NAME`]を決めるだけで、保存先のパスと読み書きが揃う。
+/// 読み込んだ値を範囲内に収める必要がある型は[`JsonSave::sanitize`]を上書きする。
+pub trait JsonSave: Serialize + DeserializeOwned + Default {
+    /// `assets/user`以下のファイル名
+    const FILE_NAME: &'static str;
+
+    /// ファイルがない場合に、編集して使えるよう既定値を書き出すか（手で編集する設定向け）
+    const WRITE_DEFAULT_IF_MISSING: bool = false;
+
+    /// セーブファイルのパスを取得
+    fn save_path() -> PathBuf {
+        Path::new("assets/user").join(Self::FILE_NAME)
+    }
+
+    /// ファイルに保存
+    fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_to_path(&Self::save_path())
+    }
+
+    /// ファイルから読み込む
+    fn load_from_file() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::load_from_path(&Self::save_path())
+    }
+
+    /// 任意のパスに書き出す（親ディレクトリがなければ作成する）
+    fn save_to_path(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// 任意のパスから読み込み、[`JsonSave::sanitize`]で補正する
+    fn load_from_path(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        let mut value: Self = serde_json::from_str(&json)?;
+        value.sanitize();
+        Ok(value)
+    }
+
+    /// 読み込んだ値を補正する（既定では何もしない）
+    fn sanitize(&mut self) {}
+
+    /// ファイルから読み込むか、デフォルトで始める
+    ///
+    /// ファイルはあるのに読み込めなかった場合は、その理由を表示する。
+    fn load_or_default() -> Self {
+        if !Self::save_path().exists() {
+            let value = Self::default();
+            if Self::WRITE_DEFAULT_IF_MISSING
+                && let Err(e) = value.save_to_file()
+            {
+                eprintln!("{} を書き出せませんでした: {}", Self::FILE_NAME, e);
+            }
+            return value;
+        }
+        Self::load_from_file().unwrap_or_else(|e| {
+            eprintln!(
+                "{} を読み込めませんでした（{}）。デフォルトを使用します",
+                Self::FILE_NAME,
+                e
+            );
+            Self::default()
+        })
+    }
+}
+
 // ========================================
 // Camera Rotation Helpers
 // ========================================
@@ -268,6 +361,18 @@ pub fn camera_relative_movement(camera_transform: &Transform) -> (Vec3, Vec3) {
     (forward_xz, right_xz)
 }
 
+/// スティック入力にデッドゾーンを適用する
+///
+/// デッドゾーン内の入力は0とし、それより外側は0〜1に再スケールする。
+pub fn apply_stick_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let length = stick.length();
+    if length <= deadzone {
+        return Vec2::ZERO;
+    }
+    let scaled = ((length - deadzone) / (1.0 - deadzone)).min(1.0);
+    stick / length * scaled
+}
+
 // ========================================
 // Entity Finding Helpers
 // ========================================